@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    created_at TEXT NOT NULL,
+    task_id TEXT NOT NULL,
+    action TEXT NOT NULL,
+    summary TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_task_id ON audit_log(task_id);
@@ -0,0 +1 @@
+ALTER TABLE tasks ADD COLUMN complete_label TEXT;
@@ -0,0 +1 @@
+ALTER TABLE completions ADD COLUMN duration_minutes INTEGER;
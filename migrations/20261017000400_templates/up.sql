@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS templates (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    task_name TEXT NOT NULL,
+    details TEXT,
+    schedule_id INTEGER NOT NULL,
+    alerting_time INTEGER,
+    completeable INTEGER NOT NULL DEFAULT 1,
+    complete_label TEXT,
+    FOREIGN KEY (schedule_id) REFERENCES schedules(id)
+);
@@ -0,0 +1,2 @@
+ALTER TABLE templates DROP COLUMN grace_minutes;
+ALTER TABLE tasks DROP COLUMN grace_minutes;
@@ -0,0 +1,2 @@
+ALTER TABLE tasks ADD COLUMN grace_minutes INTEGER;
+ALTER TABLE templates ADD COLUMN grace_minutes INTEGER;
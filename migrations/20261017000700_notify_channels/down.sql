@@ -0,0 +1,2 @@
+ALTER TABLE templates DROP COLUMN notify_channels;
+ALTER TABLE tasks DROP COLUMN notify_channels;
@@ -0,0 +1,2 @@
+ALTER TABLE tasks ADD COLUMN notify_channels TEXT;
+ALTER TABLE templates ADD COLUMN notify_channels TEXT;
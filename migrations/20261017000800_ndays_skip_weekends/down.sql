@@ -0,0 +1 @@
+ALTER TABLE schedules DROP COLUMN ndays_skip_weekends;
@@ -0,0 +1 @@
+ALTER TABLE schedules ADD COLUMN ndays_skip_weekends TEXT;
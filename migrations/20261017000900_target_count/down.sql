@@ -0,0 +1,2 @@
+ALTER TABLE templates DROP COLUMN target_count;
+ALTER TABLE tasks DROP COLUMN target_count;
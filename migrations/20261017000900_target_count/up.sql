@@ -0,0 +1,2 @@
+ALTER TABLE tasks ADD COLUMN target_count INTEGER;
+ALTER TABLE templates ADD COLUMN target_count INTEGER;
@@ -0,0 +1,2 @@
+ALTER TABLE templates DROP COLUMN instructions;
+ALTER TABLE tasks DROP COLUMN instructions;
@@ -0,0 +1,2 @@
+ALTER TABLE tasks ADD COLUMN instructions TEXT;
+ALTER TABLE templates ADD COLUMN instructions TEXT;
@@ -0,0 +1,2 @@
+ALTER TABLE templates DROP COLUMN show_ahead_days;
+ALTER TABLE tasks DROP COLUMN show_ahead_days;
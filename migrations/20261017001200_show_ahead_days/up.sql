@@ -0,0 +1,2 @@
+ALTER TABLE tasks ADD COLUMN show_ahead_days INTEGER;
+ALTER TABLE templates ADD COLUMN show_ahead_days INTEGER;
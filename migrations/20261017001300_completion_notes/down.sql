@@ -0,0 +1 @@
+ALTER TABLE completions DROP COLUMN note;
@@ -0,0 +1 @@
+ALTER TABLE completions ADD COLUMN note TEXT;
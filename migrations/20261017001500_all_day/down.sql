@@ -0,0 +1,2 @@
+ALTER TABLE templates DROP COLUMN all_day;
+ALTER TABLE tasks DROP COLUMN all_day;
@@ -0,0 +1,2 @@
+ALTER TABLE tasks ADD COLUMN all_day INTEGER;
+ALTER TABLE templates ADD COLUMN all_day INTEGER;
@@ -0,0 +1,837 @@
+use axum::{
+    extract::{rejection::JsonRejection, Path, Query, Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Json, Response},
+    routing::{delete, get, post},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::auth::secrets_match;
+use crate::config::ApiAccess;
+use crate::db::{self, DbPool};
+use crate::error::AppError;
+use crate::schedule::Schedule;
+use crate::tasks::{self, DemoTask};
+
+// ============================================================================
+// Authentication
+// ============================================================================
+
+/// Check the bearer token on every API request. The UI routes don't go through this.
+pub async fn require_token(State(access): State<ApiAccess>, request: Request, next: Next) -> Response {
+    let authorized = match &access {
+        ApiAccess::Disabled => false,
+        ApiAccess::Open => true,
+        ApiAccess::Token(expected) => request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .is_some_and(|token| secrets_match(token.trim(), expected)),
+    };
+
+    if !authorized {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            Json(serde_json::json!({ "error": "unauthorized" })),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+pub fn router(access: ApiAccess) -> Router<DbPool> {
+    Router::new()
+        .route("/tasks", get(list_tasks))
+        .route("/tasks/{id}/calendar", get(task_calendar))
+        .route("/tasks/{id}/schedule", get(task_schedule).put(replace_schedule))
+        .route("/history", get(list_history))
+        .route("/day/{date}", get(day_tasks))
+        .route("/share-tokens", get(list_share_tokens).post(create_share_token))
+        .route("/share-tokens/{id}", delete(revoke_share_token))
+        .layer(middleware::from_fn_with_state(access, require_token))
+}
+
+/// Maintenance endpoints, behind the same token as the API
+pub fn admin_router(access: ApiAccess) -> Router<DbPool> {
+    Router::new()
+        .route("/recompute", post(recompute))
+        .layer(middleware::from_fn_with_state(access, require_token))
+}
+
+// ============================================================================
+// Tasks
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct ApiTask {
+    pub id: String,
+    pub name: String,
+    pub details: String,
+    pub schedule_kind: &'static str,
+    pub next_due: String,
+    pub alerting_time: i64,
+    pub grace_minutes: i64,
+    pub completeable: bool,
+    pub target_count: i64,
+}
+
+impl From<&DemoTask> for ApiTask {
+    fn from(task: &DemoTask) -> Self {
+        ApiTask {
+            id: task.id.clone(),
+            name: task.name.clone(),
+            details: task.details.clone(),
+            schedule_kind: task.schedule_kind.as_str(),
+            next_due: task.next_due_date().to_rfc3339(),
+            alerting_time: task.alerting_time,
+            grace_minutes: task.grace_minutes,
+            completeable: task.completeable,
+            target_count: task.target_count,
+        }
+    }
+}
+
+/// Query for GET /api/tasks. Values are strings so blank or non-numeric input
+/// falls back to the defaults instead of failing the request.
+#[derive(Deserialize, Default)]
+pub struct TaskListQuery {
+    #[serde(default)]
+    page: Option<String>,
+    #[serde(default)]
+    per_page: Option<String>,
+    #[serde(default)]
+    sort: Option<String>,
+}
+
+const DEFAULT_PAGE: i64 = 1;
+const DEFAULT_PER_PAGE: i64 = 10;
+
+fn parse_or(value: Option<&str>, default: i64) -> i64 {
+    value
+        .map(str::trim)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+#[derive(Serialize)]
+pub struct TaskPage {
+    pub items: Vec<ApiTask>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+    pub total_pages: i64,
+}
+
+// GET /api/tasks?page=&per_page=&sort= - One page of tasks as JSON.
+// Pagination is clamped like the HTML list: per_page to 1..=100, page to
+// 1..=total_pages. Blank or invalid values use page 1 and 10 per page.
+// sort is "name" (default) or "due"; anything else also sorts by name.
+async fn list_tasks(
+    State(pool): State<DbPool>,
+    Query(query): Query<TaskListQuery>,
+) -> Result<Json<TaskPage>, AppError> {
+    let total = db::get_task_count(&pool).await?;
+
+    let sort = match query.sort.as_deref().map(str::trim) {
+        Some("due") => "due",
+        _ => "name",
+    };
+    let (page, per_page, total_pages) = tasks::clamp_pagination(
+        parse_or(query.page.as_deref(), DEFAULT_PAGE),
+        parse_or(query.per_page.as_deref(), DEFAULT_PER_PAGE),
+        total,
+    );
+    let items = tasks::fetch_task_page(&pool, sort, page, per_page).await;
+
+    Ok(Json(TaskPage {
+        items: items.iter().map(ApiTask::from).collect(),
+        page,
+        per_page,
+        total,
+        total_pages,
+    }))
+}
+
+// ============================================================================
+// Calendar
+// ============================================================================
+
+#[derive(Deserialize, Default)]
+pub struct CalendarQuery {
+    #[serde(default)]
+    year: Option<i32>,
+    #[serde(default)]
+    month: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct ApiCalendarDay {
+    pub day: u32,
+    /// Local "HH:MM" in the configured timezone, or null when nothing is due
+    pub due_time: Option<String>,
+    pub completed: bool,
+    /// Past its completion window without being completed
+    pub missed: bool,
+}
+
+impl From<&tasks::CalendarDay<'_>> for ApiCalendarDay {
+    fn from(day: &tasks::CalendarDay<'_>) -> Self {
+        use chrono::Datelike;
+
+        ApiCalendarDay {
+            day: day.date.day(),
+            due_time: day.due_time.map(|time| time.format("%H:%M").to_string()),
+            completed: day.completion.is_some(),
+            missed: day.missed,
+        }
+    }
+}
+
+// GET /api/tasks/:id/calendar?year=&month= - The same month grid the task
+// page shows, one entry per day. Defaults to the current month.
+async fn task_calendar(
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    Query(query): Query<CalendarQuery>,
+) -> Result<Json<Vec<ApiCalendarDay>>, AppError> {
+    use chrono::Datelike;
+
+    let task = load_task(&pool, &id).await?;
+
+    let today = chrono::Utc::now().with_timezone(&crate::config::get_timezone()).date_naive();
+    let year = query.year.unwrap_or(today.year());
+    let month = query.month.unwrap_or(today.month());
+    let completions = db::get_all_completions(&pool, &id).await?;
+    let days = tasks::calendar_month(&task, &completions, year, month)
+        .ok_or_else(|| AppError::Validation(format!("{}-{} isn't a valid month.", year, month)))?;
+
+    Ok(Json(days.iter().map(ApiCalendarDay::from).collect()))
+}
+
+// ============================================================================
+// Schedule
+// ============================================================================
+
+async fn load_task(pool: &DbPool, id: &str) -> Result<DemoTask, AppError> {
+    let not_found = || AppError::NotFound(format!("Task '{}' doesn't exist.", id));
+    let task_id = id.parse::<i64>().map_err(|_| not_found())?;
+    db::get_task(pool, task_id).await?.ok_or_else(not_found)
+}
+
+// GET /api/tasks/:id/schedule - The task's schedule on its own: "kind" plus
+// that kind's fields, in the shape PUT accepts back. Monthwise and certain-months
+// days use -N for "N through the end of the month" (README, "Schedules over the API")
+async fn task_schedule(State(pool): State<DbPool>, Path(id): Path<String>) -> Result<Json<Schedule>, AppError> {
+    Ok(Json(load_task(&pool, &id).await?.schedule()))
+}
+
+// PUT /api/tasks/:id/schedule - Replace the schedule, checked by the same rules
+// as the editor form. Returns the schedule as saved.
+async fn replace_schedule(
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    body: Result<Json<Schedule>, JsonRejection>,
+) -> Result<Json<Schedule>, AppError> {
+    let Json(schedule) = body.map_err(|e| AppError::Validation(e.body_text()))?;
+    let task = load_task(&pool, &id).await?;
+    let updated = tasks::with_schedule(&task, &schedule).map_err(AppError::Validation)?;
+    db::save_task(&pool, &updated).await?;
+
+    tracing::info!(task_id = %id, "Schedule replaced through the API");
+    let changes = tasks::describe_task_changes(&task, &updated);
+    if !changes.is_empty() {
+        tasks::record_audit_with_snapshot(&pool, &id, "edit", &changes.join("; "), &updated).await;
+    }
+    Ok(Json(updated.schedule()))
+}
+
+// ============================================================================
+// Share links
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct ApiShareToken {
+    pub id: i64,
+    pub label: String,
+    pub token: String,
+    /// Path of the read-only board this token opens
+    pub url: String,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+impl From<db::ShareToken> for ApiShareToken {
+    fn from(share: db::ShareToken) -> Self {
+        ApiShareToken {
+            url: format!("/share/{}", share.token),
+            id: share.id,
+            label: share.label,
+            token: share.token,
+            created_at: share.created_at,
+            revoked_at: share.revoked_at,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct NewShareToken {
+    #[serde(default)]
+    label: String,
+}
+
+// GET /api/share-tokens - Every share link, revoked ones included, newest first
+async fn list_share_tokens(State(pool): State<DbPool>) -> Result<Json<Vec<ApiShareToken>>, AppError> {
+    let tokens = db::get_share_tokens(&pool).await?;
+    Ok(Json(tokens.into_iter().map(ApiShareToken::from).collect()))
+}
+
+// POST /api/share-tokens - Make a new read-only link. The body is optional:
+// {"label": "..."} names the link so it can be told apart when revoking.
+async fn create_share_token(
+    State(pool): State<DbPool>,
+    body: Option<Json<NewShareToken>>,
+) -> Result<(StatusCode, Json<ApiShareToken>), AppError> {
+    let Json(new) = body.unwrap_or_default();
+    let share = db::create_share_token(&pool, &crate::share::generate_token(), new.label.trim()).await?;
+    tracing::info!(share_id = share.id, "Share link created");
+    Ok((StatusCode::CREATED, Json(share.into())))
+}
+
+// DELETE /api/share-tokens/:id - Revoke a share link; its URL 404s from then on
+async fn revoke_share_token(State(pool): State<DbPool>, Path(id): Path<i64>) -> Result<StatusCode, AppError> {
+    if !db::revoke_share_token(&pool, id).await? {
+        return Err(AppError::NotFound(format!("No active share link with id {}.", id)));
+    }
+    tracing::info!(share_id = id, "Share link revoked");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Day
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct ApiDayTask {
+    pub id: String,
+    pub name: String,
+    /// Local "HH:MM" in the configured timezone; "00:00" for all-day tasks
+    pub due_time: String,
+    /// Due any time on the day rather than at due_time
+    pub all_day: bool,
+    /// The same moment as RFC 3339, for clients in other timezones
+    pub due_at: Option<String>,
+    pub schedule: String,
+}
+
+// GET /api/day/:date - Every task due on a date (YYYY-MM-DD), earliest first
+async fn day_tasks(State(pool): State<DbPool>, Path(date): Path<String>) -> Result<Json<Vec<ApiDayTask>>, AppError> {
+    use chrono::TimeZone;
+
+    let date = tasks::parse_day(&date)?;
+    let all_tasks = db::get_all_tasks(&pool).await?;
+    let tz = crate::config::get_timezone();
+    let items = tasks::tasks_due_on(&all_tasks, date)
+        .into_iter()
+        .map(|(task, time)| ApiDayTask {
+            id: task.id.clone(),
+            name: task.name.clone(),
+            due_time: time.format("%H:%M").to_string(),
+            all_day: task.all_day,
+            due_at: tz.from_local_datetime(&date.and_time(time)).earliest().map(|dt| dt.to_rfc3339()),
+            schedule: tasks::describe_schedule(task),
+        })
+        .collect();
+    Ok(Json(items))
+}
+
+// ============================================================================
+// History
+// ============================================================================
+
+#[derive(Deserialize, Default)]
+pub struct HistoryQuery {
+    #[serde(default)]
+    page: Option<String>,
+    #[serde(default)]
+    per_page: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ApiCompletion {
+    pub id: i64,
+    pub task_id: String,
+    /// Null when the task has since been deleted for good
+    pub task_name: Option<String>,
+    pub completed_at: String,
+    pub person_initials: Option<String>,
+}
+
+impl From<&db::TimelineEntry> for ApiCompletion {
+    fn from(entry: &db::TimelineEntry) -> Self {
+        ApiCompletion {
+            id: entry.id,
+            task_id: entry.task_id.clone(),
+            task_name: entry.task_name.clone(),
+            completed_at: entry.completed_at.to_rfc3339(),
+            person_initials: entry.person_initials.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct HistoryPage {
+    pub items: Vec<ApiCompletion>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+    pub total_pages: i64,
+    /// URL of the next (older) page, or null on the last page
+    pub next: Option<String>,
+    /// URL of the previous (newer) page, or null on the first page
+    pub prev: Option<String>,
+}
+
+fn history_url(page: i64, per_page: i64) -> String {
+    format!("/api/history?page={}&per_page={}", page, per_page)
+}
+
+// GET /api/history?page=&per_page= - Completions across all tasks, newest
+// first, one page at a time. Clamped the same way as GET /api/tasks.
+async fn list_history(
+    State(pool): State<DbPool>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<HistoryPage>, AppError> {
+    let total = db::get_completion_count(&pool).await?;
+    let (page, per_page, total_pages) = tasks::clamp_pagination(
+        parse_or(query.page.as_deref(), DEFAULT_PAGE),
+        parse_or(query.per_page.as_deref(), DEFAULT_PER_PAGE),
+        total,
+    );
+    let entries = db::get_recent_completions(&pool, (page - 1) * per_page, per_page).await?;
+
+    Ok(Json(HistoryPage {
+        items: entries.iter().map(ApiCompletion::from).collect(),
+        page,
+        per_page,
+        total,
+        total_pages,
+        next: (page < total_pages).then(|| history_url(page + 1, per_page)),
+        prev: (page > 1).then(|| history_url(page - 1, per_page)),
+    }))
+}
+
+// ============================================================================
+// Admin
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct RecomputeReport {
+    pub created_at_backfilled: Vec<String>,
+    pub schedules_repaired: Vec<String>,
+}
+
+// POST /admin/recompute - Repair state after editing the database by hand.
+// Each repair only touches rows that need it, so running it twice is harmless.
+async fn recompute(State(pool): State<DbPool>) -> Result<Json<RecomputeReport>, AppError> {
+    let report = RecomputeReport {
+        created_at_backfilled: db::backfill_created_at(&pool).await?,
+        schedules_repaired: db::repair_task_schedules(&pool).await?,
+    };
+    tracing::info!(
+        "Recompute: backfilled created_at on {} task(s), repaired {} schedule(s)",
+        report.created_at_backfilled.len(),
+        report.schedules_repaired.len()
+    );
+    Ok(Json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::{insert_task, test_pool};
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    async fn call(access: ApiAccess, auth: Option<&str>) -> StatusCode {
+        let app = router(access).with_state(test_pool("api_call").await);
+        let mut request = Request::builder().uri("/tasks");
+        if let Some(auth) = auth {
+            request = request.header(header::AUTHORIZATION, auth);
+        }
+        app.oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_api_accepts_correct_token() {
+        let access = ApiAccess::Token("secret".to_string());
+        assert_eq!(call(access, Some("Bearer secret")).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_api_rejects_wrong_or_missing_token() {
+        let access = ApiAccess::Token("secret".to_string());
+        assert_eq!(call(access.clone(), Some("Bearer nope")).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(call(access.clone(), Some("Basic c2VjcmV0")).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(call(access, None).await, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_api_disabled_without_token() {
+        assert_eq!(call(ApiAccess::Disabled, None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(call(ApiAccess::Disabled, Some("Bearer anything")).await, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_api_open_needs_no_token() {
+        assert_eq!(call(ApiAccess::Open, None).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_api_tasks_pagination_envelope() {
+        let pool = test_pool("api_tasks_pagination_envelope").await;
+        for i in 0..3 {
+            sqlx::query("INSERT INTO schedules (id, kind) VALUES (?, 'n_days')").bind(i).execute(&pool).await.unwrap();
+            sqlx::query("INSERT INTO tasks (name, schedule_id) VALUES (?, ?)")
+                .bind(format!("Task {}", i))
+                .bind(i)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let get = |uri: &'static str| {
+            let app = router(ApiAccess::Open).with_state(pool.clone());
+            async move {
+                let response = app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap()).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+            }
+        };
+
+        let page = get("/tasks?page=2&per_page=2").await;
+        assert_eq!(page["page"], 2);
+        assert_eq!(page["per_page"], 2);
+        assert_eq!(page["total"], 3);
+        assert_eq!(page["total_pages"], 2);
+        assert_eq!(page["items"].as_array().unwrap().len(), 1);
+
+        // Blank and out-of-range values are clamped rather than rejected
+        let page = get("/tasks?page=&per_page=").await;
+        assert_eq!((page["page"].as_i64(), page["per_page"].as_i64()), (Some(1), Some(10)));
+        let page = get("/tasks?page=99&per_page=0").await;
+        assert_eq!((page["page"].as_i64(), page["per_page"].as_i64()), (Some(3), Some(1)));
+    }
+
+    #[tokio::test]
+    async fn test_api_unknown_sort_falls_back_to_name() {
+        let pool = test_pool("api_unknown_sort_falls_back_to_name").await;
+        insert_task(&pool, 1, "Trash").await;
+        insert_task(&pool, 2, "Dishes").await;
+        let (status, page) = get_json(&pool, "/tasks?sort=bogus").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page["items"][0]["name"], "Dishes");
+    }
+
+    // ========================================================================
+    // Calendar tests
+    // ========================================================================
+
+    async fn get_json(pool: &DbPool, uri: &str) -> (StatusCode, serde_json::Value) {
+        let app = router(ApiAccess::Open).with_state(pool.clone());
+        let response = app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_calendar_matches_schedule_and_completions() {
+        let pool = test_pool("api_calendar_matches_schedule_and_completions").await;
+        sqlx::query("INSERT INTO schedules (id, kind) VALUES (1, 'n_days')").execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO tasks (id, name, schedule_id, created_at) VALUES (1, 'Dishes', 1, '2026-01-01T00:00:00+00:00')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let completed_at = chrono::DateTime::parse_from_rfc3339("2026-03-10T12:00:00+00:00").unwrap();
+        db::add_completion_at(&pool, "1", completed_at.into(), None, None, None).await.unwrap();
+
+        let (status, days) = get_json(&pool, "/tasks/1/calendar?year=2026&month=3").await;
+        assert_eq!(status, StatusCode::OK);
+        let days = days.as_array().unwrap();
+        assert_eq!(days.len(), 31);
+        assert_eq!(days[0]["day"], 1);
+        assert!(days.iter().all(|d| d["due_time"].is_string()));
+        assert_eq!(days.iter().filter(|d| d["completed"] == true).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_calendar_rejects_bad_month_and_missing_task() {
+        let pool = test_pool("api_calendar_rejects_bad_month_and_missing_task").await;
+        insert_task(&pool, 1, "Dishes").await;
+
+        let (status, body) = get_json(&pool, "/tasks/1/calendar?year=2026&month=13").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "invalid_request");
+        let (status, _) = get_json(&pool, "/tasks/42/calendar").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    // ========================================================================
+    // Share link tests
+    // ========================================================================
+
+    async fn send(pool: &DbPool, method: &str, uri: &str, body: Option<&str>) -> (StatusCode, String) {
+        let app = router(ApiAccess::Open).with_state(pool.clone());
+        let mut request = Request::builder().method(method).uri(uri);
+        if body.is_some() {
+            request = request.header(header::CONTENT_TYPE, "application/json");
+        }
+        let body = body.map(|b| Body::from(b.to_string())).unwrap_or_else(Body::empty);
+        let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_share_tokens_create_list_and_revoke() {
+        let pool = test_pool("api_share_tokens_create_list_and_revoke").await;
+
+        let (status, body) = send(&pool, "POST", "/share-tokens", Some(r#"{"label": "Grandma"}"#)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let created: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let token = created["token"].as_str().unwrap();
+        assert_eq!(token.len(), 32);
+        assert_eq!(created["url"], format!("/share/{}", token));
+        assert_eq!(created["label"], "Grandma");
+        assert!(db::is_share_token_active(&pool, token).await.unwrap());
+
+        let (status, _) = send(&pool, "POST", "/share-tokens", None).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (_, listed) = get_json(&pool, "/share-tokens").await;
+        assert_eq!(listed.as_array().unwrap().len(), 2);
+        assert_eq!(listed[1]["label"], "Grandma");
+
+        let uri = format!("/share-tokens/{}", created["id"]);
+        assert_eq!(send(&pool, "DELETE", &uri, None).await.0, StatusCode::NO_CONTENT);
+        assert!(!db::is_share_token_active(&pool, token).await.unwrap());
+        assert_eq!(send(&pool, "DELETE", &uri, None).await.0, StatusCode::NOT_FOUND);
+        let (_, listed) = get_json(&pool, "/share-tokens").await;
+        assert!(listed[1]["revoked_at"].is_string());
+    }
+
+    // ========================================================================
+    // Schedule tests
+    // ========================================================================
+
+    async fn put_schedule(pool: &DbPool, uri: &str, body: serde_json::Value) -> (StatusCode, serde_json::Value) {
+        let app = router(ApiAccess::Open).with_state(pool.clone());
+        let request = Request::builder()
+            .method("PUT")
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_schedule_round_trips_through_get_and_put() {
+        let pool = test_pool("api_schedule_round_trips_through_get_and_put").await;
+        insert_task(&pool, 1, "Bins").await;
+
+        let (status, schedule) = get_json(&pool, "/tasks/1/schedule").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(schedule["kind"], "n_days");
+        assert_eq!(schedule["skip_weekends"], "off");
+
+        let weekly = serde_json::json!({
+            "kind": "n_weeks",
+            "weeks": 2,
+            "offset": 3,
+            "sub_schedule": {
+                "sunday": false, "monday": false, "tuesday": true, "wednesday": false,
+                "thursday": false, "friday": true, "saturday": false, "time": "18:30:00"
+            }
+        });
+        let (status, saved) = put_schedule(&pool, "/tasks/1/schedule", weekly).await;
+        assert_eq!(status, StatusCode::OK);
+        // The offset is wrapped into the cycle, as the form does
+        assert_eq!(saved["offset"], 1);
+        let (_, schedule) = get_json(&pool, "/tasks/1/schedule").await;
+        assert_eq!(schedule, saved);
+        assert_eq!(db::get_task(&pool, 1).await.unwrap().unwrap().name, "Bins");
+    }
+
+    #[tokio::test]
+    async fn test_schedule_open_ended_days_are_negative() {
+        let pool = test_pool("api_schedule_open_ended_days_are_negative").await;
+        insert_task(&pool, 1, "Rent").await;
+
+        let open_ended = serde_json::json!({ "kind": "monthwise", "days": [1, -20], "time": "09:00:00" });
+        let (status, saved) = put_schedule(&pool, "/tasks/1/schedule", open_ended).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(saved["days"], serde_json::json!([1, -20]));
+
+        let task = db::get_task(&pool, 1).await.unwrap().unwrap();
+        assert_eq!(tasks::describe_schedule(&task), "Monthly on day(s) 1, 20-");
+    }
+
+    #[tokio::test]
+    async fn test_schedule_put_rejects_what_the_form_would() {
+        let pool = test_pool("api_schedule_put_rejects_what_the_form_would").await;
+        insert_task(&pool, 1, "Bins").await;
+
+        let bad_days = serde_json::json!({ "kind": "monthwise", "days": [40], "time": "09:00:00" });
+        let (status, body) = put_schedule(&pool, "/tasks/1/schedule", bad_days).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "invalid_request");
+
+        let no_months = serde_json::json!({ "kind": "certain_months", "months": [], "days": [1], "time": "09:00:00" });
+        let (status, _) = put_schedule(&pool, "/tasks/1/schedule", no_months).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = put_schedule(&pool, "/tasks/1/schedule", serde_json::json!({ "kind": "yearly" })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "invalid_request");
+
+        let (_, schedule) = get_json(&pool, "/tasks/1/schedule").await;
+        assert_eq!(schedule["kind"], "n_days");
+    }
+
+    // ========================================================================
+    // Day tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_day_lists_tasks_due_that_date() {
+        let pool = test_pool("api_day_lists_tasks_due_that_date").await;
+        sqlx::query("INSERT INTO schedules (id, kind, once_datetime) VALUES (1, 'once', '2025-12-25T18:00:00+00:00')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO schedules (id, kind, once_datetime) VALUES (2, 'once', '2025-12-26T08:00:00+00:00')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO schedules (id, kind, monthwise_days, monthwise_time) VALUES (3, 'monthwise', '25', '07:30')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO tasks (id, name, schedule_id) VALUES (1, 'Dinner', 1), (2, 'Boxing Day', 2), (3, 'Rent', 3)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (status, days) = get_json(&pool, "/day/2025-12-25").await;
+        assert_eq!(status, StatusCode::OK);
+        let names: Vec<&str> = days.as_array().unwrap().iter().map(|t| t["name"].as_str().unwrap()).collect();
+        assert_eq!(names, vec!["Rent", "Dinner"]);
+        assert_eq!(days[0]["due_time"], "07:30");
+        assert_eq!(days[1]["due_at"], "2025-12-25T18:00:00+00:00");
+
+        let (status, body) = get_json(&pool, "/day/25-12-2025").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "invalid_request");
+    }
+
+    // ========================================================================
+    // History tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_history_pages_with_cursor_hints() {
+        let pool = test_pool("api_history_pages_with_cursor_hints").await;
+        insert_task(&pool, 1, "Dishes").await;
+        for day in 1..=5 {
+            let completed_at = chrono::DateTime::parse_from_rfc3339(&format!("2026-03-0{}T12:00:00+00:00", day)).unwrap();
+            db::add_completion_at(&pool, "1", completed_at.into(), None, None, None).await.unwrap();
+        }
+
+        let (status, page) = get_json(&pool, "/history?page=2&per_page=2").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page["total"], 5);
+        assert_eq!(page["total_pages"], 3);
+        assert_eq!(page["items"].as_array().unwrap().len(), 2);
+        assert_eq!(page["items"][0]["task_name"], "Dishes");
+        assert!(page["items"][0]["completed_at"].as_str().unwrap().starts_with("2026-03-03"));
+        assert_eq!(page["next"], "/api/history?page=3&per_page=2");
+        assert_eq!(page["prev"], "/api/history?page=1&per_page=2");
+
+        let (_, page) = get_json(&pool, "/history?page=3&per_page=2").await;
+        assert_eq!(page["items"].as_array().unwrap().len(), 1);
+        assert!(page["next"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_history_empty() {
+        let (status, page) = get_json(&test_pool("api_history_empty").await, "/history").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page["total"], 0);
+        assert!(page["items"].as_array().unwrap().is_empty());
+        assert!(page["next"].is_null() && page["prev"].is_null());
+    }
+
+    // ========================================================================
+    // Recompute tests
+    // ========================================================================
+
+    async fn recompute_once(pool: &DbPool) -> serde_json::Value {
+        let app = admin_router(ApiAccess::Open).with_state(pool.clone());
+        let request = Request::builder().method("POST").uri("/recompute").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_recompute_repairs_and_is_idempotent() {
+        let pool = test_pool("api_recompute_repairs_and_is_idempotent").await;
+        sqlx::query("INSERT INTO schedules (id, kind) VALUES (1, 'bogus')").execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO tasks (id, name, schedule_id) VALUES (1, 'Dishes', 1)").execute(&pool).await.unwrap();
+        // Simulate a hand-edited database where a task's schedule row is gone.
+        // The pragma is per connection, so switch it off and back on for just this one.
+        let mut conn = pool.acquire().await.unwrap();
+        sqlx::query("PRAGMA foreign_keys = OFF").execute(&mut *conn).await.unwrap();
+        sqlx::query("INSERT INTO tasks (id, name, schedule_id) VALUES (2, 'Trash', 99)").execute(&mut *conn).await.unwrap();
+        sqlx::query("PRAGMA foreign_keys = ON").execute(&mut *conn).await.unwrap();
+        drop(conn);
+        sqlx::query("INSERT INTO completions (task_id, completed_at) VALUES ('1', '2026-01-02T03:04:05+00:00')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let report = recompute_once(&pool).await;
+        assert_eq!(report["created_at_backfilled"].as_array().unwrap().len(), 2);
+        assert_eq!(report["schedules_repaired"].as_array().unwrap().len(), 2);
+
+        let task = db::get_task(&pool, 1).await.unwrap().unwrap();
+        assert_eq!(task.created_at.unwrap().to_rfc3339(), "2026-01-02T03:04:05+00:00");
+        assert!(db::get_all_tasks(&pool).await.is_ok());
+
+        let report = recompute_once(&pool).await;
+        assert!(report["created_at_backfilled"].as_array().unwrap().is_empty());
+        assert!(report["schedules_repaired"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_recompute_requires_token() {
+        let app = admin_router(ApiAccess::Token("secret".to_string())).with_state(test_pool("api_recompute_requires_token").await);
+        let request = Request::builder().method("POST").uri("/recompute").body(Body::empty()).unwrap();
+        assert_eq!(app.oneshot(request).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,94 @@
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use subtle::ConstantTimeEq;
+
+/// Whether a secret from a request matches the configured one, in time that
+/// doesn't depend on where they first differ
+pub fn secrets_match(given: &str, expected: &str) -> bool {
+    given.as_bytes().ct_eq(expected.as_bytes()).into()
+}
+
+/// The password from an `Authorization: Basic` header; the user name is ignored
+fn basic_auth_password(headers: &HeaderMap) -> Option<String> {
+    let encoded = headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Basic ")?;
+    let decoded = String::from_utf8(STANDARD.decode(encoded.trim()).ok()?).ok()?;
+    let (_user, password) = decoded.split_once(':')?;
+    Some(password.to_string())
+}
+
+/// With APP_PASSWORD set, every route behind this needs it, sent with HTTP Basic
+/// auth under any user name. The guest board, share links, completion links and
+/// the token-protected API stay outside.
+pub async fn require_login(State(password): State<Option<String>>, request: Request, next: Next) -> Response {
+    let Some(expected) = password else {
+        return next.run(request).await;
+    };
+    let authorized = basic_auth_password(request.headers()).is_some_and(|given| secrets_match(&given, &expected));
+    if !authorized {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, r#"Basic realm="Chores", charset="UTF-8""#)],
+            "Log in to use Chores.",
+        )
+            .into_response();
+    }
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    async fn call(password: Option<&str>, auth: Option<&str>) -> Response {
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .route_layer(middleware::from_fn_with_state(password.map(str::to_string), require_login));
+        let mut request = axum::http::Request::builder().uri("/");
+        if let Some(auth) = auth {
+            request = request.header(header::AUTHORIZATION, auth);
+        }
+        app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    fn basic(credentials: &str) -> String {
+        format!("Basic {}", STANDARD.encode(credentials))
+    }
+
+    #[test]
+    fn test_secrets_match() {
+        assert!(secrets_match("s3cret", "s3cret"));
+        assert!(!secrets_match("s3cres", "s3cret"));
+        assert!(!secrets_match("s3cret!", "s3cret"));
+        assert!(!secrets_match("", "s3cret"));
+    }
+
+    #[tokio::test]
+    async fn test_open_without_a_password() {
+        assert_eq!(call(None, None).await.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_password_required_when_set() {
+        let response = call(Some("s3cret"), None).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(response.headers()[header::WWW_AUTHENTICATE].to_str().unwrap().starts_with("Basic "));
+
+        assert_eq!(call(Some("s3cret"), Some(&basic("me:wrong"))).await.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(call(Some("s3cret"), Some("Basic not-base64!")).await.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(call(Some("s3cret"), Some("Bearer s3cret")).await.status(), StatusCode::UNAUTHORIZED);
+        // Any user name, and a password may itself contain a colon
+        assert_eq!(call(Some("s3cret"), Some(&basic("me:s3cret"))).await.status(), StatusCode::OK);
+        assert_eq!(call(Some("a:b"), Some(&basic(":a:b"))).await.status(), StatusCode::OK);
+    }
+}
@@ -4,11 +4,18 @@
 //!        cargo run --bin backup -- --target my_backup.db
 //!        cargo run --bin backup -- --db sqlite:other.db --target backup.db
 //!
-//! Creates a backup of all database entries to a new file.
+//! Creates a backup of all database entries to a new file: schedules, tasks,
+//! templates, people, completions, the audit log, app settings and share links.
+//! Photos aren't copied; `pack` stores them in the database for moving instead.
 
+mod clock;
 mod config;
 mod db;
+mod engine;
+mod error;
+mod events;
 mod migrate;
+mod pages;
 mod schedule;
 mod settings;
 mod tasks;
@@ -16,7 +23,7 @@ mod tasks;
 use anyhow::Result;
 use chrono::Datelike;
 use clap::Parser;
-use db::{DbSchedule, DbTask};
+use db::{DbPool, DbSchedule, DbTask, DbTemplate};
 use std::collections::HashMap;
 
 #[derive(Parser, Debug)]
@@ -74,17 +81,46 @@ async fn main() -> Result<()> {
     let migrations_path = migrate::default_migrations_path();
     migrate::run_up(&target_pool, &migrations_path, None).await?;
 
-    // Copy schedules
+    copy_all(&source_pool, &target_pool).await?;
+
+    println!("\nBackup completed successfully!");
+    println!("Backup saved to: {}", target_file);
+
+    Ok(())
+}
+
+
+/// Copy every backed-up table, parents before the rows that reference them
+async fn copy_all(source: &DbPool, target: &DbPool) -> Result<()> {
     println!("Copying schedules...");
+    println!("  Copied {} schedules", copy_schedules(source, target).await?);
+    println!("Copying tasks...");
+    println!("  Copied {} tasks", copy_tasks(source, target).await?);
+    println!("Copying templates...");
+    println!("  Copied {} templates", copy_templates(source, target).await?);
+    println!("Copying people...");
+    println!("  Copied {} people", copy_people(source, target).await?);
+    println!("Copying completions...");
+    println!("  Copied {} completions", copy_completions(source, target).await?);
+    println!("Copying audit log...");
+    println!("  Copied {} audit entries", copy_audit_log(source, target).await?);
+    println!("Copying app settings...");
+    println!("  Copied {} app settings", copy_app_settings(source, target).await?);
+    println!("Copying share links...");
+    println!("  Copied {} share links", copy_share_tokens(source, target).await?);
+    Ok(())
+}
+
+async fn copy_schedules(source: &DbPool, target: &DbPool) -> Result<usize> {
     let schedules: Vec<DbSchedule> = sqlx::query_as("SELECT * FROM schedules")
-        .fetch_all(&source_pool)
+        .fetch_all(source)
         .await?;
 
     for s in &schedules {
         sqlx::query(
             r#"INSERT INTO schedules (
                 id, kind,
-                ndays_days, ndays_time,
+                ndays_days, ndays_time, ndays_skip_weekends,
                 nweeks_weeks, nweeks_offset, nweeks_sunday, nweeks_monday, nweeks_tuesday,
                 nweeks_wednesday, nweeks_thursday, nweeks_friday, nweeks_saturday, nweeks_time,
                 monthwise_days, monthwise_time,
@@ -93,12 +129,13 @@ async fn main() -> Result<()> {
                 weeks_of_month_friday, weeks_of_month_saturday, weeks_of_month_time,
                 certain_months_months, certain_months_days, certain_months_time,
                 once_datetime
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
         )
         .bind(s.id)
         .bind(&s.kind)
         .bind(s.ndays_days)
         .bind(&s.ndays_time)
+        .bind(&s.ndays_skip_weekends)
         .bind(s.nweeks_weeks)
         .bind(s.nweeks_offset)
         .bind(s.nweeks_sunday)
@@ -124,20 +161,24 @@ async fn main() -> Result<()> {
         .bind(&s.certain_months_days)
         .bind(&s.certain_months_time)
         .bind(&s.once_datetime)
-        .execute(&target_pool)
+        .execute(target)
         .await?;
     }
-    println!("  Copied {} schedules", schedules.len());
+    Ok(schedules.len())
+}
 
-    // Copy tasks
-    println!("Copying tasks...");
+async fn copy_tasks(source: &DbPool, target: &DbPool) -> Result<usize> {
     let tasks: Vec<DbTask> = sqlx::query_as("SELECT * FROM tasks")
-        .fetch_all(&source_pool)
+        .fetch_all(source)
         .await?;
 
     for t in &tasks {
         sqlx::query(
-            "INSERT INTO tasks (id, name, details, schedule_id, alerting_time, completeable, created_at, deleted_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            r#"INSERT INTO tasks (
+                id, name, details, schedule_id, alerting_time, completeable, created_at, deleted_at,
+                complete_label, grace_minutes, snoozed_until, notify_channels, target_count,
+                instructions, show_ahead_days, all_day
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
         )
         .bind(t.id)
         .bind(&t.name)
@@ -147,31 +188,202 @@ async fn main() -> Result<()> {
         .bind(t.completeable)
         .bind(&t.created_at)
         .bind(&t.deleted_at)
-        .execute(&target_pool)
+        .bind(&t.complete_label)
+        .bind(t.grace_minutes)
+        .bind(&t.snoozed_until)
+        .bind(&t.notify_channels)
+        .bind(t.target_count)
+        .bind(&t.instructions)
+        .bind(t.show_ahead_days)
+        .bind(t.all_day)
+        .execute(target)
         .await?;
     }
-    println!("  Copied {} tasks", tasks.len());
+    Ok(tasks.len())
+}
 
-    // Copy completions
-    println!("Copying completions...");
-    let completions: Vec<(i64, String, String)> =
-        sqlx::query_as("SELECT id, task_id, completed_at FROM completions")
-            .fetch_all(&source_pool)
-            .await?;
+async fn copy_templates(source: &DbPool, target: &DbPool) -> Result<usize> {
+    let templates: Vec<DbTemplate> = sqlx::query_as("SELECT * FROM templates")
+        .fetch_all(source)
+        .await?;
+
+    for t in &templates {
+        sqlx::query(
+            r#"INSERT INTO templates (
+                id, name, task_name, details, schedule_id, alerting_time, completeable,
+                complete_label, grace_minutes, notify_channels, target_count,
+                instructions, show_ahead_days, all_day
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(t.id)
+        .bind(&t.name)
+        .bind(&t.task_name)
+        .bind(&t.details)
+        .bind(t.schedule_id)
+        .bind(t.alerting_time)
+        .bind(t.completeable)
+        .bind(&t.complete_label)
+        .bind(t.grace_minutes)
+        .bind(&t.notify_channels)
+        .bind(t.target_count)
+        .bind(&t.instructions)
+        .bind(t.show_ahead_days)
+        .bind(t.all_day)
+        .execute(target)
+        .await?;
+    }
+    Ok(templates.len())
+}
+
+async fn copy_people(source: &DbPool, target: &DbPool) -> Result<usize> {
+    let people: Vec<(i64, String)> = sqlx::query_as("SELECT id, initials FROM people")
+        .fetch_all(source)
+        .await?;
 
-    for completion in &completions {
-        sqlx::query("INSERT INTO completions (id, task_id, completed_at) VALUES (?, ?, ?)")
-            .bind(completion.0)
-            .bind(&completion.1)
-            .bind(&completion.2)
-            .execute(&target_pool)
+    for (id, initials) in &people {
+        sqlx::query("INSERT INTO people (id, initials) VALUES (?, ?)")
+            .bind(id)
+            .bind(initials)
+            .execute(target)
             .await?;
     }
-    println!("  Copied {} completions", completions.len());
+    Ok(people.len())
+}
 
-    println!("\nBackup completed successfully!");
-    println!("Backup saved to: {}", target_file);
+/// (id, task_id, completed_at, person_id, duration_minutes, note)
+type CompletionRow = (i64, String, String, Option<i64>, Option<i64>, Option<String>);
 
-    Ok(())
+async fn copy_completions(source: &DbPool, target: &DbPool) -> Result<usize> {
+    let completions: Vec<CompletionRow> = sqlx::query_as(
+        "SELECT id, task_id, completed_at, person_id, duration_minutes, note FROM completions",
+    )
+    .fetch_all(source)
+    .await?;
+
+    for (id, task_id, completed_at, person_id, duration_minutes, note) in &completions {
+        sqlx::query(
+            "INSERT INTO completions (id, task_id, completed_at, person_id, duration_minutes, note) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id)
+        .bind(task_id)
+        .bind(completed_at)
+        .bind(person_id)
+        .bind(duration_minutes)
+        .bind(note)
+        .execute(target)
+        .await?;
+    }
+    Ok(completions.len())
+}
+
+/// (id, created_at, task_id, action, summary, schedule_snapshot)
+type AuditRow = (i64, String, String, String, String, Option<String>);
+
+async fn copy_audit_log(source: &DbPool, target: &DbPool) -> Result<usize> {
+    let entries: Vec<AuditRow> = sqlx::query_as(
+        "SELECT id, created_at, task_id, action, summary, schedule_snapshot FROM audit_log",
+    )
+    .fetch_all(source)
+    .await?;
+
+    for (id, created_at, task_id, action, summary, schedule_snapshot) in &entries {
+        sqlx::query(
+            "INSERT INTO audit_log (id, created_at, task_id, action, summary, schedule_snapshot) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id)
+        .bind(created_at)
+        .bind(task_id)
+        .bind(action)
+        .bind(summary)
+        .bind(schedule_snapshot)
+        .execute(target)
+        .await?;
+    }
+    Ok(entries.len())
 }
 
+async fn copy_app_settings(source: &DbPool, target: &DbPool) -> Result<usize> {
+    let settings = db::get_app_settings(source).await?;
+    let values: Vec<(&str, String)> = settings.iter().map(|(key, value)| (key.as_str(), value.clone())).collect();
+    db::set_app_settings(target, &values).await?;
+    Ok(settings.len())
+}
+
+async fn copy_share_tokens(source: &DbPool, target: &DbPool) -> Result<usize> {
+    let tokens: Vec<(i64, String, String, String, Option<String>)> =
+        sqlx::query_as("SELECT id, token, label, created_at, revoked_at FROM share_tokens")
+            .fetch_all(source)
+            .await?;
+
+    for (id, token, label, created_at, revoked_at) in &tokens {
+        sqlx::query("INSERT INTO share_tokens (id, token, label, created_at, revoked_at) VALUES (?, ?, ?, ?, ?)")
+            .bind(id)
+            .bind(token)
+            .bind(label)
+            .bind(created_at)
+            .bind(revoked_at)
+            .execute(target)
+            .await?;
+    }
+    Ok(tokens.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use db::test_support::test_pool;
+
+    /// Tables a backup deliberately leaves out
+    const NOT_BACKED_UP: [&str; 6] = ["migrations", "sqlite_sequence", "photos", "tags", "photo_tags", "photo_blobs"];
+
+    async fn column_names(pool: &DbPool, table: &str) -> Vec<String> {
+        let rows: Vec<(String,)> = sqlx::query_as(&format!("SELECT name FROM pragma_table_info('{}')", table))
+            .fetch_all(pool)
+            .await
+            .unwrap();
+        rows.into_iter().map(|(name,)| name).collect()
+    }
+
+    #[tokio::test]
+    async fn test_backup_round_trips_every_column() {
+        let source = test_pool("backup_source").await;
+        // One row in every backed-up table, with no column left NULL
+        for sql in [
+            "INSERT INTO schedules VALUES (1, 'n_days', 2, '08:30', 3, 1, 1, 1, 0, 1, 0, 1, '09:00', '1,15', '10:00', \
+             '1,3', 1, 0, 1, 0, 1, 0, 1, '11:00', '1,6', '5', '12:00', '2026-05-01T09:00:00+00:00', 1, 'calendar')",
+            "INSERT INTO tasks VALUES (1, 'Dishes', 'After dinner', 1, 60, 1, '2026-01-01T00:00:00+00:00', \
+             '2026-02-01T00:00:00+00:00', 'Washed', 15, '2026-02-02T00:00:00+00:00', 'webhook', 3, 'Rinse first', 2, 1)",
+            "INSERT INTO templates VALUES (1, 'Kitchen', 'Dishes', 'After dinner', 1, 60, 1, 'Washed', 15, 'webhook', 3, \
+             'Rinse first', 2, 1)",
+            "INSERT INTO people VALUES (1, 'AB')",
+            "INSERT INTO completions VALUES (1, '1', '2026-01-05T19:00:00+00:00', 1, 20, 'Quick one')",
+            "INSERT INTO audit_log VALUES (1, '2026-01-01T00:00:00+00:00', '1', 'edit', 'edited', '{\"kind\":\"n_days\"}')",
+            "INSERT INTO app_settings VALUES ('timezone', 'Europe/London')",
+            "INSERT INTO share_tokens VALUES (1, 'abc123', 'Fridge', '2026-01-01T00:00:00+00:00', '2026-01-02T00:00:00+00:00')",
+        ] {
+            sqlx::query(sql).execute(&source).await.unwrap();
+        }
+        let target = test_pool("backup_target").await;
+
+        copy_all(&source, &target).await.unwrap();
+
+        let tables: Vec<(String,)> = sqlx::query_as("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name")
+            .fetch_all(&source)
+            .await
+            .unwrap();
+        for (table,) in tables.iter().filter(|(t,)| !NOT_BACKED_UP.contains(&t.as_str())) {
+            for column in column_names(&source, table).await {
+                let sql = format!("SELECT quote({}) FROM {} ORDER BY rowid", column, table);
+                let before: Vec<(String,)> = sqlx::query_as(&sql).fetch_all(&source).await.unwrap();
+                let after: Vec<(String,)> = sqlx::query_as(&sql).fetch_all(&target).await.unwrap();
+                assert!(
+                    !before.is_empty() && before.iter().all(|(value,)| value != "NULL"),
+                    "the test fixture leaves {}.{} empty",
+                    table,
+                    column
+                );
+                assert_eq!(before, after, "{}.{} didn't survive the backup", table, column);
+            }
+        }
+    }
+}
@@ -4,9 +4,14 @@
 //!
 //! Deletes all entries from all database tables.
 
+mod clock;
 mod config;
 mod db;
+mod engine;
+mod error;
+mod events;
 mod migrate;
+mod pages;
 mod schedule;
 mod settings;
 mod tasks;
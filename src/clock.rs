@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+
+/// The current time. Schedule code calls this instead of `Utc::now()` so
+/// tests can pin "now" with `freeze`; outside tests it is the real clock.
+#[cfg(not(test))]
+pub fn now() -> DateTime<Utc> {
+    Utc::now()
+}
+
+#[cfg(test)]
+pub use frozen::{freeze, now};
+
+#[cfg(test)]
+mod frozen {
+    use super::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static FROZEN_AT: Cell<Option<DateTime<Utc>>> = const { Cell::new(None) };
+    }
+
+    pub fn now() -> DateTime<Utc> {
+        FROZEN_AT.with(Cell::get).unwrap_or_else(Utc::now)
+    }
+
+    /// Pins `now()` on the current thread until the returned guard is dropped.
+    /// Tests run one per thread, so a frozen clock never leaks into another test.
+    #[must_use]
+    pub fn freeze(at: DateTime<Utc>) -> FrozenClock {
+        let previous = FROZEN_AT.with(|frozen| frozen.replace(Some(at)));
+        FrozenClock { previous }
+    }
+
+    pub struct FrozenClock {
+        previous: Option<DateTime<Utc>>,
+    }
+
+    impl Drop for FrozenClock {
+        fn drop(&mut self) {
+            FROZEN_AT.with(|frozen| frozen.set(self.previous));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_freeze_pins_now_until_dropped() {
+        let at = DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z").unwrap().with_timezone(&Utc);
+        {
+            let _clock = freeze(at);
+            assert_eq!(now(), at);
+        }
+        assert!(now() > at);
+    }
+}
@@ -0,0 +1,295 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::Html,
+    routing::get,
+    Extension, Router,
+};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use hypertext::prelude::*;
+use sha2::Sha256;
+use tracing::info;
+
+use crate::clock;
+use crate::config::CompletionLinks;
+use crate::db::{self, DbPool};
+use crate::error::AppError;
+use crate::pages::{self, NavItem};
+use crate::reminders::Reminder;
+use crate::tasks::{self, DemoTask};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Why a completion link was refused
+#[derive(Debug, PartialEq)]
+pub enum LinkError {
+    /// Not something `sign` produced
+    Malformed,
+    /// Well-formed, but not signed with our secret (or tampered with)
+    BadSignature,
+    /// The occurrence it was made for is over
+    Expired,
+}
+
+/// The occurrence a verified link completes
+#[derive(Debug, PartialEq)]
+pub struct LinkTarget {
+    pub task_id: String,
+    pub due: DateTime<Utc>,
+}
+
+fn mac_for(secret: &str, payload: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(payload.as_bytes());
+    mac
+}
+
+/// Token for completing one occurrence: `<task id>.<due>.<expires>.<signature>`,
+/// with Unix timestamps and a hex HMAC-SHA256 of everything before it
+pub fn sign(secret: &str, task_id: &str, due: DateTime<Utc>, expires: DateTime<Utc>) -> String {
+    let payload = format!("{}.{}.{}", task_id, due.timestamp(), expires.timestamp());
+    let signature = hex::encode(mac_for(secret, &payload).finalize().into_bytes());
+    format!("{}.{}", payload, signature)
+}
+
+/// Check a token's signature and expiry. The signature is checked first, so
+/// nothing in an unsigned token is trusted, not even its expiry.
+pub fn verify(secret: &str, token: &str, now: DateTime<Utc>) -> Result<LinkTarget, LinkError> {
+    let (payload, signature) = token.rsplit_once('.').ok_or(LinkError::Malformed)?;
+    let signature = hex::decode(signature).map_err(|_| LinkError::Malformed)?;
+    mac_for(secret, payload)
+        .verify_slice(&signature)
+        .map_err(|_| LinkError::BadSignature)?;
+
+    let parse_time = |s: &str| s.parse::<i64>().ok().and_then(|t| DateTime::from_timestamp(t, 0));
+    let parts: Vec<&str> = payload.split('.').collect();
+    let [task_id, due, expires] = parts[..] else {
+        return Err(LinkError::Malformed);
+    };
+    let due = parse_time(due).ok_or(LinkError::Malformed)?;
+    let expires = parse_time(expires).ok_or(LinkError::Malformed)?;
+    if now >= expires {
+        return Err(LinkError::Expired);
+    }
+    Ok(LinkTarget { task_id: task_id.to_string(), due })
+}
+
+/// Absolute "Mark done" URL for a reminder, valid until its occurrence ends
+pub fn url_for(links: &CompletionLinks, reminder: &Reminder) -> String {
+    let token = sign(&links.secret, &reminder.task_id, reminder.due, reminder.until);
+    format!("{}/complete/{}", links.base_url, token)
+}
+
+/// Routes for `/complete/<token>`. They check only the link's signature, so
+/// they sit outside APP_PASSWORD; with `links` unset every link is a 404.
+pub fn router(links: Option<CompletionLinks>) -> Router<DbPool> {
+    Router::new()
+        .route("/complete/{token}", get(confirm).post(complete))
+        .layer(Extension(links))
+}
+
+/// The task a link completes, once its signature, expiry and the task itself check out
+async fn linked_task(pool: &DbPool, links: Option<&CompletionLinks>, token: &str) -> Result<DemoTask, AppError> {
+    let nothing_here = || AppError::NotFound("There's nothing at this address.".to_string());
+    let links = links.ok_or_else(nothing_here)?;
+    let target = verify(&links.secret, token, clock::now()).map_err(|e| match e {
+        LinkError::Expired => AppError::Validation("This link has expired; the occurrence it was for is over.".to_string()),
+        LinkError::Malformed | LinkError::BadSignature => nothing_here(),
+    })?;
+
+    let task_id = target.task_id.parse::<i64>().map_err(|_| nothing_here())?;
+    let task = db::get_task(pool, task_id)
+        .await?
+        .filter(|task| !task.is_deleted())
+        .ok_or_else(|| AppError::NotFound("That task no longer exists.".to_string()))?;
+    if !task.completeable || task.is_inactive() {
+        return Err(AppError::Validation(format!("\"{}\" can't be completed.", task.name)));
+    }
+    Ok(task)
+}
+
+/// Decided the way the dashboard decides it. A link stops working once its
+/// occurrence ends, so until then the current occurrence is either the link's
+/// own or, while it's still alerting, the one before it.
+async fn already_done(pool: &DbPool, task: &DemoTask) -> Result<bool, AppError> {
+    Ok(tasks::occurrence_completed(pool, task, task.most_recent_due_date()).await?)
+}
+
+// GET /complete/:token - Ask before completing the occurrence a reminder link was made for.
+// Only the POST from this page completes it, so link scanners and mail clients
+// that prefetch the link don't.
+async fn confirm(
+    State(pool): State<DbPool>,
+    Extension(links): Extension<Option<CompletionLinks>>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<Html<String>, AppError> {
+    let task = linked_task(&pool, links.as_ref(), &token).await?;
+    let is_touch = crate::settings::is_touch_mode(&headers);
+    if already_done(&pool, &task).await? {
+        let message = format!("\"{}\" was already marked done.", task.name);
+        return Ok(Html(render_confirmation(&task.id, &message, is_touch)));
+    }
+    Ok(Html(render_confirm_page(&task, &token, is_touch)))
+}
+
+// POST /complete/:token - Complete the occurrence a reminder link was made for.
+// Sending it twice doesn't complete it twice.
+async fn complete(
+    State(pool): State<DbPool>,
+    Extension(links): Extension<Option<CompletionLinks>>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<Html<String>, AppError> {
+    let task = linked_task(&pool, links.as_ref(), &token).await?;
+    let message = if already_done(&pool, &task).await? {
+        format!("\"{}\" was already marked done.", task.name)
+    } else {
+        db::add_completion(&pool, &task.id, None, None, None).await?;
+        info!(task_id = %task.id, "Task completed from a reminder link");
+        tasks::record_audit(&pool, &task.id, "complete", "completed from a reminder link").await;
+        format!("\"{}\" is marked done.", task.name)
+    };
+
+    let is_touch = crate::settings::is_touch_mode(&headers);
+    Ok(Html(render_confirmation(&task.id, &message, is_touch)))
+}
+
+fn render_confirm_page(task: &DemoTask, token: &str, is_touch: bool) -> String {
+    let action = format!("/complete/{}", token);
+    let question = format!("Mark \"{}\" as done?", task.name);
+    let body = maud! {
+        div .not-found-page {
+            div .window {
+                div .title-bar {
+                    h1 .title { "Mark done?" }
+                }
+                div .window-pane {
+                    p { (question) }
+                    form method="post" action=(action) {
+                        div .not-found-links {
+                            button .btn type="submit" { "Mark done" }
+                            a .btn href="/" { "Home" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    .render()
+    .into_inner();
+    pages::render_page("Mark done", &body, is_touch, NavItem::Other)
+}
+
+fn render_confirmation(task_id: &str, message: &str, is_touch: bool) -> String {
+    let task_url = format!("/tasks/{}", task_id);
+    let body = maud! {
+        div .not-found-page {
+            div .window {
+                div .title-bar {
+                    h1 .title { "Done" }
+                }
+                div .window-pane {
+                    p { (message) }
+                    div .not-found-links {
+                        a .btn href=(task_url) { "View task" }
+                        a .btn href="/" { "Home" }
+                    }
+                }
+            }
+        }
+    }
+    .render()
+    .into_inner();
+    pages::render_page("Done", &body, is_touch, NavItem::Other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_signed_token_verifies() {
+        let due = at("2026-03-10T09:00:00Z");
+        let token = sign("secret", "7", due, at("2026-03-11T09:00:00Z"));
+        let target = verify("secret", &token, at("2026-03-10T09:05:00Z")).unwrap();
+        assert_eq!(target, LinkTarget { task_id: "7".to_string(), due });
+    }
+
+    #[test]
+    fn test_forged_tokens_are_rejected() {
+        let token = sign("secret", "7", at("2026-03-10T09:00:00Z"), at("2026-03-11T09:00:00Z"));
+        let now = at("2026-03-10T09:05:00Z");
+        assert_eq!(verify("other secret", &token, now), Err(LinkError::BadSignature));
+
+        // Pointing a valid signature at another task doesn't work
+        let retargeted = format!("8{}", &token[1..]);
+        assert_eq!(verify("secret", &retargeted, now), Err(LinkError::BadSignature));
+
+        assert_eq!(verify("secret", "garbage", now), Err(LinkError::Malformed));
+        assert_eq!(verify("secret", "7.1.2.zz", now), Err(LinkError::Malformed));
+    }
+
+    #[test]
+    fn test_token_expires_when_the_occurrence_ends() {
+        let expires = at("2026-03-11T09:00:00Z");
+        let token = sign("secret", "7", at("2026-03-10T09:00:00Z"), expires);
+        assert!(verify("secret", &token, expires - Duration::seconds(1)).is_ok());
+        assert_eq!(verify("secret", &token, expires), Err(LinkError::Expired));
+    }
+
+    #[tokio::test]
+    async fn test_get_asks_and_post_completes() {
+        use crate::db::test_support::test_pool;
+        use crate::schedule::ScheduleKind;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let pool = test_pool("completion_link_handlers").await;
+        let due = Utc::now() - Duration::minutes(5);
+        let mut task = tasks::create_default_task();
+        task.name = "Dishes".to_string();
+        task.schedule_kind = ScheduleKind::Once;
+        task.once.datetime = due;
+        let id = db::save_task(&pool, &task).await.unwrap().to_string();
+
+        let links = CompletionLinks { secret: "secret".to_string(), base_url: "https://chores.example.com".to_string() };
+        let uri = format!("/complete/{}", sign(&links.secret, &id, due, Utc::now() + Duration::days(1)));
+        let app = router(Some(links)).with_state(pool.clone());
+        let send = |method: &str| {
+            let request = Request::builder().method(method).uri(&uri).body(Body::empty()).unwrap();
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                String::from_utf8(body.to_vec()).unwrap()
+            }
+        };
+        let completions = || async { db::get_all_completions(&pool, &id).await.unwrap().len() };
+
+        // Opening the link (or a scanner prefetching it) only asks
+        let page = send("GET").await;
+        assert!(page.contains(&format!(r#"<form method="post" action="{}">"#, uri)), "{}", page);
+        assert_eq!(completions().await, 0);
+
+        assert!(send("POST").await.contains("is marked done"));
+        assert_eq!(completions().await, 1);
+
+        assert!(send("POST").await.contains("was already marked done"));
+        assert!(send("GET").await.contains("was already marked done"));
+        assert_eq!(completions().await, 1);
+
+        // Without a secret configured, links lead nowhere
+        let request = Request::builder().uri(&uri).body(Body::empty()).unwrap();
+        let response = router(None).with_state(pool.clone()).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
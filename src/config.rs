@@ -1,38 +1,760 @@
-use std::sync::OnceLock;
+use std::sync::{OnceLock, PoisonError, RwLock};
+use chrono::NaiveTime;
 use chrono_tz::Tz;
 
+use crate::schedule::ScheduleKind;
+
+// The settings below can be changed at runtime from /settings/app, so they
+// sit behind a RwLock rather than a OnceLock. Startup config provides the
+// initial values and the app_settings table overrides them.
+
 /// Global timezone setting for the application
-static APP_TIMEZONE: OnceLock<Tz> = OnceLock::new();
+static APP_TIMEZONE: RwLock<Tz> = RwLock::new(chrono_tz::UTC);
 
 /// Global touch mode setting (use buttons instead of links)
-static TOUCH_MODE: OnceLock<bool> = OnceLock::new();
+static TOUCH_MODE: RwLock<bool> = RwLock::new(false);
+
+/// Show ISO week numbers beside the task calendar
+static SHOW_WEEK_NUMBERS: RwLock<bool> = RwLock::new(false);
+
+/// Global time-of-day display format
+static TIME_FORMAT: RwLock<TimeFormat> = RwLock::new(TimeFormat::Minutes);
+
+fn read<T: Copy>(lock: &RwLock<T>) -> T {
+    *lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write<T>(lock: &RwLock<T>, value: T) {
+    *lock.write().unwrap_or_else(PoisonError::into_inner) = value;
+}
+
+/// Global landing page for `/`
+static HOME_ROUTE: OnceLock<HomeRoute> = OnceLock::new();
+
+/// Which page `/` lands on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HomeRoute {
+    /// Render the dashboard (the default)
+    Dashboard,
+    /// Redirect to the task management list at /tasks
+    Tasks,
+}
+
+/// Global reminder webhook URL (reminders are disabled when unset)
+static REMINDER_WEBHOOK: OnceLock<Option<String>> = OnceLock::new();
+
+/// Global quiet hours during which reminders are held back
+static QUIET_HOURS: OnceLock<Option<QuietHours>> = OnceLock::new();
+
+/// A local time-of-day window during which no notifications are sent.
+/// The window may wrap past midnight (e.g. 22:00 to 07:00).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Check whether the given local time falls inside quiet hours (start inclusive, end exclusive)
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// Global daily digest settings (no digest when unset)
+static DIGEST: OnceLock<Option<Digest>> = OnceLock::new();
+
+/// A once-a-day summary of the next day's tasks, sent through the reminder webhook
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Digest {
+    /// Local time of day the digest goes out
+    pub time: NaiveTime,
+    pub mode: DigestMode,
+}
+
+/// Whether the digest comes on top of per-task reminders or instead of them
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DigestMode {
+    /// Send the digest and keep sending per-task reminders (the default)
+    Supplement,
+    /// Send only the digest
+    Replace,
+}
+
+/// Days after which past Once tasks drop off the dashboard's Completed section (None: never)
+static ARCHIVE_AFTER_DAYS: OnceLock<Option<i64>> = OnceLock::new();
+
+/// How long completions are kept before pruning; None keeps everything
+static COMPLETION_RETENTION_DAYS: OnceLock<Option<i64>> = OnceLock::new();
+
+/// Shortest gap, in minutes, between two reminders for the same task
+static MIN_NOTIFY_INTERVAL: OnceLock<i64> = OnceLock::new();
+
+/// Used when MIN_NOTIFY_INTERVAL isn't set
+const DEFAULT_MIN_NOTIFY_MINUTES: i64 = 15;
+
+/// Local hour (0-23) at which a new day starts for "Today"/"Tomorrow" labels
+static DAY_START_HOUR: OnceLock<u32> = OnceLock::new();
+
+/// Schedule kind preselected when creating a task
+static DEFAULT_SCHEDULE_KIND: OnceLock<ScheduleKind> = OnceLock::new();
+
+/// Global default notification channels, for tasks that don't pick their own
+static NOTIFY_CHANNELS: OnceLock<Vec<NotifyChannel>> = OnceLock::new();
+
+/// Where a task's reminders are delivered
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NotifyChannel {
+    /// POST to REMINDER_WEBHOOK_URL
+    Webhook,
+}
+
+/// Parse a comma-separated channel list such as "webhook" or "none".
+/// "none" or an empty list means no reminders; unrecognized names are skipped.
+pub fn parse_notify_channels(s: &str) -> Vec<NotifyChannel> {
+    s.split(',')
+        .map(|c| c.trim().to_lowercase())
+        .filter_map(|c| match c.as_str() {
+            "webhook" => Some(NotifyChannel::Webhook),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Global access policy for the JSON API under /api
+static API_ACCESS: OnceLock<ApiAccess> = OnceLock::new();
+
+/// Password for the app itself (HTTP Basic auth); None leaves it open
+static APP_PASSWORD: OnceLock<Option<String>> = OnceLock::new();
+
+/// Read-only guest board access; None when GUEST_TOKEN is unset
+static GUEST_ACCESS: OnceLock<Option<GuestAccess>> = OnceLock::new();
+
+/// Signed "Mark done" links in reminders; None unless both a secret and a public URL are set
+static COMPLETION_LINKS: OnceLock<Option<CompletionLinks>> = OnceLock::new();
+
+/// What's needed to sign `/complete/<token>` links and make them absolute
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompletionLinks {
+    /// HMAC key; anyone who has it can forge completion links
+    pub secret: String,
+    /// Where the app is reachable from outside, without a trailing slash
+    pub base_url: String,
+}
+
+/// The shareable guest board at `/guest/<token>`
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuestAccess {
+    pub token: String,
+    /// Whether guests may mark tasks complete (they can never edit or delete)
+    pub can_complete: bool,
+}
+
+/// Who may call the JSON API
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApiAccess {
+    /// No API_TOKEN set and API_OPEN not enabled: every API call is rejected
+    Disabled,
+    /// No token required (API_OPEN=true without an API_TOKEN)
+    Open,
+    /// Callers must send `Authorization: Bearer <token>`
+    Token(String),
+}
+
+/// How times of day are displayed where precision matters (e.g. completion timestamps)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimeFormat {
+    /// 24-hour clock with minutes, e.g. "14:05"
+    Minutes,
+    /// 24-hour clock with seconds, e.g. "14:05:32"
+    Seconds,
+}
+
+impl TimeFormat {
+    /// The chrono format string for this time format
+    pub fn strftime(&self) -> &'static str {
+        match self {
+            TimeFormat::Minutes => "%H:%M",
+            TimeFormat::Seconds => "%H:%M:%S",
+        }
+    }
+
+    /// The name used in config and the app_settings table
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeFormat::Minutes => "HH:MM",
+            TimeFormat::Seconds => "HH:MM:SS",
+        }
+    }
+
+    /// Parse "HH:MM" or "HH:MM:SS", in any case
+    pub fn parse(format_str: &str) -> Option<TimeFormat> {
+        match format_str.trim().to_ascii_uppercase().as_str() {
+            "HH:MM" => Some(TimeFormat::Minutes),
+            "HH:MM:SS" => Some(TimeFormat::Seconds),
+            _ => None,
+        }
+    }
+}
 
 /// Initialize the timezone from the given string
 pub fn init_timezone(tz_str: &str) {
-    let timezone: Tz = tz_str.parse().unwrap_or_else(|_| {
-        eprintln!("Warning: Invalid timezone '{}', falling back to UTC", tz_str);
-        chrono_tz::UTC
-    });
+    let timezone: Tz = match normalize_timezone(tz_str) {
+        Ok(tz) => {
+            if tz.name() != tz_str {
+                eprintln!("Note: Timezone '{}' interpreted as '{}'", tz_str, tz.name());
+            }
+            tz
+        }
+        Err(e) => {
+            eprintln!("Warning: {}, falling back to UTC", e);
+            chrono_tz::UTC
+        }
+    };
+
+    write(&APP_TIMEZONE, timezone);
+}
+
+/// Most suggestions listed when a timezone isn't recognized
+const MAX_TIMEZONE_SUGGESTIONS: usize = 5;
+
+/// Resolve user input to an IANA timezone. Accepts exact ids, any casing
+/// ("america/new_york") and spaces for underscores ("America/New York").
+/// Otherwise returns an error naming the closest known zones.
+pub fn normalize_timezone(input: &str) -> Result<Tz, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err("Timezone is empty".to_string());
+    }
+    if let Ok(tz) = trimmed.parse::<Tz>() {
+        return Ok(tz);
+    }
 
-    if APP_TIMEZONE.set(timezone).is_err() {
-        eprintln!("Warning: Timezone already initialized");
+    let wanted = trimmed.replace(' ', "_").to_lowercase();
+    if let Some(tz) = chrono_tz::TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().to_lowercase() == wanted)
+    {
+        return Ok(*tz);
     }
+
+    let suggestions = close_timezones(&wanted);
+    if suggestions.is_empty() {
+        Err(format!(
+            "Unknown timezone '{}' (use an IANA name such as America/New_York)",
+            trimmed
+        ))
+    } else {
+        Err(format!(
+            "Unknown timezone '{}' (did you mean {}?)",
+            trimmed,
+            suggestions.join(", ")
+        ))
+    }
+}
+
+/// Known zones whose name contains the input's city part, or whose city is a
+/// couple of typos away from it. `wanted` is already lowercased.
+fn close_timezones(wanted: &str) -> Vec<&'static str> {
+    let city = wanted.rsplit('/').next().unwrap_or(wanted);
+    let mut scored: Vec<(usize, &'static str)> = chrono_tz::TZ_VARIANTS
+        .iter()
+        .filter_map(|tz| {
+            let name = tz.name();
+            let lower = name.to_lowercase();
+            let tz_city = lower.rsplit('/').next().unwrap_or(&lower);
+            if city.len() >= 3 && lower.contains(city) {
+                return Some((0, name));
+            }
+            let distance = edit_distance(city, tz_city);
+            (distance <= 2).then_some((distance, name))
+        })
+        .collect();
+    scored.sort();
+    scored.into_iter().take(MAX_TIMEZONE_SUGGESTIONS).map(|(_, name)| name).collect()
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
 }
 
 /// Get the configured timezone
 pub fn get_timezone() -> Tz {
-    *APP_TIMEZONE.get().unwrap_or(&chrono_tz::UTC)
+    read(&APP_TIMEZONE)
 }
 
 /// Initialize touch mode
 pub fn init_touch_mode(enabled: bool) {
-    if TOUCH_MODE.set(enabled).is_err() {
-        eprintln!("Warning: Touch mode already initialized");
-    }
+    write(&TOUCH_MODE, enabled);
 }
 
 /// Check if touch mode is enabled (buttons instead of links)
 pub fn is_touch_mode() -> bool {
-    *TOUCH_MODE.get().unwrap_or(&false)
+    read(&TOUCH_MODE)
+}
+
+/// Initialize the week-number column setting
+pub fn init_show_week_numbers(enabled: bool) {
+    write(&SHOW_WEEK_NUMBERS, enabled);
+}
+
+/// Whether calendars start each row with its ISO week number
+pub fn show_week_numbers() -> bool {
+    read(&SHOW_WEEK_NUMBERS)
+}
+
+/// Apply one stored or submitted app setting. Unlike the startup `init_*`
+/// functions, a bad value is rejected rather than replaced with a default.
+pub fn apply_app_setting(key: &str, value: &str) -> Result<(), String> {
+    match key {
+        "timezone" => write(&APP_TIMEZONE, normalize_timezone(value)?),
+        "time_format" => {
+            let format = TimeFormat::parse(value).ok_or_else(|| format!("Unknown time format '{}'", value.trim()))?;
+            write(&TIME_FORMAT, format);
+        }
+        "touch_mode" => write(&TOUCH_MODE, parse_flag(value)),
+        "show_week_numbers" => write(&SHOW_WEEK_NUMBERS, parse_flag(value)),
+        _ => return Err(format!("Unknown setting '{}'", key)),
+    }
+    Ok(())
+}
+
+fn parse_flag(value: &str) -> bool {
+    value.trim().eq_ignore_ascii_case("true") || value.trim() == "1"
+}
+
+/// Initialize the time format from the given string ("HH:MM" or "HH:MM:SS")
+pub fn init_time_format(format_str: &str) {
+    let format = TimeFormat::parse(format_str).unwrap_or_else(|| {
+        eprintln!("Warning: Invalid time format '{}', falling back to HH:MM", format_str);
+        TimeFormat::Minutes
+    });
+    write(&TIME_FORMAT, format);
+}
+
+/// Get the configured time format
+pub fn get_time_format() -> TimeFormat {
+    read(&TIME_FORMAT)
+}
+
+/// Initialize the landing page from the given string ("dashboard" or "tasks")
+pub fn init_home_route(route_str: &str) {
+    let route = match route_str.trim().trim_start_matches('/').to_ascii_lowercase().as_str() {
+        "" | "dashboard" => HomeRoute::Dashboard,
+        "tasks" => HomeRoute::Tasks,
+        _ => {
+            eprintln!("Warning: Invalid home route '{}', falling back to dashboard", route_str);
+            HomeRoute::Dashboard
+        }
+    };
+
+    if HOME_ROUTE.set(route).is_err() {
+        eprintln!("Warning: Home route already initialized");
+    }
+}
+
+/// Get the configured landing page
+pub fn get_home_route() -> HomeRoute {
+    *HOME_ROUTE.get().unwrap_or(&HomeRoute::Dashboard)
+}
+
+/// Initialize the reminder webhook URL; an empty string disables reminders
+pub fn init_reminder_webhook(url: &str) {
+    let url = url.trim();
+    let value = if url.is_empty() { None } else { Some(url.to_string()) };
+
+    if REMINDER_WEBHOOK.set(value).is_err() {
+        eprintln!("Warning: Reminder webhook already initialized");
+    }
+}
+
+/// Get the configured reminder webhook URL, if any
+pub fn get_reminder_webhook() -> Option<String> {
+    REMINDER_WEBHOOK.get().cloned().flatten()
+}
+
+/// Initialize the default notification channels (e.g. "webhook" or "none")
+pub fn init_notify_channels(channels_str: &str) {
+    for name in channels_str.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+        if parse_notify_channels(name).is_empty() && !name.eq_ignore_ascii_case("none") {
+            eprintln!("Warning: Unknown notification channel '{}', ignoring", name);
+        }
+    }
+
+    if NOTIFY_CHANNELS.set(parse_notify_channels(channels_str)).is_err() {
+        eprintln!("Warning: Notification channels already initialized");
+    }
+}
+
+/// Get the default notification channels (webhook unless configured otherwise)
+pub fn get_notify_channels() -> Vec<NotifyChannel> {
+    NOTIFY_CHANNELS.get().cloned().unwrap_or_else(|| vec![NotifyChannel::Webhook])
+}
+
+/// Initialize the Once-task archive age in days; empty or "never" disables archiving
+pub fn init_archive_after_days(days_str: &str) {
+    let days_str = days_str.trim();
+    let value = if days_str.is_empty() || days_str.eq_ignore_ascii_case("never") {
+        None
+    } else {
+        match days_str.parse::<i64>() {
+            Ok(days) if days >= 0 => Some(days),
+            _ => {
+                eprintln!("Warning: Invalid ARCHIVE_AFTER_DAYS '{}', never archiving", days_str);
+                None
+            }
+        }
+    };
+
+    if ARCHIVE_AFTER_DAYS.set(value).is_err() {
+        eprintln!("Warning: Archive age already initialized");
+    }
+}
+
+/// Get the Once-task archive age in days, if archiving is enabled
+pub fn get_archive_after_days() -> Option<i64> {
+    ARCHIVE_AFTER_DAYS.get().copied().flatten()
+}
+
+/// Initialize the per-task reminder interval in minutes; 0 only dedups occurrences
+pub fn init_min_notify_interval(minutes_str: &str) {
+    let minutes_str = minutes_str.trim();
+    let value = match minutes_str.parse::<i64>() {
+        Ok(minutes) if minutes >= 0 => minutes,
+        _ => {
+            eprintln!(
+                "Warning: Invalid MIN_NOTIFY_INTERVAL '{}', using {} minutes",
+                minutes_str, DEFAULT_MIN_NOTIFY_MINUTES
+            );
+            DEFAULT_MIN_NOTIFY_MINUTES
+        }
+    };
+
+    if MIN_NOTIFY_INTERVAL.set(value).is_err() {
+        eprintln!("Warning: Minimum notify interval already initialized");
+    }
+}
+
+/// Get the shortest gap allowed between reminders for one task
+pub fn get_min_notify_interval() -> chrono::Duration {
+    chrono::Duration::minutes(*MIN_NOTIFY_INTERVAL.get().unwrap_or(&DEFAULT_MIN_NOTIFY_MINUTES))
+}
+
+/// Initialize the hour the day starts at for relative date labels; 0 is midnight
+pub fn init_day_start_hour(hour_str: &str) {
+    let hour_str = hour_str.trim();
+    let value = match hour_str.parse::<u32>() {
+        Ok(hour) if hour < 24 => hour,
+        _ => {
+            eprintln!("Warning: Invalid DAY_START_HOUR '{}', using 0 (midnight)", hour_str);
+            0
+        }
+    };
+
+    if DAY_START_HOUR.set(value).is_err() {
+        eprintln!("Warning: Day start hour already initialized");
+    }
+}
+
+/// Get the local hour at which "Today" becomes "Yesterday" in date labels
+pub fn get_day_start_hour() -> u32 {
+    *DAY_START_HOUR.get().unwrap_or(&0)
+}
+
+/// Initialize the completion retention window in days; empty or "forever" keeps everything
+pub fn init_completion_retention_days(days_str: &str) {
+    let value = parse_retention_days(days_str);
+    if COMPLETION_RETENTION_DAYS.set(value).is_err() {
+        eprintln!("Warning: Completion retention already initialized");
+    }
+}
+
+fn parse_retention_days(days_str: &str) -> Option<i64> {
+    let days_str = days_str.trim();
+    if days_str.is_empty() || days_str.eq_ignore_ascii_case("forever") {
+        return None;
+    }
+    match days_str.parse::<i64>() {
+        Ok(days) if days >= 1 => Some(days),
+        _ => {
+            eprintln!("Warning: Invalid COMPLETION_RETENTION_DAYS '{}', keeping all completions", days_str);
+            None
+        }
+    }
+}
+
+/// Get the completion retention window in days, if pruning is enabled
+pub fn get_completion_retention_days() -> Option<i64> {
+    COMPLETION_RETENTION_DAYS.get().copied().flatten()
+}
+
+/// Initialize the new-task schedule kind from an identifier like "n_weeks"; empty or unknown means "once"
+pub fn init_default_schedule_kind(kind_str: &str) {
+    let kind_str = kind_str.trim();
+    let kind = if kind_str.is_empty() {
+        ScheduleKind::Once
+    } else {
+        ScheduleKind::parse(&kind_str.to_lowercase()).unwrap_or_else(|| {
+            eprintln!(
+                "Warning: Invalid DEFAULT_SCHEDULE_KIND '{}', expected one of n_days, n_weeks, monthwise, weeks_of_month, certain_months, once. Using once",
+                kind_str
+            );
+            ScheduleKind::Once
+        })
+    };
+
+    if DEFAULT_SCHEDULE_KIND.set(kind).is_err() {
+        eprintln!("Warning: Default schedule kind already initialized");
+    }
+}
+
+/// Get the schedule kind for new tasks (Once unless configured)
+pub fn get_default_schedule_kind() -> ScheduleKind {
+    DEFAULT_SCHEDULE_KIND.get().cloned().unwrap_or(ScheduleKind::Once)
+}
+
+/// Initialize quiet hours from local "HH:MM" start and end times.
+/// Quiet hours stay off unless both are given and valid.
+pub fn init_quiet_hours(start_str: &str, end_str: &str) {
+    let (start_str, end_str) = (start_str.trim(), end_str.trim());
+    let quiet_hours = if start_str.is_empty() && end_str.is_empty() {
+        None
+    } else {
+        match (
+            NaiveTime::parse_from_str(start_str, "%H:%M"),
+            NaiveTime::parse_from_str(end_str, "%H:%M"),
+        ) {
+            (Ok(start), Ok(end)) if start != end => Some(QuietHours { start, end }),
+            _ => {
+                eprintln!(
+                    "Warning: Invalid quiet hours '{}'-'{}', quiet hours disabled",
+                    start_str, end_str
+                );
+                None
+            }
+        }
+    };
+
+    if QUIET_HOURS.set(quiet_hours).is_err() {
+        eprintln!("Warning: Quiet hours already initialized");
+    }
+}
+
+/// Get the configured quiet hours, if any
+pub fn get_quiet_hours() -> Option<QuietHours> {
+    QUIET_HOURS.get().copied().flatten()
+}
+
+/// Initialize the daily digest from a local "HH:MM" time and a mode
+/// ("supplement" or "replace"). An empty time leaves the digest off.
+pub fn init_digest(time_str: &str, mode_str: &str) {
+    let time_str = time_str.trim();
+    let digest = if time_str.is_empty() {
+        None
+    } else {
+        match NaiveTime::parse_from_str(time_str, "%H:%M") {
+            Ok(time) => {
+                let mode = match mode_str.trim().to_lowercase().as_str() {
+                    "replace" => DigestMode::Replace,
+                    "supplement" | "" => DigestMode::Supplement,
+                    other => {
+                        eprintln!("Warning: Unknown DIGEST_MODE '{}', using supplement", other);
+                        DigestMode::Supplement
+                    }
+                };
+                Some(Digest { time, mode })
+            }
+            Err(_) => {
+                eprintln!("Warning: Invalid DIGEST_TIME '{}', digest disabled", time_str);
+                None
+            }
+        }
+    };
+
+    if DIGEST.set(digest).is_err() {
+        eprintln!("Warning: Digest already initialized");
+    }
 }
 
+/// Get the daily digest settings, if a digest is configured
+pub fn get_digest() -> Option<Digest> {
+    DIGEST.get().copied().flatten()
+}
+
+/// Initialize API access from the API_TOKEN and API_OPEN settings.
+/// A token always wins; without one the API is disabled unless explicitly opened.
+pub fn init_api_access(token: &str, open: bool) {
+    let token = token.trim();
+    let access = if !token.is_empty() {
+        ApiAccess::Token(token.to_string())
+    } else if open {
+        ApiAccess::Open
+    } else {
+        ApiAccess::Disabled
+    };
+
+    if API_ACCESS.set(access).is_err() {
+        eprintln!("Warning: API access already initialized");
+    }
+}
+
+/// Initialize the app password; an empty one leaves the app open
+pub fn init_app_password(password: &str) {
+    let password = (!password.is_empty()).then(|| password.to_string());
+    if APP_PASSWORD.set(password).is_err() {
+        eprintln!("Warning: App password already initialized");
+    }
+}
+
+/// Get the app password, if logging in is required
+pub fn get_app_password() -> Option<String> {
+    APP_PASSWORD.get().cloned().flatten()
+}
+
+/// Initialize the guest board; an empty token disables it
+pub fn init_guest_access(token: &str, can_complete: bool) {
+    let token = token.trim();
+    let access = (!token.is_empty()).then(|| GuestAccess {
+        token: token.to_string(),
+        can_complete,
+    });
+
+    if GUEST_ACCESS.set(access).is_err() {
+        eprintln!("Warning: Guest access already initialized");
+    }
+}
+
+/// Get the guest board settings, if the board is enabled
+pub fn get_guest_access() -> Option<GuestAccess> {
+    GUEST_ACCESS.get().cloned().flatten()
+}
+
+/// Initialize completion links from COMPLETION_LINK_SECRET and PUBLIC_URL.
+/// Both are needed; a secret without a URL is reported, since links can't be built.
+pub fn init_completion_links(secret: &str, base_url: &str) {
+    let secret = secret.trim();
+    let base_url = base_url.trim().trim_end_matches('/');
+    let links = match (secret.is_empty(), base_url.is_empty()) {
+        (false, false) => Some(CompletionLinks {
+            secret: secret.to_string(),
+            base_url: base_url.to_string(),
+        }),
+        (false, true) => {
+            eprintln!("Warning: COMPLETION_LINK_SECRET is set but PUBLIC_URL isn't; reminders won't include completion links");
+            None
+        }
+        _ => None,
+    };
+
+    if COMPLETION_LINKS.set(links).is_err() {
+        eprintln!("Warning: Completion links already initialized");
+    }
+}
+
+/// Get the completion link settings, if links are enabled
+pub fn get_completion_links() -> Option<CompletionLinks> {
+    COMPLETION_LINKS.get().cloned().flatten()
+}
+
+/// Get the configured API access policy
+pub fn get_api_access() -> ApiAccess {
+    API_ACCESS.get().cloned().unwrap_or(ApiAccess::Disabled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================================================
+    // Timezone normalization tests
+    // ========================================================================
+
+    #[test]
+    fn test_normalize_timezone_exact() {
+        assert_eq!(normalize_timezone("Europe/London").unwrap().name(), "Europe/London");
+        assert_eq!(normalize_timezone(" UTC ").unwrap().name(), "UTC");
+    }
+
+    #[test]
+    fn test_normalize_timezone_wrong_case() {
+        assert_eq!(normalize_timezone("america/new_york").unwrap().name(), "America/New_York");
+        assert_eq!(normalize_timezone("AMERICA/CHICAGO").unwrap().name(), "America/Chicago");
+        assert_eq!(normalize_timezone("est").unwrap().name(), "EST");
+    }
+
+    #[test]
+    fn test_normalize_timezone_spaces_for_underscores() {
+        assert_eq!(normalize_timezone("America/Los Angeles").unwrap().name(), "America/Los_Angeles");
+    }
+
+    #[test]
+    fn test_normalize_timezone_misspelling_suggests() {
+        let err = normalize_timezone("America/New_Yrok").unwrap_err();
+        assert!(err.contains("America/New_York"), "{}", err);
+        let err = normalize_timezone("Europe/Londn").unwrap_err();
+        assert!(err.contains("Europe/London"), "{}", err);
+    }
+
+    #[test]
+    fn test_normalize_timezone_missing_region_suggests() {
+        let err = normalize_timezone("Tokyo").unwrap_err();
+        assert!(err.contains("Asia/Tokyo"), "{}", err);
+    }
+
+    #[test]
+    fn test_normalize_timezone_garbage() {
+        assert!(normalize_timezone("").is_err());
+        let err = normalize_timezone("Not/AZone_xyzzy").unwrap_err();
+        assert!(err.contains("IANA"), "{}", err);
+    }
+
+    // ========================================================================
+    // App settings tests
+    // ========================================================================
+
+    // Only rejected values are applied here: the settings are process-wide and
+    // other tests running alongside rely on the defaults.
+    #[test]
+    fn test_apply_app_setting_rejects_bad_values() {
+        assert!(apply_app_setting("timezone", "Europe/Londn").unwrap_err().contains("Europe/London"));
+        assert!(apply_app_setting("time_format", "hh").is_err());
+        assert!(apply_app_setting("colour", "blue").is_err());
+        assert_eq!(get_timezone(), chrono_tz::UTC);
+    }
+
+    #[test]
+    fn test_time_format_names_round_trip() {
+        for format in [TimeFormat::Minutes, TimeFormat::Seconds] {
+            assert_eq!(TimeFormat::parse(format.name()), Some(format));
+        }
+        assert_eq!(TimeFormat::parse(" hh:mm:ss "), Some(TimeFormat::Seconds));
+    }
+
+    // ========================================================================
+    // Completion retention tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_retention_days() {
+        assert_eq!(parse_retention_days(""), None);
+        assert_eq!(parse_retention_days("Forever"), None);
+        assert_eq!(parse_retention_days(" 365 "), Some(365));
+        assert_eq!(parse_retention_days("0"), None);
+        assert_eq!(parse_retention_days("a year"), None);
+    }
+}
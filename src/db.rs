@@ -1,14 +1,33 @@
 use anyhow::Result;
 use chrono::{DateTime, NaiveTime, Utc};
-use sqlx::{sqlite::SqlitePool, FromRow, Row};
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqliteConnection, SqlitePool, SqlitePoolOptions},
+    FromRow, Row,
+};
 
-use crate::schedule::{CertainMonths, DaysOfWeek, Monthwise, NDays, NWeeks, Once, ScheduleKind, WeeksOfMonth};
+use serde::{Deserialize, Serialize};
+
+use crate::schedule::{CertainMonths, DaysOfWeek, Monthwise, NDays, NWeeks, Once, ScheduleKind, SkipWeekends, WeeksOfMonth};
 use crate::tasks::DemoTask;
 
 pub type DbPool = SqlitePool;
 
 pub async fn init_db(database_url: &str) -> Result<DbPool> {
-    let pool = SqlitePool::connect(database_url).await?;
+    connect(database_url.parse()?).await
+}
+
+async fn connect(options: SqliteConnectOptions) -> Result<DbPool> {
+    let pool = SqlitePoolOptions::new()
+        .after_connect(|conn, _meta| {
+            Box::pin(async move {
+                // SQLite only enforces foreign keys when asked, per connection. Don't rely
+                // on the driver's default: schedule and person references depend on it.
+                sqlx::query("PRAGMA foreign_keys = ON").execute(conn).await?;
+                Ok(())
+            })
+        })
+        .connect_with(options)
+        .await?;
     Ok(pool)
 }
 
@@ -50,17 +69,79 @@ pub async fn delete_person(pool: &DbPool, person_id: i64) -> Result<()> {
 // Completions
 // ============================================================================
 
-pub async fn add_completion(pool: &DbPool, task_id: &str, person_id: i64) -> Result<()> {
-    let now = chrono::Utc::now().to_rfc3339();
-    sqlx::query("INSERT INTO completions (task_id, completed_at, person_id) VALUES (?, ?, ?)")
+pub async fn add_completion(
+    pool: &DbPool,
+    task_id: &str,
+    person_id: Option<i64>,
+    duration_minutes: Option<i64>,
+    note: Option<&str>,
+) -> Result<()> {
+    add_completion_at(pool, task_id, chrono::Utc::now(), person_id, duration_minutes, note).await
+}
+
+/// Record a completion at a specific time, e.g. when backfilling a missed occurrence
+pub async fn add_completion_at(
+    pool: &DbPool,
+    task_id: &str,
+    completed_at: chrono::DateTime<chrono::Utc>,
+    person_id: Option<i64>,
+    duration_minutes: Option<i64>,
+    note: Option<&str>,
+) -> Result<()> {
+    sqlx::query("INSERT INTO completions (task_id, completed_at, person_id, duration_minutes, note) VALUES (?, ?, ?, ?, ?)")
         .bind(task_id)
-        .bind(now)
+        .bind(completed_at.to_rfc3339())
         .bind(person_id)
+        .bind(duration_minutes)
+        .bind(note)
         .execute(pool)
         .await?;
     Ok(())
 }
 
+/// Number of completions of any task strictly after `since`. completed_at is
+/// stored as RFC 3339 in UTC, so comparing it as text orders it by time.
+pub async fn count_all_completions_since(pool: &DbPool, since: chrono::DateTime<chrono::Utc>) -> Result<i64> {
+    let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM completions WHERE completed_at > ?")
+        .bind(since.to_rfc3339())
+        .fetch_one(pool)
+        .await?;
+    Ok(result.0)
+}
+
+/// Number of completions of a task in `[start, end)`, e.g. taps in the current
+/// occurrence of a counter task. Compared as RFC 3339 UTC text, like
+/// `count_all_completions_since`.
+pub async fn count_completions_between(
+    pool: &DbPool,
+    task_id: &str,
+    start: chrono::DateTime<chrono::Utc>,
+    end: chrono::DateTime<chrono::Utc>,
+) -> Result<i64> {
+    let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM completions WHERE task_id = ? AND completed_at >= ? AND completed_at < ?")
+        .bind(task_id)
+        .bind(start.to_rfc3339())
+        .bind(end.to_rfc3339())
+        .fetch_one(pool)
+        .await?;
+    Ok(result.0)
+}
+
+/// (task_id, completed_at) of every completion of any task at or after `since`
+pub async fn get_completions_since(pool: &DbPool, since: chrono::DateTime<chrono::Utc>) -> Result<Vec<(String, chrono::DateTime<chrono::Utc>)>> {
+    let rows: Vec<(String, String)> = sqlx::query_as("SELECT task_id, completed_at FROM completions WHERE completed_at >= ?")
+        .bind(since.to_rfc3339())
+        .fetch_all(pool)
+        .await?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|(task_id, s)| {
+            let completed_at = chrono::DateTime::parse_from_rfc3339(&s).ok()?;
+            Some((task_id, completed_at.with_timezone(&chrono::Utc)))
+        })
+        .collect())
+}
+
 /// Returns (completed_at, person_initials) for the most recent completion.
 pub async fn get_latest_completion(pool: &DbPool, task_id: &str) -> Result<Option<(chrono::DateTime<chrono::Utc>, Option<String>)>> {
     let result: Option<(String, Option<String>)> = sqlx::query_as(
@@ -85,11 +166,16 @@ pub struct CompletionRecord {
     pub id: i64,
     pub completed_at: chrono::DateTime<chrono::Utc>,
     pub person_initials: Option<String>,
+    pub duration_minutes: Option<i64>,
+    pub note: Option<String>,
 }
 
+/// (completion id, completed_at, person initials, duration minutes, note)
+type CompletionRow = (i64, String, Option<String>, Option<i64>, Option<String>);
+
 pub async fn get_all_completions(pool: &DbPool, task_id: &str) -> Result<Vec<CompletionRecord>> {
-    let results: Vec<(i64, String, Option<String>)> = sqlx::query_as(
-        "SELECT c.id, c.completed_at, p.initials \
+    let results: Vec<CompletionRow> = sqlx::query_as(
+        "SELECT c.id, c.completed_at, p.initials, c.duration_minutes, c.note \
          FROM completions c \
          LEFT JOIN people p ON c.person_id = p.id \
          WHERE c.task_id = ? \
@@ -101,18 +187,129 @@ pub async fn get_all_completions(pool: &DbPool, task_id: &str) -> Result<Vec<Com
 
     Ok(results
         .into_iter()
-        .filter_map(|(id, s, initials)| {
+        .filter_map(|(id, s, initials, duration_minutes, note)| {
             chrono::DateTime::parse_from_rfc3339(&s)
                 .ok()
                 .map(|dt| CompletionRecord {
                     id,
                     completed_at: dt.with_timezone(&chrono::Utc),
                     person_initials: initials,
+                    duration_minutes,
+                    note,
+                })
+        })
+        .collect())
+}
+
+/// Time spent on a task, summed from completion durations.
+pub struct DurationTotal {
+    pub task_id: String,
+    pub task_name: Option<String>,
+    pub total_minutes: i64,
+    pub recent_minutes: i64,
+    pub timed_completions: i64,
+}
+
+/// Returns per-task duration totals for completions that recorded a duration,
+/// with `recent_minutes` counting only completions at or after `since`.
+pub async fn get_duration_totals(pool: &DbPool, since: chrono::DateTime<chrono::Utc>) -> Result<Vec<DurationTotal>> {
+    let results: Vec<(String, Option<String>, i64, i64, i64)> = sqlx::query_as(
+        "SELECT c.task_id, t.name, \
+                SUM(c.duration_minutes), \
+                SUM(CASE WHEN c.completed_at >= ? THEN c.duration_minutes ELSE 0 END), \
+                COUNT(*) \
+         FROM completions c \
+         LEFT JOIN tasks t ON t.id = c.task_id \
+         WHERE c.duration_minutes IS NOT NULL \
+         GROUP BY c.task_id \
+         ORDER BY SUM(c.duration_minutes) DESC"
+    )
+        .bind(since.to_rfc3339())
+        .fetch_all(pool)
+        .await?;
+
+    Ok(results
+        .into_iter()
+        .map(|(task_id, task_name, total_minutes, recent_minutes, timed_completions)| DurationTotal {
+            task_id,
+            task_name,
+            total_minutes,
+            recent_minutes,
+            timed_completions,
+        })
+        .collect())
+}
+
+/// A completion joined to its task, for the cross-task history timeline.
+pub struct TimelineEntry {
+    /// The completion's own ID
+    pub id: i64,
+    pub task_id: String,
+    pub task_name: Option<String>,
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+    pub person_initials: Option<String>,
+}
+
+/// (completion id, task id, task name, completed_at, person initials)
+type TimelineRow = (i64, String, Option<String>, String, Option<String>);
+
+/// Returns completions across all tasks, newest first.
+pub async fn get_recent_completions(pool: &DbPool, offset: i64, limit: i64) -> Result<Vec<TimelineEntry>> {
+    let results: Vec<TimelineRow> = sqlx::query_as(
+        "SELECT c.id, c.task_id, t.name, c.completed_at, p.initials \
+         FROM completions c \
+         LEFT JOIN tasks t ON t.id = c.task_id \
+         LEFT JOIN people p ON c.person_id = p.id \
+         ORDER BY c.completed_at DESC \
+         LIMIT ? OFFSET ?"
+    )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(results
+        .into_iter()
+        .filter_map(|(id, task_id, task_name, s, initials)| {
+            chrono::DateTime::parse_from_rfc3339(&s)
+                .ok()
+                .map(|dt| TimelineEntry {
+                    id,
+                    task_id,
+                    task_name,
+                    completed_at: dt.with_timezone(&chrono::Utc),
+                    person_initials: initials,
                 })
         })
         .collect())
 }
 
+pub async fn get_completion_count(pool: &DbPool) -> Result<i64> {
+    let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM completions")
+        .fetch_one(pool)
+        .await?;
+    Ok(result.0)
+}
+
+/// Delete completions older than `cutoff`, except each task's most recent one so
+/// streaks and "last done" still have something to go on. Returns the number removed.
+pub async fn prune_completions(pool: &DbPool, cutoff: chrono::DateTime<chrono::Utc>) -> Result<u64> {
+    let result = sqlx::query(
+        "DELETE FROM completions \
+         WHERE completed_at < ? \
+         AND id NOT IN ( \
+             SELECT id FROM ( \
+                 SELECT id, ROW_NUMBER() OVER (PARTITION BY task_id ORDER BY completed_at DESC, id DESC) AS rank \
+                 FROM completions \
+             ) WHERE rank = 1 \
+         )"
+    )
+        .bind(cutoff.to_rfc3339())
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
 // Delete a completion by ID
 pub async fn delete_completion(pool: &DbPool, completion_id: i64) -> Result<()> {
     sqlx::query("DELETE FROM completions WHERE id = ?")
@@ -122,13 +319,114 @@ pub async fn delete_completion(pool: &DbPool, completion_id: i64) -> Result<()>
     Ok(())
 }
 
-#[derive(Debug, FromRow)]
+// ============================================================================
+// Audit Log
+// ============================================================================
+
+pub struct AuditEntry {
+    pub id: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub action: String,
+    pub summary: String,
+    /// JSON of the task's schedule as it stood after this change (see `schedule_snapshot`)
+    pub schedule_snapshot: Option<String>,
+}
+
+pub async fn add_audit_entry(pool: &DbPool, task_id: &str, action: &str, summary: &str) -> Result<()> {
+    add_audit_entry_with_snapshot(pool, task_id, action, summary, None, 0).await
+}
+
+/// Write an audit entry carrying a schedule snapshot. Only the newest `keep_snapshots`
+/// snapshots per task are kept; older entries keep their summary but lose the snapshot.
+pub async fn add_audit_entry_with_snapshot(
+    pool: &DbPool,
+    task_id: &str,
+    action: &str,
+    summary: &str,
+    snapshot: Option<&str>,
+    keep_snapshots: i64,
+) -> Result<()> {
+    let now = chrono::Utc::now().to_rfc3339();
+    sqlx::query("INSERT INTO audit_log (created_at, task_id, action, summary, schedule_snapshot) VALUES (?, ?, ?, ?, ?)")
+        .bind(now)
+        .bind(task_id)
+        .bind(action)
+        .bind(summary)
+        .bind(snapshot)
+        .execute(pool)
+        .await?;
+
+    if snapshot.is_some() {
+        sqlx::query(
+            "UPDATE audit_log SET schedule_snapshot = NULL \
+             WHERE task_id = ? AND schedule_snapshot IS NOT NULL AND id NOT IN ( \
+                 SELECT id FROM audit_log WHERE task_id = ? AND schedule_snapshot IS NOT NULL \
+                 ORDER BY id DESC LIMIT ?)",
+        )
+        .bind(task_id)
+        .bind(task_id)
+        .bind(keep_snapshots)
+        .execute(pool)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Returns the most recent audit entries for a task, newest first.
+pub async fn get_audit_log(pool: &DbPool, task_id: &str, limit: i64) -> Result<Vec<AuditEntry>> {
+    let results: Vec<(i64, String, String, String, Option<String>)> = sqlx::query_as(
+        "SELECT id, created_at, action, summary, schedule_snapshot FROM audit_log \
+         WHERE task_id = ? \
+         ORDER BY created_at DESC, id DESC LIMIT ?"
+    )
+        .bind(task_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(results
+        .into_iter()
+        .filter_map(|(id, s, action, summary, schedule_snapshot)| {
+            chrono::DateTime::parse_from_rfc3339(&s)
+                .ok()
+                .map(|dt| AuditEntry {
+                    id,
+                    created_at: dt.with_timezone(&chrono::Utc),
+                    action,
+                    summary,
+                    schedule_snapshot,
+                })
+        })
+        .collect())
+}
+
+/// Returns (created_at, snapshot) for one of a task's audit entries, if it still has a snapshot
+pub async fn get_audit_snapshot(pool: &DbPool, task_id: &str, entry_id: i64) -> Result<Option<(chrono::DateTime<chrono::Utc>, String)>> {
+    let row: Option<(String, String)> = sqlx::query_as(
+        "SELECT created_at, schedule_snapshot FROM audit_log \
+         WHERE id = ? AND task_id = ? AND schedule_snapshot IS NOT NULL",
+    )
+    .bind(entry_id)
+    .bind(task_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.and_then(|(created_at, snapshot)| {
+        chrono::DateTime::parse_from_rfc3339(&created_at)
+            .ok()
+            .map(|dt| (dt.with_timezone(&chrono::Utc), snapshot))
+    }))
+}
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
 pub struct DbSchedule {
+    #[serde(skip, default)]
     pub id: i64,
     pub kind: String,
     // NDays
     pub ndays_days: Option<i32>,
     pub ndays_time: Option<String>,
+    pub ndays_skip_weekends: Option<String>,
     // NWeeks
     pub nweeks_weeks: Option<i32>,
     pub nweeks_offset: Option<i32>,
@@ -171,6 +469,14 @@ pub struct DbTask {
     pub completeable: Option<i32>,
     pub created_at: Option<String>,
     pub deleted_at: Option<String>,
+    pub complete_label: Option<String>,
+    pub grace_minutes: Option<i64>,
+    pub snoozed_until: Option<String>,
+    pub notify_channels: Option<String>,
+    pub target_count: Option<i64>,
+    pub instructions: Option<String>,
+    pub show_ahead_days: Option<i64>,
+    pub all_day: Option<i32>,
 }
 
 #[derive(Debug, FromRow)]
@@ -180,11 +486,24 @@ pub struct DbCompletion {
     pub completed_at: String,
 }
 
-// Helper to parse time from string
+/// Time formats accepted from the database, newest first. Older rows and
+/// hand-edited or imported databases can hold seconds or a 12-hour clock.
+const TIME_FORMATS: [&str; 4] = ["%H:%M", "%H:%M:%S", "%l:%M %p", "%I:%M %p"];
+
+// Helper to parse time from string. A missing time means 09:00; one that
+// can't be read also falls back to 09:00, but says so in the log.
 fn parse_time(s: &Option<String>) -> NaiveTime {
-    s.as_ref()
-        .and_then(|t| NaiveTime::parse_from_str(t, "%H:%M").ok())
-        .unwrap_or_else(|| NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+    let default = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+    let Some(raw) = s.as_deref().map(str::trim).filter(|t| !t.is_empty()) else {
+        return default;
+    };
+    TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(raw, format).ok())
+        .unwrap_or_else(|| {
+            tracing::warn!(value = %raw, "Unreadable schedule time in the database, using 09:00");
+            default
+        })
 }
 
 // Helper to parse comma-separated integers
@@ -213,6 +532,7 @@ impl DbSchedule {
         let n_days = NDays {
             days: self.ndays_days.unwrap_or(1),
             time: parse_time(&self.ndays_time),
+            skip_weekends: SkipWeekends::parse(self.ndays_skip_weekends.as_deref().unwrap_or_default()),
         };
 
         let n_weeks = NWeeks {
@@ -266,23 +586,15 @@ impl DbSchedule {
     }
 }
 
-// Get a task by ID from the database
-pub async fn get_task(pool: &DbPool, task_id: i64) -> Result<Option<DemoTask>> {
-    let task: Option<DbTask> = sqlx::query_as("SELECT * FROM tasks WHERE id = ?")
-        .bind(task_id)
-        .fetch_optional(pool)
-        .await?;
-
-    let Some(task) = task else {
-        return Ok(None);
-    };
-
+/// Build a DemoTask from a task row, loading its schedule
+async fn load_task(pool: &DbPool, task: DbTask) -> Result<DemoTask> {
     let schedule: DbSchedule = sqlx::query_as("SELECT * FROM schedules WHERE id = ?")
         .bind(task.schedule_id)
         .fetch_one(pool)
         .await?;
 
-    let (schedule_kind, n_days, n_weeks, monthwise, weeks_of_month, certain_months, once) = schedule.to_schedule_parts();
+    let (schedule_kind, n_days, n_weeks, monthwise, weeks_of_month, certain_months, once) =
+        schedule.to_schedule_parts();
 
     let created_at = task.created_at.as_ref()
         .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
@@ -290,9 +602,12 @@ pub async fn get_task(pool: &DbPool, task_id: i64) -> Result<Option<DemoTask>> {
     let deleted_at = task.deleted_at.as_ref()
         .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
         .map(|dt| dt.with_timezone(&Utc));
+    let snoozed_until = task.snoozed_until.as_ref()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc));
 
-    Ok(Some(DemoTask {
-        id: task_id.to_string(),
+    Ok(DemoTask {
+        id: task.id.to_string(),
         name: task.name,
         details: task.details.unwrap_or_default(),
         schedule_kind,
@@ -304,9 +619,31 @@ pub async fn get_task(pool: &DbPool, task_id: i64) -> Result<Option<DemoTask>> {
         once,
         alerting_time: task.alerting_time.unwrap_or(1440), // Default 24 hours
         completeable: task.completeable.unwrap_or(1) != 0,
+        complete_label: task.complete_label.unwrap_or_default(),
+        grace_minutes: task.grace_minutes.unwrap_or(0),
+        notify_channels: task.notify_channels.unwrap_or_default(),
+        target_count: task.target_count.unwrap_or(1).max(1),
+        instructions: task.instructions.unwrap_or_default(),
+        show_ahead_days: task.show_ahead_days.unwrap_or(0).max(0),
+        all_day: task.all_day.unwrap_or(0) != 0,
+        snoozed_until,
         created_at,
         deleted_at,
-    }))
+    })
+}
+
+// Get a task by ID from the database
+pub async fn get_task(pool: &DbPool, task_id: i64) -> Result<Option<DemoTask>> {
+    let task: Option<DbTask> = sqlx::query_as("SELECT * FROM tasks WHERE id = ?")
+        .bind(task_id)
+        .fetch_optional(pool)
+        .await?;
+
+    let Some(task) = task else {
+        return Ok(None);
+    };
+
+    Ok(Some(load_task(pool, task).await?))
 }
 
 // Get all tasks from the database
@@ -318,37 +655,7 @@ pub async fn get_all_tasks(pool: &DbPool) -> Result<Vec<DemoTask>> {
     let mut result = Vec::new();
 
     for task in tasks {
-        let schedule: DbSchedule = sqlx::query_as("SELECT * FROM schedules WHERE id = ?")
-            .bind(task.schedule_id)
-            .fetch_one(pool)
-            .await?;
-
-        let (schedule_kind, n_days, n_weeks, monthwise, weeks_of_month, certain_months, once) =
-            schedule.to_schedule_parts();
-
-        let created_at = task.created_at.as_ref()
-            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-            .map(|dt| dt.with_timezone(&Utc));
-        let deleted_at = task.deleted_at.as_ref()
-            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-            .map(|dt| dt.with_timezone(&Utc));
-
-        result.push(DemoTask {
-            id: task.id.to_string(),
-            name: task.name,
-            details: task.details.unwrap_or_default(),
-            schedule_kind,
-            n_days,
-            n_weeks,
-            monthwise,
-            weeks_of_month,
-            certain_months,
-            once,
-            alerting_time: task.alerting_time.unwrap_or(1440), // Default 24 hours
-            completeable: task.completeable.unwrap_or(1) != 0,
-            created_at,
-            deleted_at,
-        });
+        result.push(load_task(pool, task).await?);
     }
 
     Ok(result)
@@ -362,6 +669,14 @@ pub async fn get_task_count(pool: &DbPool) -> Result<i64> {
     Ok(result.0)
 }
 
+// Get the IDs of every task on the tasks index, for "select all" bulk actions
+pub async fn get_all_task_ids(pool: &DbPool) -> Result<Vec<i64>> {
+    let rows: Vec<(i64,)> = sqlx::query_as("SELECT id FROM tasks ORDER BY id")
+        .fetch_all(pool)
+        .await?;
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
+
 // Get paginated tasks from the database (sorted by specified column)
 pub async fn get_tasks_paginated(
     pool: &DbPool,
@@ -385,100 +700,216 @@ pub async fn get_tasks_paginated(
     let mut result = Vec::new();
 
     for task in tasks {
-        let schedule: DbSchedule = sqlx::query_as("SELECT * FROM schedules WHERE id = ?")
-            .bind(task.schedule_id)
-            .fetch_one(pool)
-            .await?;
+        result.push(load_task(pool, task).await?);
+    }
 
-        let (schedule_kind, n_days, n_weeks, monthwise, weeks_of_month, certain_months, once) =
-            schedule.to_schedule_parts();
+    Ok(result)
+}
 
-        let created_at = task.created_at.as_ref()
-            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-            .map(|dt| dt.with_timezone(&Utc));
-        let deleted_at = task.deleted_at.as_ref()
-            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-            .map(|dt| dt.with_timezone(&Utc));
-
-        result.push(DemoTask {
-            id: task.id.to_string(),
-            name: task.name,
-            details: task.details.unwrap_or_default(),
-            schedule_kind,
-            n_days,
-            n_weeks,
-            monthwise,
-            weeks_of_month,
-            certain_months,
-            once,
-            alerting_time: task.alerting_time.unwrap_or(1440), // Default 24 hours
-            completeable: task.completeable.unwrap_or(1) != 0,
-            created_at,
-            deleted_at,
-        });
+/// Schedule fields in their database column representation
+struct ScheduleColumns {
+    kind: &'static str,
+    ndays_time: String,
+    nweeks_time: String,
+    monthwise_days: String,
+    monthwise_time: String,
+    wom_weeks: String,
+    wom_time: String,
+    cm_months: String,
+    cm_days: String,
+    cm_time: String,
+    once_datetime: String,
+}
+
+impl ScheduleColumns {
+    fn from_task(task: &DemoTask) -> Self {
+        let ndays_time = task.n_days.time.format("%H:%M").to_string();
+        let nweeks_time = task.n_weeks.sub_schedule.time.format("%H:%M").to_string();
+        let monthwise_days = task
+            .monthwise
+            .days
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let monthwise_time = task.monthwise.time.format("%H:%M").to_string();
+        let wom_weeks = task
+            .weeks_of_month
+            .weeks
+            .iter()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let wom_time = task
+            .weeks_of_month
+            .sub_schedule
+            .time
+            .format("%H:%M")
+            .to_string();
+        let cm_months = task
+            .certain_months
+            .months
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let cm_days = task
+            .certain_months
+            .days
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let cm_time = task.certain_months.time.format("%H:%M").to_string();
+        let once_datetime = task.once.datetime.to_rfc3339();
+
+        ScheduleColumns {
+            kind: task.schedule_kind.as_str(),
+            ndays_time,
+            nweeks_time,
+            monthwise_days,
+            monthwise_time,
+            wom_weeks,
+            wom_time,
+            cm_months,
+            cm_days,
+            cm_time,
+            once_datetime,
+        }
     }
+}
 
-    Ok(result)
+/// Serialize the task's schedule as JSON, using the same column layout as the schedules table
+pub fn schedule_snapshot(task: &DemoTask) -> String {
+    let cols = ScheduleColumns::from_task(task);
+    let days = &task.n_weeks.sub_schedule;
+    let wom_days = &task.weeks_of_month.sub_schedule;
+    let row = DbSchedule {
+        id: 0,
+        kind: cols.kind.to_string(),
+        ndays_days: Some(task.n_days.days),
+        ndays_time: Some(cols.ndays_time),
+        ndays_skip_weekends: Some(task.n_days.skip_weekends.as_str().to_string()),
+        nweeks_weeks: Some(task.n_weeks.weeks),
+        nweeks_offset: Some(task.n_weeks.offset),
+        nweeks_sunday: Some(days.sunday as i32),
+        nweeks_monday: Some(days.monday as i32),
+        nweeks_tuesday: Some(days.tuesday as i32),
+        nweeks_wednesday: Some(days.wednesday as i32),
+        nweeks_thursday: Some(days.thursday as i32),
+        nweeks_friday: Some(days.friday as i32),
+        nweeks_saturday: Some(days.saturday as i32),
+        nweeks_time: Some(cols.nweeks_time),
+        monthwise_days: Some(cols.monthwise_days),
+        monthwise_time: Some(cols.monthwise_time),
+        weeks_of_month_weeks: Some(cols.wom_weeks),
+        weeks_of_month_sunday: Some(wom_days.sunday as i32),
+        weeks_of_month_monday: Some(wom_days.monday as i32),
+        weeks_of_month_tuesday: Some(wom_days.tuesday as i32),
+        weeks_of_month_wednesday: Some(wom_days.wednesday as i32),
+        weeks_of_month_thursday: Some(wom_days.thursday as i32),
+        weeks_of_month_friday: Some(wom_days.friday as i32),
+        weeks_of_month_saturday: Some(wom_days.saturday as i32),
+        weeks_of_month_time: Some(cols.wom_time),
+        certain_months_months: Some(cols.cm_months),
+        certain_months_days: Some(cols.cm_days),
+        certain_months_time: Some(cols.cm_time),
+        once_datetime: Some(cols.once_datetime),
+    };
+    serde_json::to_string(&row).unwrap_or_default()
+}
+
+/// Replace the task's schedule with one from `schedule_snapshot`. Returns false if the snapshot doesn't parse.
+pub fn apply_schedule_snapshot(task: &mut DemoTask, snapshot: &str) -> bool {
+    let Ok(row) = serde_json::from_str::<DbSchedule>(snapshot) else {
+        return false;
+    };
+    let (schedule_kind, n_days, n_weeks, monthwise, weeks_of_month, certain_months, once) = row.to_schedule_parts();
+    task.schedule_kind = schedule_kind;
+    task.n_days = n_days;
+    task.n_weeks = n_weeks;
+    task.monthwise = monthwise;
+    task.weeks_of_month = weeks_of_month;
+    task.certain_months = certain_months;
+    task.once = once;
+    true
+}
+
+// Insert a new schedule row for the task's schedule, returning its ID
+async fn insert_schedule(conn: &mut SqliteConnection, task: &DemoTask) -> Result<i64> {
+    let cols = ScheduleColumns::from_task(task);
+    let schedule_result = sqlx::query(
+        r#"
+        INSERT INTO schedules (
+            kind,
+            ndays_days, ndays_time, ndays_skip_weekends,
+            nweeks_weeks, nweeks_offset, nweeks_sunday, nweeks_monday, nweeks_tuesday, nweeks_wednesday,
+            nweeks_thursday, nweeks_friday, nweeks_saturday, nweeks_time,
+            monthwise_days, monthwise_time,
+            weeks_of_month_weeks, weeks_of_month_sunday, weeks_of_month_monday,
+            weeks_of_month_tuesday, weeks_of_month_wednesday, weeks_of_month_thursday,
+            weeks_of_month_friday, weeks_of_month_saturday, weeks_of_month_time,
+            certain_months_months, certain_months_days, certain_months_time,
+            once_datetime
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(cols.kind)
+    .bind(task.n_days.days)
+    .bind(&cols.ndays_time)
+    .bind(task.n_days.skip_weekends.as_str())
+    .bind(task.n_weeks.weeks)
+    .bind(task.n_weeks.offset)
+    .bind(task.n_weeks.sub_schedule.sunday as i32)
+    .bind(task.n_weeks.sub_schedule.monday as i32)
+    .bind(task.n_weeks.sub_schedule.tuesday as i32)
+    .bind(task.n_weeks.sub_schedule.wednesday as i32)
+    .bind(task.n_weeks.sub_schedule.thursday as i32)
+    .bind(task.n_weeks.sub_schedule.friday as i32)
+    .bind(task.n_weeks.sub_schedule.saturday as i32)
+    .bind(&cols.nweeks_time)
+    .bind(&cols.monthwise_days)
+    .bind(&cols.monthwise_time)
+    .bind(&cols.wom_weeks)
+    .bind(task.weeks_of_month.sub_schedule.sunday as i32)
+    .bind(task.weeks_of_month.sub_schedule.monday as i32)
+    .bind(task.weeks_of_month.sub_schedule.tuesday as i32)
+    .bind(task.weeks_of_month.sub_schedule.wednesday as i32)
+    .bind(task.weeks_of_month.sub_schedule.thursday as i32)
+    .bind(task.weeks_of_month.sub_schedule.friday as i32)
+    .bind(task.weeks_of_month.sub_schedule.saturday as i32)
+    .bind(&cols.wom_time)
+    .bind(&cols.cm_months)
+    .bind(&cols.cm_days)
+    .bind(&cols.cm_time)
+    .bind(&cols.once_datetime)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(schedule_result.last_insert_rowid())
 }
 
 // Save (insert or update) a task to the database
 pub async fn save_task(pool: &DbPool, task: &DemoTask) -> Result<i64> {
+    // The schedule and task rows are written together or not at all
+    let mut tx = pool.begin().await?;
+    let id = save_task_tx(&mut tx, task).await?;
+    tx.commit().await?;
+    Ok(id)
+}
+
+/// `save_task` inside a transaction the caller commits, e.g. to write many
+/// tasks at once
+pub async fn save_task_tx(conn: &mut SqliteConnection, task: &DemoTask) -> Result<i64> {
     let task_id: Option<i64> = task.id.parse().ok();
 
-    let kind_str = match task.schedule_kind {
-        ScheduleKind::NDays => "n_days",
-        ScheduleKind::NWeeks => "n_weeks",
-        ScheduleKind::Monthwise => "monthwise",
-        ScheduleKind::WeeksOfMonth => "weeks_of_month",
-        ScheduleKind::CertainMonths => "certain_months",
-        ScheduleKind::Once => "once",
-    };
-
-    let ndays_time = task.n_days.time.format("%H:%M").to_string();
-    let nweeks_time = task.n_weeks.sub_schedule.time.format("%H:%M").to_string();
-    let monthwise_days = task
-        .monthwise
-        .days
-        .iter()
-        .map(|d| d.to_string())
-        .collect::<Vec<_>>()
-        .join(",");
-    let monthwise_time = task.monthwise.time.format("%H:%M").to_string();
-    let wom_weeks = task
-        .weeks_of_month
-        .weeks
-        .iter()
-        .map(|w| w.to_string())
-        .collect::<Vec<_>>()
-        .join(",");
-    let wom_time = task
-        .weeks_of_month
-        .sub_schedule
-        .time
-        .format("%H:%M")
-        .to_string();
-    let cm_months = task
-        .certain_months
-        .months
-        .iter()
-        .map(|m| m.to_string())
-        .collect::<Vec<_>>()
-        .join(",");
-    let cm_days = task
-        .certain_months
-        .days
-        .iter()
-        .map(|d| d.to_string())
-        .collect::<Vec<_>>()
-        .join(",");
-    let cm_time = task.certain_months.time.format("%H:%M").to_string();
-    let once_datetime = task.once.datetime.to_rfc3339();
+    let cols = ScheduleColumns::from_task(task);
 
     // Check if task exists
     if let Some(id) = task_id {
         let existing: Option<DbTask> = sqlx::query_as("SELECT * FROM tasks WHERE id = ?")
             .bind(id)
-            .fetch_optional(pool)
+            .fetch_optional(&mut *conn)
             .await?;
 
         if let Some(existing) = existing {
@@ -489,6 +920,7 @@ pub async fn save_task(pool: &DbPool, task: &DemoTask) -> Result<i64> {
                     kind = ?,
                     ndays_days = ?,
                     ndays_time = ?,
+                    ndays_skip_weekends = ?,
                     nweeks_weeks = ?,
                     nweeks_offset = ?,
                     nweeks_sunday = ?,
@@ -517,9 +949,10 @@ pub async fn save_task(pool: &DbPool, task: &DemoTask) -> Result<i64> {
                 WHERE id = ?
                 "#,
             )
-            .bind(kind_str)
+            .bind(cols.kind)
             .bind(task.n_days.days)
-            .bind(&ndays_time)
+            .bind(&cols.ndays_time)
+            .bind(task.n_days.skip_weekends.as_str())
             .bind(task.n_weeks.weeks)
             .bind(task.n_weeks.offset)
             .bind(task.n_weeks.sub_schedule.sunday as i32)
@@ -529,10 +962,10 @@ pub async fn save_task(pool: &DbPool, task: &DemoTask) -> Result<i64> {
             .bind(task.n_weeks.sub_schedule.thursday as i32)
             .bind(task.n_weeks.sub_schedule.friday as i32)
             .bind(task.n_weeks.sub_schedule.saturday as i32)
-            .bind(&nweeks_time)
-            .bind(&monthwise_days)
-            .bind(&monthwise_time)
-            .bind(&wom_weeks)
+            .bind(&cols.nweeks_time)
+            .bind(&cols.monthwise_days)
+            .bind(&cols.monthwise_time)
+            .bind(&cols.wom_weeks)
             .bind(task.weeks_of_month.sub_schedule.sunday as i32)
             .bind(task.weeks_of_month.sub_schedule.monday as i32)
             .bind(task.weeks_of_month.sub_schedule.tuesday as i32)
@@ -540,27 +973,34 @@ pub async fn save_task(pool: &DbPool, task: &DemoTask) -> Result<i64> {
             .bind(task.weeks_of_month.sub_schedule.thursday as i32)
             .bind(task.weeks_of_month.sub_schedule.friday as i32)
             .bind(task.weeks_of_month.sub_schedule.saturday as i32)
-            .bind(&wom_time)
-            .bind(&cm_months)
-            .bind(&cm_days)
-            .bind(&cm_time)
-            .bind(&once_datetime)
+            .bind(&cols.wom_time)
+            .bind(&cols.cm_months)
+            .bind(&cols.cm_days)
+            .bind(&cols.cm_time)
+            .bind(&cols.once_datetime)
             .bind(existing.schedule_id)
-            .execute(pool)
+            .execute(&mut *conn)
             .await?;
 
             // Update existing task
             let created_at_str = task.created_at.map(|dt| dt.to_rfc3339());
             let deleted_at_str = task.deleted_at.map(|dt| dt.to_rfc3339());
-            sqlx::query("UPDATE tasks SET name = ?, details = ?, alerting_time = ?, completeable = ?, created_at = ?, deleted_at = ? WHERE id = ?")
+            sqlx::query("UPDATE tasks SET name = ?, details = ?, alerting_time = ?, completeable = ?, complete_label = ?, grace_minutes = ?, notify_channels = ?, target_count = ?, instructions = ?, show_ahead_days = ?, all_day = ?, created_at = ?, deleted_at = ? WHERE id = ?")
                 .bind(&task.name)
                 .bind(&task.details)
                 .bind(task.alerting_time)
                 .bind(task.completeable as i32)
+                .bind(&task.complete_label)
+                .bind(task.grace_minutes)
+                .bind(&task.notify_channels)
+                .bind(task.target_count)
+                .bind(&task.instructions)
+                .bind(task.show_ahead_days)
+                .bind(task.all_day as i32)
                 .bind(&created_at_str)
                 .bind(&deleted_at_str)
                 .bind(id)
-                .execute(pool)
+                .execute(&mut *conn)
                 .await?;
 
             return Ok(id);
@@ -568,69 +1008,29 @@ pub async fn save_task(pool: &DbPool, task: &DemoTask) -> Result<i64> {
     }
 
     // Insert new schedule
-    let schedule_result = sqlx::query(
-        r#"
-        INSERT INTO schedules (
-            kind,
-            ndays_days, ndays_time,
-            nweeks_weeks, nweeks_offset, nweeks_sunday, nweeks_monday, nweeks_tuesday, nweeks_wednesday,
-            nweeks_thursday, nweeks_friday, nweeks_saturday, nweeks_time,
-            monthwise_days, monthwise_time,
-            weeks_of_month_weeks, weeks_of_month_sunday, weeks_of_month_monday,
-            weeks_of_month_tuesday, weeks_of_month_wednesday, weeks_of_month_thursday,
-            weeks_of_month_friday, weeks_of_month_saturday, weeks_of_month_time,
-            certain_months_months, certain_months_days, certain_months_time,
-            once_datetime
-        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-        "#,
-    )
-    .bind(kind_str)
-    .bind(task.n_days.days)
-    .bind(&ndays_time)
-    .bind(task.n_weeks.weeks)
-    .bind(task.n_weeks.offset)
-    .bind(task.n_weeks.sub_schedule.sunday as i32)
-    .bind(task.n_weeks.sub_schedule.monday as i32)
-    .bind(task.n_weeks.sub_schedule.tuesday as i32)
-    .bind(task.n_weeks.sub_schedule.wednesday as i32)
-    .bind(task.n_weeks.sub_schedule.thursday as i32)
-    .bind(task.n_weeks.sub_schedule.friday as i32)
-    .bind(task.n_weeks.sub_schedule.saturday as i32)
-    .bind(&nweeks_time)
-    .bind(&monthwise_days)
-    .bind(&monthwise_time)
-    .bind(&wom_weeks)
-    .bind(task.weeks_of_month.sub_schedule.sunday as i32)
-    .bind(task.weeks_of_month.sub_schedule.monday as i32)
-    .bind(task.weeks_of_month.sub_schedule.tuesday as i32)
-    .bind(task.weeks_of_month.sub_schedule.wednesday as i32)
-    .bind(task.weeks_of_month.sub_schedule.thursday as i32)
-    .bind(task.weeks_of_month.sub_schedule.friday as i32)
-    .bind(task.weeks_of_month.sub_schedule.saturday as i32)
-    .bind(&wom_time)
-    .bind(&cm_months)
-    .bind(&cm_days)
-    .bind(&cm_time)
-    .bind(&once_datetime)
-    .execute(pool)
-    .await?;
-
-    let schedule_id = schedule_result.last_insert_rowid();
+    let schedule_id = insert_schedule(conn, task).await?;
 
     // Insert new task
     let created_at_str = task.created_at.map(|dt| dt.to_rfc3339());
     let deleted_at_str = task.deleted_at.map(|dt| dt.to_rfc3339());
     let task_result = sqlx::query(
-        "INSERT INTO tasks (name, details, schedule_id, alerting_time, completeable, created_at, deleted_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        "INSERT INTO tasks (name, details, schedule_id, alerting_time, completeable, complete_label, grace_minutes, notify_channels, target_count, instructions, show_ahead_days, all_day, created_at, deleted_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
     )
     .bind(&task.name)
     .bind(&task.details)
     .bind(schedule_id)
     .bind(task.alerting_time)
     .bind(task.completeable as i32)
+    .bind(&task.complete_label)
+    .bind(task.grace_minutes)
+    .bind(&task.notify_channels)
+    .bind(task.target_count)
+    .bind(&task.instructions)
+    .bind(task.show_ahead_days)
+    .bind(task.all_day as i32)
     .bind(&created_at_str)
     .bind(&deleted_at_str)
-    .execute(pool)
+    .execute(&mut *conn)
     .await?;
 
     Ok(task_result.last_insert_rowid())
@@ -648,3 +1048,667 @@ pub async fn set_task_deleted_at(pool: &DbPool, task_id: i64, deleted_at: Option
     
     Ok(())
 }
+
+// Set alerting_time on several tasks in one transaction, so either all of them change or none do.
+// Returns (task id, previous alerting_time) for each task whose time actually changed; the
+// previous values are read in the same transaction, so they're what was overwritten.
+pub async fn set_alerting_time_batch(pool: &DbPool, task_ids: &[i64], alerting_time: i64) -> Result<Vec<(i64, i64)>> {
+    let mut tx = pool.begin().await?;
+    let mut changed = Vec::new();
+    for task_id in task_ids {
+        let previous: Option<(Option<i64>,)> = sqlx::query_as("SELECT alerting_time FROM tasks WHERE id = ?")
+            .bind(task_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        // Same default as get_task for rows without one
+        let Some(previous) = previous.map(|(minutes,)| minutes.unwrap_or(1440)) else {
+            continue;
+        };
+        if previous == alerting_time {
+            continue;
+        }
+        sqlx::query("UPDATE tasks SET alerting_time = ? WHERE id = ?")
+            .bind(alerting_time)
+            .bind(task_id)
+            .execute(&mut *tx)
+            .await?;
+        changed.push((*task_id, previous));
+    }
+    tx.commit().await?;
+    Ok(changed)
+}
+
+pub async fn get_deleted_tasks(pool: &DbPool) -> Result<Vec<DemoTask>> {
+    let tasks: Vec<DbTask> = sqlx::query_as("SELECT * FROM tasks WHERE deleted_at IS NOT NULL AND deleted_at <= ? ORDER BY deleted_at DESC")
+        .bind(Utc::now().to_rfc3339())
+        .fetch_all(pool)
+        .await?;
+
+    let mut result = Vec::new();
+    for task in tasks {
+        result.push(load_task(pool, task).await?);
+    }
+    Ok(result)
+}
+
+// Clear deleted_at on every soft-deleted task, returning the restored IDs
+pub async fn restore_all_deleted_tasks(pool: &DbPool) -> Result<Vec<i64>> {
+    let now = Utc::now().to_rfc3339();
+    // One transaction, so the ids returned are exactly the rows restored
+    let mut tx = pool.begin().await?;
+    let ids: Vec<(i64,)> = sqlx::query_as("SELECT id FROM tasks WHERE deleted_at IS NOT NULL AND deleted_at <= ?")
+        .bind(&now)
+        .fetch_all(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE tasks SET deleted_at = NULL WHERE deleted_at IS NOT NULL AND deleted_at <= ?")
+        .bind(&now)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(ids.into_iter().map(|(id,)| id).collect())
+}
+
+// Permanently remove a task with its schedule, completions and audit log
+pub async fn hard_delete_task(pool: &DbPool, task_id: i64) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    let schedule_id: Option<(i64,)> = sqlx::query_as("SELECT schedule_id FROM tasks WHERE id = ?")
+        .bind(task_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+    let task_key = task_id.to_string();
+    sqlx::query("DELETE FROM completions WHERE task_id = ?")
+        .bind(&task_key)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM audit_log WHERE task_id = ?")
+        .bind(&task_key)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM tasks WHERE id = ?")
+        .bind(task_id)
+        .execute(&mut *tx)
+        .await?;
+    if let Some((schedule_id,)) = schedule_id {
+        sqlx::query("DELETE FROM schedules WHERE id = ?")
+            .bind(schedule_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+// Set or clear the snoozed_until timestamp for a task
+pub async fn set_task_snoozed_until(pool: &DbPool, task_id: i64, snoozed_until: Option<DateTime<Utc>>) -> Result<()> {
+    sqlx::query("UPDATE tasks SET snoozed_until = ? WHERE id = ?")
+        .bind(snoozed_until.map(|dt| dt.to_rfc3339()))
+        .bind(task_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+// ============================================================================
+// Templates
+// ============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct DbTemplate {
+    pub id: i64,
+    pub name: String,
+    pub task_name: String,
+    pub details: Option<String>,
+    pub schedule_id: i64,
+    pub alerting_time: Option<i64>,
+    pub completeable: Option<i32>,
+    pub complete_label: Option<String>,
+    pub grace_minutes: Option<i64>,
+    pub notify_channels: Option<String>,
+    pub target_count: Option<i64>,
+    pub instructions: Option<String>,
+    pub show_ahead_days: Option<i64>,
+    pub all_day: Option<i32>,
+}
+
+// Save a task's schedule and metadata as a reusable template (no dates or completions)
+pub async fn save_template(pool: &DbPool, name: &str, task: &DemoTask) -> Result<i64> {
+    let mut tx = pool.begin().await?;
+    let schedule_id = insert_schedule(&mut tx, task).await?;
+    let result = sqlx::query(
+        "INSERT INTO templates (name, task_name, details, schedule_id, alerting_time, completeable, complete_label, grace_minutes, notify_channels, target_count, instructions, show_ahead_days, all_day) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(name)
+    .bind(&task.name)
+    .bind(&task.details)
+    .bind(schedule_id)
+    .bind(task.alerting_time)
+    .bind(task.completeable as i32)
+    .bind(&task.complete_label)
+    .bind(task.grace_minutes)
+    .bind(&task.notify_channels)
+    .bind(task.target_count)
+    .bind(&task.instructions)
+    .bind(task.show_ahead_days)
+    .bind(task.all_day as i32)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(result.last_insert_rowid())
+}
+
+// Every template, by name
+pub async fn get_all_templates(pool: &DbPool) -> Result<Vec<DbTemplate>> {
+    let templates = sqlx::query_as("SELECT * FROM templates ORDER BY name COLLATE NOCASE")
+        .fetch_all(pool)
+        .await?;
+    Ok(templates)
+}
+
+// Build an unsaved task from a template. The caller fills in any dates.
+pub async fn get_template_task(pool: &DbPool, template_id: i64) -> Result<Option<DemoTask>> {
+    let template: Option<DbTemplate> = sqlx::query_as("SELECT * FROM templates WHERE id = ?")
+        .bind(template_id)
+        .fetch_optional(pool)
+        .await?;
+
+    let Some(template) = template else {
+        return Ok(None);
+    };
+
+    let schedule: DbSchedule = sqlx::query_as("SELECT * FROM schedules WHERE id = ?")
+        .bind(template.schedule_id)
+        .fetch_one(pool)
+        .await?;
+
+    let (schedule_kind, n_days, n_weeks, monthwise, weeks_of_month, certain_months, once) =
+        schedule.to_schedule_parts();
+
+    Ok(Some(DemoTask {
+        id: String::new(),
+        name: template.task_name,
+        details: template.details.unwrap_or_default(),
+        schedule_kind,
+        n_days,
+        n_weeks,
+        monthwise,
+        weeks_of_month,
+        certain_months,
+        once,
+        alerting_time: template.alerting_time.unwrap_or(1440), // Default 24 hours
+        completeable: template.completeable.unwrap_or(1) != 0,
+        complete_label: template.complete_label.unwrap_or_default(),
+        grace_minutes: template.grace_minutes.unwrap_or(0),
+        notify_channels: template.notify_channels.unwrap_or_default(),
+        target_count: template.target_count.unwrap_or(1).max(1),
+        instructions: template.instructions.unwrap_or_default(),
+        show_ahead_days: template.show_ahead_days.unwrap_or(0).max(0),
+        all_day: template.all_day.unwrap_or(0) != 0,
+        snoozed_until: None,
+        created_at: None,
+        deleted_at: None,
+    }))
+}
+
+pub async fn delete_template(pool: &DbPool, template_id: i64) -> Result<()> {
+    let schedule_id: Option<(i64,)> = sqlx::query_as("SELECT schedule_id FROM templates WHERE id = ?")
+        .bind(template_id)
+        .fetch_optional(pool)
+        .await?;
+
+    sqlx::query("DELETE FROM templates WHERE id = ?")
+        .bind(template_id)
+        .execute(pool)
+        .await?;
+
+    if let Some((schedule_id,)) = schedule_id {
+        sqlx::query("DELETE FROM schedules WHERE id = ?")
+            .bind(schedule_id)
+            .execute(pool)
+            .await?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// App Settings
+// ============================================================================
+
+// Every stored app setting as (key, value)
+pub async fn get_app_settings(pool: &DbPool) -> Result<Vec<(String, String)>> {
+    let rows = sqlx::query_as("SELECT key, value FROM app_settings ORDER BY key")
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+// Insert or replace several app settings, all of them or none
+pub async fn set_app_settings(pool: &DbPool, values: &[(&str, String)]) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    for (key, value) in values {
+        sqlx::query("INSERT INTO app_settings (key, value) VALUES (?, ?) ON CONFLICT(key) DO UPDATE SET value = excluded.value")
+            .bind(key)
+            .bind(value)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+// ============================================================================
+// Share tokens
+// ============================================================================
+
+/// A revocable link to the read-only board at `/share/{token}`
+#[derive(Debug, FromRow)]
+pub struct ShareToken {
+    pub id: i64,
+    pub token: String,
+    pub label: String,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+// Store a new share token and return it
+pub async fn create_share_token(pool: &DbPool, token: &str, label: &str) -> Result<ShareToken> {
+    let id = sqlx::query("INSERT INTO share_tokens (token, label, created_at) VALUES (?, ?, ?)")
+        .bind(token)
+        .bind(label)
+        .bind(Utc::now().to_rfc3339())
+        .execute(pool)
+        .await?
+        .last_insert_rowid();
+    let created = sqlx::query_as("SELECT * FROM share_tokens WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+    Ok(created)
+}
+
+// Every share token, revoked ones included, newest first
+pub async fn get_share_tokens(pool: &DbPool) -> Result<Vec<ShareToken>> {
+    let tokens = sqlx::query_as("SELECT * FROM share_tokens ORDER BY id DESC")
+        .fetch_all(pool)
+        .await?;
+    Ok(tokens)
+}
+
+// Whether a token opens the shared board: it exists and hasn't been revoked
+pub async fn is_share_token_active(pool: &DbPool, token: &str) -> Result<bool> {
+    let found: Option<(i64,)> = sqlx::query_as("SELECT id FROM share_tokens WHERE token = ? AND revoked_at IS NULL")
+        .bind(token)
+        .fetch_optional(pool)
+        .await?;
+    Ok(found.is_some())
+}
+
+// Revoke a share token. False when there's no such token or it was already revoked.
+pub async fn revoke_share_token(pool: &DbPool, id: i64) -> Result<bool> {
+    let result = sqlx::query("UPDATE share_tokens SET revoked_at = ? WHERE id = ? AND revoked_at IS NULL")
+        .bind(Utc::now().to_rfc3339())
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+// ============================================================================
+// Repairs
+// ============================================================================
+
+// Fill in missing created_at from the task's first completion, or now if it has none.
+// Returns the names of the tasks that were updated.
+pub async fn backfill_created_at(pool: &DbPool) -> Result<Vec<String>> {
+    let rows: Vec<(i64, String, Option<String>)> = sqlx::query_as(
+        "SELECT t.id, t.name, MIN(c.completed_at) FROM tasks t \
+         LEFT JOIN completions c ON c.task_id = CAST(t.id AS TEXT) \
+         WHERE t.created_at IS NULL OR t.created_at = '' \
+         GROUP BY t.id",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut fixed = Vec::new();
+    for (id, name, first_completion) in rows {
+        let created_at = first_completion
+            .as_ref()
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+        sqlx::query("UPDATE tasks SET created_at = ? WHERE id = ?")
+            .bind(created_at.to_rfc3339())
+            .bind(id)
+            .execute(pool)
+            .await?;
+        fixed.push(name);
+    }
+    Ok(fixed)
+}
+
+// Give tasks whose schedule row is missing a fresh daily schedule, and reset
+// unknown schedule kinds to n_days (what loading already falls back to).
+// Returns a description of each repair.
+pub async fn repair_task_schedules(pool: &DbPool) -> Result<Vec<String>> {
+    let mut fixed = Vec::new();
+
+    let orphans: Vec<(i64, String)> = sqlx::query_as(
+        "SELECT t.id, t.name FROM tasks t LEFT JOIN schedules s ON s.id = t.schedule_id WHERE s.id IS NULL",
+    )
+    .fetch_all(pool)
+    .await?;
+    for (id, name) in orphans {
+        let result = sqlx::query("INSERT INTO schedules (kind, ndays_days, ndays_time) VALUES ('n_days', 1, '09:00')")
+            .execute(pool)
+            .await?;
+        sqlx::query("UPDATE tasks SET schedule_id = ? WHERE id = ?")
+            .bind(result.last_insert_rowid())
+            .bind(id)
+            .execute(pool)
+            .await?;
+        fixed.push(format!("{}: missing schedule replaced with daily at 09:00", name));
+    }
+
+    let unknown: Vec<(i64, String, String)> = sqlx::query_as(
+        "SELECT s.id, t.name, s.kind FROM tasks t JOIN schedules s ON s.id = t.schedule_id \
+         WHERE s.kind NOT IN ('n_days', 'n_weeks', 'monthwise', 'weeks_of_month', 'certain_months', 'once')",
+    )
+    .fetch_all(pool)
+    .await?;
+    for (schedule_id, name, kind) in unknown {
+        sqlx::query("UPDATE schedules SET kind = 'n_days' WHERE id = ?")
+            .bind(schedule_id)
+            .execute(pool)
+            .await?;
+        fixed.push(format!("{}: unknown schedule kind '{}' reset to n_days", name, kind));
+    }
+
+    Ok(fixed)
+}
+
+/// Database setup shared by the tests of every module
+#[cfg(test)]
+pub mod test_support {
+    use super::*;
+
+    /// A fresh, migrated in-memory database, opened through `init_db` like the
+    /// real one. The shared cache lets every pooled connection see the same
+    /// tables; a counter after `name` keeps each call's database apart.
+    pub async fn test_pool(name: &str) -> DbPool {
+        static NEXT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let n = NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let url = format!("sqlite:file:{}_{}?mode=memory&cache=shared", name, n);
+        let pool = init_db(&url).await.unwrap();
+        crate::migrate::run_up(&pool, &crate::migrate::default_migrations_path(), None)
+            .await
+            .unwrap();
+        pool
+    }
+
+    /// A daily task with the given id, on a schedule of its own with the same id
+    pub async fn insert_task(pool: &DbPool, id: i64, name: &str) {
+        sqlx::query("INSERT INTO schedules (id, kind) VALUES (?, 'n_days')")
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO tasks (id, name, schedule_id) VALUES (?, ?, ?)")
+            .bind(id)
+            .bind(name)
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::test_support::{insert_task, test_pool};
+
+    #[test]
+    fn test_parse_time_accepts_legacy_formats() {
+        let time = |s: &str| parse_time(&Some(s.to_string()));
+        let expect = |h, m, sec| NaiveTime::from_hms_opt(h, m, sec).unwrap();
+        assert_eq!(time("14:30"), expect(14, 30, 0));
+        assert_eq!(time("07:05:09"), expect(7, 5, 9));
+        assert_eq!(time("9:00 AM"), expect(9, 0, 0));
+        assert_eq!(time("9:15 pm"), expect(21, 15, 0));
+        assert_eq!(time("09:15 PM"), expect(21, 15, 0));
+        assert_eq!(time(" 18:00 "), expect(18, 0, 0));
+    }
+
+    #[test]
+    fn test_parse_time_falls_back_to_nine() {
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        assert_eq!(parse_time(&None), nine);
+        assert_eq!(parse_time(&Some(String::new())), nine);
+        assert_eq!(parse_time(&Some("teatime".to_string())), nine);
+        assert_eq!(parse_time(&Some("25:00".to_string())), nine);
+    }
+
+    #[tokio::test]
+    async fn test_init_db_enforces_foreign_keys() {
+        // Ask the driver to leave enforcement off, so only our own pragma can turn it on
+        let options: SqliteConnectOptions = "sqlite:file:fk_test?mode=memory&cache=shared".parse().unwrap();
+        let pool = connect(options.foreign_keys(false)).await.unwrap();
+        crate::migrate::run_up(&pool, &crate::migrate::default_migrations_path(), None)
+            .await
+            .unwrap();
+
+        let error = sqlx::query("INSERT INTO tasks (name, schedule_id) VALUES ('Orphan', 9999)")
+            .execute(&pool)
+            .await
+            .expect_err("task with a missing schedule should be rejected");
+        assert!(error.to_string().contains("FOREIGN KEY constraint failed"), "{}", error);
+
+        // Deleting a person clears their completions' person_id (ON DELETE SET NULL)
+        insert_task(&pool, 1, "Dishes").await;
+        add_person(&pool, "AB").await.unwrap();
+        let person = get_all_people(&pool).await.unwrap().remove(0);
+        add_completion(&pool, "1", Some(person.id), None, None).await.unwrap();
+        delete_person(&pool, person.id).await.unwrap();
+        let person_ids: Vec<(Option<i64>,)> = sqlx::query_as("SELECT person_id FROM completions")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(person_ids, vec![(None,)]);
+    }
+
+    #[tokio::test]
+    async fn test_hard_delete_removes_schedule_completions_and_audit() {
+        let pool = test_pool("hard_delete_test").await;
+        insert_task(&pool, 1, "Dishes").await;
+        insert_task(&pool, 2, "Trash").await;
+        for id in ["1", "2"] {
+            add_completion(&pool, id, None, None, None).await.unwrap();
+            add_audit_entry(&pool, id, "complete", "completed").await.unwrap();
+        }
+
+        hard_delete_task(&pool, 1).await.unwrap();
+
+        let count = |sql: &'static str| {
+            let pool = pool.clone();
+            async move { sqlx::query_as::<_, (i64,)>(sql).fetch_one(&pool).await.unwrap().0 }
+        };
+        assert_eq!(count("SELECT COUNT(*) FROM tasks WHERE id = 1").await, 0);
+        assert_eq!(count("SELECT COUNT(*) FROM schedules WHERE id = 1").await, 0);
+        assert_eq!(count("SELECT COUNT(*) FROM completions WHERE task_id = '1'").await, 0);
+        assert_eq!(count("SELECT COUNT(*) FROM audit_log WHERE task_id = '1'").await, 0);
+        // The other task is untouched
+        assert!(get_task(&pool, 2).await.unwrap().is_some());
+        assert_eq!(count("SELECT COUNT(*) FROM completions WHERE task_id = '2'").await, 1);
+        assert_eq!(count("SELECT COUNT(*) FROM audit_log WHERE task_id = '2'").await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_restore_all_deleted_tasks() {
+        let pool = test_pool("restore_all_test").await;
+        for id in 1..=3 {
+            insert_task(&pool, id, "Task").await;
+        }
+        let yesterday = Utc::now() - chrono::Duration::days(1);
+        set_task_deleted_at(&pool, 1, Some(yesterday)).await.unwrap();
+        set_task_deleted_at(&pool, 3, Some(yesterday)).await.unwrap();
+
+        let mut restored = restore_all_deleted_tasks(&pool).await.unwrap();
+        restored.sort();
+        assert_eq!(restored, vec![1, 3]);
+        assert!(get_deleted_tasks(&pool).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_count_completions_between_is_half_open() {
+        let pool = test_pool("count_between_test").await;
+        let at = |s: &str| chrono::DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&chrono::Utc);
+        for (task, time) in [
+            ("1", "2026-03-10T08:59:59.999+00:00"),
+            ("1", "2026-03-10T09:00:00+00:00"),
+            ("1", "2026-03-10T20:30:00.25+00:00"),
+            ("1", "2026-03-11T09:00:00+00:00"),
+            ("2", "2026-03-10T10:00:00+00:00"),
+        ] {
+            add_completion_at(&pool, task, at(time), None, None, None).await.unwrap();
+        }
+        let count = count_completions_between(&pool, "1", at("2026-03-10T09:00:00Z"), at("2026-03-11T09:00:00Z"))
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_app_settings_upsert() {
+        let pool = test_pool("app_settings_test").await;
+
+        set_app_settings(&pool, &[("timezone", "Europe/London".to_string()), ("time_format", "HH:MM".to_string())])
+            .await
+            .unwrap();
+        set_app_settings(&pool, &[("timezone", "Asia/Tokyo".to_string())]).await.unwrap();
+        let stored = get_app_settings(&pool).await.unwrap();
+        assert_eq!(
+            stored,
+            vec![
+                ("time_format".to_string(), "HH:MM".to_string()),
+                ("timezone".to_string(), "Asia/Tokyo".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_app_settings_saved_together() {
+        let pool = test_pool("app_settings_together_test").await;
+        set_app_settings(&pool, &[("timezone", "Europe/London".to_string())]).await.unwrap();
+        sqlx::query("CREATE TRIGGER no_week_numbers BEFORE INSERT ON app_settings WHEN NEW.key = 'show_week_numbers' BEGIN SELECT RAISE(ABORT, 'nope'); END")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let values = [("timezone", "Asia/Tokyo".to_string()), ("show_week_numbers", "true".to_string())];
+        assert!(set_app_settings(&pool, &values).await.is_err());
+        assert_eq!(get_app_settings(&pool).await.unwrap(), vec![("timezone".to_string(), "Europe/London".to_string())]);
+
+        sqlx::query("DROP TRIGGER no_week_numbers").execute(&pool).await.unwrap();
+        set_app_settings(&pool, &values).await.unwrap();
+        assert_eq!(
+            get_app_settings(&pool).await.unwrap(),
+            vec![
+                ("show_week_numbers".to_string(), "true".to_string()),
+                ("timezone".to_string(), "Asia/Tokyo".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_schedule_snapshots_are_bounded() {
+        let pool = test_pool("snapshot_test").await;
+
+        for n in 1..=3 {
+            let snapshot = format!("{{\"kind\":\"v{}\"}}", n);
+            add_audit_entry_with_snapshot(&pool, "7", "edit", "edited", Some(&snapshot), 2).await.unwrap();
+        }
+        add_audit_entry(&pool, "7", "complete", "completed").await.unwrap();
+
+        let log = get_audit_log(&pool, "7", 10).await.unwrap();
+        assert_eq!(log.len(), 4);
+        let kept: Vec<&str> = log.iter().filter_map(|e| e.schedule_snapshot.as_deref()).collect();
+        assert_eq!(kept, vec![r#"{"kind":"v3"}"#, r#"{"kind":"v2"}"#]);
+
+        let oldest = log.last().unwrap();
+        assert!(get_audit_snapshot(&pool, "7", oldest.id).await.unwrap().is_none());
+        assert!(get_audit_snapshot(&pool, "8", log[1].id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_set_alerting_time_batch() {
+        let pool = test_pool("alerting_batch_test").await;
+        for id in 1..=3 {
+            sqlx::query("INSERT INTO schedules (id, kind) VALUES (?, 'n_days')").bind(id).execute(&pool).await.unwrap();
+            sqlx::query("INSERT INTO tasks (id, name, schedule_id, alerting_time) VALUES (?, 'Task', ?, 1440)")
+                .bind(id)
+                .bind(id)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        sqlx::query("UPDATE tasks SET alerting_time = 60 WHERE id = 3").execute(&pool).await.unwrap();
+
+        // Task 3 already alerts at 60, and task 9 doesn't exist; neither counts as changed
+        let changed = set_alerting_time_batch(&pool, &[1, 3, 9], 60).await.unwrap();
+        assert_eq!(changed, vec![(1, 1440)]);
+
+        let times: Vec<(i64, i64)> = sqlx::query_as("SELECT id, alerting_time FROM tasks ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(times, vec![(1, 60), (2, 1440), (3, 60)]);
+    }
+
+    #[tokio::test]
+    async fn test_completion_note_round_trips() {
+        let pool = test_pool("completion_note_test").await;
+        insert_task(&pool, 1, "Water plants").await;
+
+        add_completion(&pool, "1", None, None, Some("Fern looks dry")).await.unwrap();
+        add_completion(&pool, "1", None, None, None).await.unwrap();
+
+        let notes: Vec<Option<String>> = get_all_completions(&pool, "1").await.unwrap().into_iter().map(|c| c.note).collect();
+        assert_eq!(notes.len(), 2);
+        assert!(notes.contains(&Some("Fern looks dry".to_string())));
+        assert!(notes.contains(&None));
+    }
+
+    #[tokio::test]
+    async fn test_prune_completions_keeps_latest_per_task() {
+        let pool = test_pool("prune_test").await;
+        sqlx::query("INSERT INTO schedules (id, kind) VALUES (1, 'n_days')").execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO tasks (id, name, schedule_id) VALUES (1, 'Dishes', 1), (2, 'Filter', 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let at = |s: &str| chrono::DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&chrono::Utc);
+        for day in ["2024-01-01", "2024-01-02", "2026-03-01"] {
+            add_completion_at(&pool, "1", at(&format!("{}T12:00:00Z", day)), None, None, None).await.unwrap();
+        }
+        // A task last done long ago keeps that one completion
+        add_completion_at(&pool, "2", at("2023-06-01T12:00:00Z"), None, None, None).await.unwrap();
+        add_completion_at(&pool, "2", at("2023-05-01T12:00:00Z"), None, None, None).await.unwrap();
+
+        let pruned = prune_completions(&pool, at("2025-01-01T00:00:00Z")).await.unwrap();
+        assert_eq!(pruned, 3);
+
+        let kept = |id: &'static str| {
+            let pool = pool.clone();
+            async move {
+                get_all_completions(&pool, id).await.unwrap().into_iter().map(|c| c.completed_at).collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(kept("1").await, vec![at("2026-03-01T12:00:00Z")]);
+        assert_eq!(kept("2").await, vec![at("2023-06-01T12:00:00Z")]);
+
+        assert_eq!(prune_completions(&pool, at("2025-01-01T00:00:00Z")).await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,312 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::schedule::{NDays, ScheduleKind, SkipWeekends};
+use crate::tasks::{day_in_range, DemoTask};
+
+// ============================================================================
+// Occurrence math
+//
+// Which local dates a task's schedule fires on, at what time, and the due
+// times around a given instant. Everything here takes the timezone and "now"
+// as arguments; the rest of the app calls through the wrappers in tasks.rs,
+// which pass the configured timezone and `clock::now()`.
+// ============================================================================
+
+/// How far ahead `next_due` looks before settling on the "distant future"
+pub const NEXT_DUE_SCAN_DAYS: i64 = 1000;
+
+/// How far back `most_recent_due` looks
+const RECENT_DUE_SCAN_DAYS: i64 = 60;
+
+/// How far ahead `next_due_after` looks for the end of an occurrence
+const FOLLOWING_DUE_SCAN_DAYS: i64 = 60;
+
+/// Furthest ahead `upcoming_after` looks; long enough for yearly schedules
+const UPCOMING_SCAN_DAYS: i64 = 731;
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Number of weekdays from a fixed Monday up to and including `date`.
+/// Differences between two dates give the weekdays in between.
+fn weekday_index(date: NaiveDate) -> i64 {
+    let epoch = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(); // a Monday
+    let days = (date - epoch).num_days();
+    days.div_euclid(7) * 5 + (days.rem_euclid(7) + 1).min(5)
+}
+
+/// Whether an every-N-days schedule has an occurrence on `date`, counting the cadence from `anchor`
+pub fn n_days_due_on(n_days: &NDays, anchor: NaiveDate, date: NaiveDate) -> bool {
+    let every = n_days.days.max(1) as i64;
+    let on_cadence = |d: NaiveDate| (d - anchor).num_days().abs() % every == 0;
+
+    match n_days.skip_weekends {
+        SkipWeekends::Off => on_cadence(date),
+        SkipWeekends::CalendarDays => {
+            if is_weekend(date) {
+                return false;
+            }
+            // Monday also picks up anything that fell on the weekend before it
+            on_cadence(date)
+                || (date.weekday() == Weekday::Mon
+                    && (on_cadence(date - Duration::days(1)) || on_cadence(date - Duration::days(2))))
+        }
+        SkipWeekends::WeekdaysOnly => {
+            !is_weekend(date) && (weekday_index(date) - weekday_index(anchor)).abs() % every == 0
+        }
+    }
+}
+
+/// Whether the task has an occurrence on the given local date
+pub fn is_due_on_date(task: &DemoTask, date: NaiveDate, tz: Tz, now: DateTime<Utc>) -> bool {
+    // Nothing before the task was created or after it was deleted
+    if task.created_at.is_some_and(|created_at| date < created_at.with_timezone(&tz).date_naive()) {
+        return false;
+    }
+    if task.deleted_at.is_some_and(|deleted_at| date > deleted_at.with_timezone(&tz).date_naive()) {
+        return false;
+    }
+
+    match task.schedule_kind {
+        // Every N days counts from today
+        ScheduleKind::NDays => n_days_due_on(&task.n_days, now.with_timezone(&tz).date_naive(), date),
+        ScheduleKind::NWeeks => {
+            if !task.n_weeks.sub_schedule.active(date.weekday()) {
+                return false;
+            }
+            if task.n_weeks.weeks <= 1 {
+                return true;
+            }
+            let epoch = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+            let week_num = (date - epoch).num_days().div_euclid(7);
+            week_num.rem_euclid(task.n_weeks.weeks as i64) == task.n_weeks.offset as i64
+        }
+        ScheduleKind::Monthwise => day_in_range(&task.monthwise.days, date.day() as i32),
+        ScheduleKind::WeeksOfMonth => {
+            let week_num = ((date.day() - 1) / 7 + 1) as i32;
+            task.weeks_of_month.sub_schedule.active(date.weekday()) && task.weeks_of_month.weeks.contains(&week_num)
+        }
+        ScheduleKind::CertainMonths => {
+            task.certain_months.months.contains(&(date.month() as i32))
+                && day_in_range(&task.certain_months.days, date.day() as i32)
+        }
+        ScheduleKind::Once => date == task.once.datetime.with_timezone(&tz).date_naive(),
+    }
+}
+
+/// Local time of day the task's occurrences are due
+pub fn due_time(task: &DemoTask, tz: Tz) -> NaiveTime {
+    // All-day occurrences start at midnight, so their window covers the whole day
+    if task.all_day {
+        return NaiveTime::MIN;
+    }
+    match task.schedule_kind {
+        ScheduleKind::NDays => task.n_days.time,
+        ScheduleKind::NWeeks => task.n_weeks.sub_schedule.time,
+        ScheduleKind::Monthwise => task.monthwise.time,
+        ScheduleKind::WeeksOfMonth => task.weeks_of_month.sub_schedule.time,
+        ScheduleKind::CertainMonths => task.certain_months.time,
+        ScheduleKind::Once => task.once.datetime.with_timezone(&tz).time(),
+    }
+}
+
+/// The instant the task is due on a local date, if that date has an occurrence.
+/// A due time that falls in a DST gap has no instant and is skipped; one in a
+/// DST overlap is due the first time the clock reads it.
+fn due_on(task: &DemoTask, date: NaiveDate, tz: Tz, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if !is_due_on_date(task, date, tz, now) {
+        return None;
+    }
+    tz.from_local_datetime(&date.and_time(due_time(task, tz)))
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// The first due time after `now`. A Once task's only occurrence is returned
+/// even when it's past; a schedule with nothing due within `NEXT_DUE_SCAN_DAYS`
+/// gives a time far beyond that, which callers treat as "distant future".
+pub fn next_due(task: &DemoTask, tz: Tz, now: DateTime<Utc>) -> DateTime<Utc> {
+    if matches!(task.schedule_kind, ScheduleKind::Once) {
+        return task.once.datetime;
+    }
+    let today = now.with_timezone(&tz).date_naive();
+    (0..=NEXT_DUE_SCAN_DAYS)
+        .filter_map(|days_ahead| due_on(task, today + Duration::days(days_ahead), tz, now))
+        .find(|due| *due > now)
+        .unwrap_or(now + Duration::days(10 * NEXT_DUE_SCAN_DAYS))
+}
+
+/// The latest due time at or before `now`, or `RECENT_DUE_SCAN_DAYS` ago if there's none that recent
+pub fn most_recent_due(task: &DemoTask, tz: Tz, now: DateTime<Utc>) -> DateTime<Utc> {
+    let today = now.with_timezone(&tz).date_naive();
+    (0..=RECENT_DUE_SCAN_DAYS)
+        .filter_map(|days_back| due_on(task, today - Duration::days(days_back), tz, now))
+        .find(|due| *due <= now)
+        .unwrap_or(now - Duration::days(RECENT_DUE_SCAN_DAYS))
+}
+
+/// The due time on the first local date after `after`'s, i.e. when the
+/// occurrence due at `after` ends. Falls back to `FOLLOWING_DUE_SCAN_DAYS` later.
+pub fn next_due_after(task: &DemoTask, after: DateTime<Utc>, tz: Tz, now: DateTime<Utc>) -> DateTime<Utc> {
+    let after_date = after.with_timezone(&tz).date_naive();
+    (1..=FOLLOWING_DUE_SCAN_DAYS)
+        .find_map(|days_ahead| due_on(task, after_date + Duration::days(days_ahead), tz, now))
+        .unwrap_or(after + Duration::days(FOLLOWING_DUE_SCAN_DAYS))
+}
+
+/// The next `count` due times after `after`, earliest first
+pub fn upcoming_after(task: &DemoTask, after: DateTime<Utc>, count: usize, tz: Tz, now: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let start = after.with_timezone(&tz).date_naive();
+    (0..UPCOMING_SCAN_DAYS)
+        .filter_map(|offset| start.checked_add_signed(Duration::days(offset)))
+        .filter_map(|date| due_on(task, date, tz, now))
+        .filter(|due| *due > after)
+        .take(count)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::{DaysOfWeek, NWeeks};
+    use crate::tasks::create_default_task;
+
+    fn n_days(days: i32, skip_weekends: SkipWeekends) -> NDays {
+        NDays {
+            days,
+            time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            skip_weekends,
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        // October 2026: the 16th is a Friday, the 19th a Monday
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn chicago() -> Tz {
+        "America/Chicago".parse().unwrap()
+    }
+
+    /// Every Tuesday and Thursday at 09:00 local time
+    fn tue_thu_task() -> DemoTask {
+        let mut task = create_default_task();
+        task.schedule_kind = ScheduleKind::NWeeks;
+        task.n_weeks = NWeeks {
+            weeks: 1,
+            offset: 0,
+            sub_schedule: DaysOfWeek {
+                sunday: false,
+                monday: false,
+                tuesday: true,
+                wednesday: false,
+                thursday: true,
+                friday: false,
+                saturday: false,
+                time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            },
+        };
+        task
+    }
+
+    // ========================================================================
+    // Every N days
+    // ========================================================================
+
+    #[test]
+    fn test_n_days_skip_weekends_off_includes_weekends() {
+        let daily = n_days(1, SkipWeekends::Off);
+        assert!((16..=19).all(|d| n_days_due_on(&daily, date(16), date(d))));
+    }
+
+    #[test]
+    fn test_n_days_daily_rolls_friday_to_monday() {
+        for skip in [SkipWeekends::CalendarDays, SkipWeekends::WeekdaysOnly] {
+            let daily = n_days(1, skip);
+            assert!(n_days_due_on(&daily, date(16), date(16)));
+            assert!(!n_days_due_on(&daily, date(16), date(17)));
+            assert!(!n_days_due_on(&daily, date(16), date(18)));
+            assert!(n_days_due_on(&daily, date(16), date(19)));
+        }
+    }
+
+    #[test]
+    fn test_n_days_calendar_days_moves_weekend_occurrence_to_monday() {
+        // Every other calendar day from Friday: Sunday's occurrence lands on Monday
+        let every_other = n_days(2, SkipWeekends::CalendarDays);
+        assert!(n_days_due_on(&every_other, date(16), date(19)));
+        assert!(n_days_due_on(&every_other, date(16), date(20)));
+        assert!(!n_days_due_on(&every_other, date(16), date(21)));
+        assert!(n_days_due_on(&every_other, date(16), date(22)));
+    }
+
+    #[test]
+    fn test_n_days_weekdays_only_counts_weekdays() {
+        // Every other weekday from Friday: Tuesday, Thursday, next Monday
+        let every_other = n_days(2, SkipWeekends::WeekdaysOnly);
+        assert!(!n_days_due_on(&every_other, date(16), date(19)));
+        assert!(n_days_due_on(&every_other, date(16), date(20)));
+        assert!(n_days_due_on(&every_other, date(16), date(22)));
+        assert!(n_days_due_on(&every_other, date(16), date(26)));
+    }
+
+    // ========================================================================
+    // Due times around now
+    // ========================================================================
+
+    #[test]
+    fn test_next_and_most_recent_due_use_the_given_timezone() {
+        // Tuesday Mar 10, 2026 at 12:00 in Chicago (CDT, UTC-5)
+        let now = at("2026-03-10T17:00:00Z");
+        let task = tue_thu_task();
+        assert_eq!(most_recent_due(&task, chicago(), now), at("2026-03-10T14:00:00Z"));
+        assert_eq!(next_due(&task, chicago(), now), at("2026-03-12T14:00:00Z"));
+        // The same instant in UTC is still Tuesday, and 09:00 UTC has passed too
+        assert_eq!(next_due(&task, Tz::UTC, now), at("2026-03-12T09:00:00Z"));
+    }
+
+    #[test]
+    fn test_next_due_after_ends_the_occurrence_at_the_following_one() {
+        let now = at("2026-03-10T17:00:00Z");
+        let task = tue_thu_task();
+        let due = at("2026-03-10T14:00:00Z");
+        assert_eq!(next_due_after(&task, due, chicago(), now), at("2026-03-12T14:00:00Z"));
+        assert_eq!(
+            upcoming_after(&task, due, 3, chicago(), now),
+            vec![at("2026-03-12T14:00:00Z"), at("2026-03-17T14:00:00Z"), at("2026-03-19T14:00:00Z")]
+        );
+    }
+
+    #[test]
+    fn test_due_times_follow_local_time_across_dst() {
+        // Chicago springs forward on Sunday Mar 8, 2026: 09:00 local moves from 15:00 to 14:00 UTC
+        let now = at("2026-03-04T12:00:00Z");
+        let upcoming = upcoming_after(&tue_thu_task(), now, 3, chicago(), now);
+        assert_eq!(upcoming, vec![at("2026-03-05T15:00:00Z"), at("2026-03-10T14:00:00Z"), at("2026-03-12T14:00:00Z")]);
+    }
+
+    #[test]
+    fn test_due_time_in_a_dst_gap_is_skipped() {
+        // 02:30 doesn't exist in Chicago on Mar 8, 2026
+        let mut task = create_default_task();
+        task.schedule_kind = ScheduleKind::NDays;
+        task.n_days = n_days(1, SkipWeekends::Off);
+        task.n_days.time = NaiveTime::from_hms_opt(2, 30, 0).unwrap();
+        let now = at("2026-03-08T06:00:00Z");
+        assert_eq!(next_due(&task, chicago(), now), at("2026-03-09T07:30:00Z"));
+    }
+
+    #[test]
+    fn test_nothing_due_before_creation() {
+        let mut task = tue_thu_task();
+        task.created_at = Some(at("2026-03-11T00:00:00Z"));
+        assert!(!is_due_on_date(&task, NaiveDate::from_ymd_opt(2026, 3, 10).unwrap(), Tz::UTC, at("2026-03-01T00:00:00Z")));
+        assert!(is_due_on_date(&task, NaiveDate::from_ymd_opt(2026, 3, 12).unwrap(), Tz::UTC, at("2026-03-01T00:00:00Z")));
+    }
+}
@@ -0,0 +1,210 @@
+use axum::{
+    extract::Request,
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{Html, IntoResponse, Json, Response},
+};
+
+use crate::pages;
+
+// ============================================================================
+// AppError
+// ============================================================================
+
+/// Error returned by handlers. Turned into HTML for the browser or JSON for
+/// the API by `render_errors`, depending on who asked.
+#[derive(Debug)]
+pub enum AppError {
+    /// The thing asked for doesn't exist; the message says what
+    NotFound(String),
+    /// The request itself was wrong, e.g. a bad query value
+    Validation(String),
+    /// A database failure. Logged, but never shown to the user.
+    Db(anyhow::Error),
+}
+
+impl AppError {
+    fn status(&self) -> StatusCode {
+        match self {
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::Db(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "not_found",
+            AppError::Validation(_) => "invalid_request",
+            AppError::Db(_) => "internal_error",
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "Not Found",
+            AppError::Validation(_) => "Invalid Request",
+            AppError::Db(_) => "Something Went Wrong",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            AppError::NotFound(message) | AppError::Validation(message) => message.clone(),
+            AppError::Db(_) => "Something went wrong on our end. Please try again.".to_string(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for AppError {
+    fn from(e: anyhow::Error) -> Self {
+        AppError::Db(e)
+    }
+}
+
+/// What `render_errors` needs to re-render an error once the handler has returned
+#[derive(Clone)]
+struct ErrorBody {
+    status: StatusCode,
+    code: &'static str,
+    title: &'static str,
+    message: String,
+}
+
+impl ErrorBody {
+    fn json(&self) -> Response {
+        (self.status, Json(serde_json::json!({ "error": self.code, "message": self.message }))).into_response()
+    }
+}
+
+impl IntoResponse for AppError {
+    /// Defaults to the JSON form so routers without `render_errors` (and their
+    /// tests) still get a sensible body
+    fn into_response(self) -> Response {
+        if let AppError::Db(e) = &self {
+            tracing::error!(error = %e, "Request failed");
+        }
+        let body = ErrorBody {
+            status: self.status(),
+            code: self.code(),
+            title: self.title(),
+            message: self.message(),
+        };
+        let mut response = body.json();
+        response.extensions_mut().insert(body);
+        response
+    }
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+enum ErrorFormat {
+    Json,
+    /// An HTMX swap: a bare fragment, no page chrome
+    Fragment,
+    Page,
+}
+
+fn error_format(path: &str, headers: &HeaderMap) -> ErrorFormat {
+    let accepts_json = headers
+        .get(header::ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("application/json"));
+    if path.starts_with("/api") || path.starts_with("/admin") || accepts_json {
+        ErrorFormat::Json
+    } else if headers.get("HX-Request").is_some_and(|v| v == "true") {
+        ErrorFormat::Fragment
+    } else {
+        ErrorFormat::Page
+    }
+}
+
+/// Middleware that renders any `AppError` in the format the request asked
+/// for: JSON for the API or `Accept: application/json`, a fragment for HTMX,
+/// and a full page otherwise. HTMX doesn't swap 4xx/5xx responses by default,
+/// so a failed swap leaves the target as it was.
+pub async fn render_errors(request: Request, next: Next) -> Response {
+    let path = request.uri().path().to_string();
+    let headers = request.headers().clone();
+    let response = next.run(request).await;
+
+    let Some(body) = response.extensions().get::<ErrorBody>().cloned() else {
+        return response;
+    };
+
+    match error_format(&path, &headers) {
+        ErrorFormat::Json => body.json(),
+        ErrorFormat::Fragment => (
+            body.status,
+            Html(format!(
+                r#"<div class="field-error-message">{}</div>"#,
+                crate::tasks::html_escape(&body.message)
+            )),
+        )
+            .into_response(),
+        ErrorFormat::Page => {
+            let back = path.starts_with("/tasks").then_some(("/tasks", "Back to Tasks"));
+            pages::error_page(&headers, body.status, body.title, &body.message, back)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    async fn missing() -> Result<Html<String>, AppError> {
+        Err(AppError::NotFound("Task '7' doesn't exist.".to_string()))
+    }
+
+    async fn call(uri: &str, headers: &[(&str, &str)]) -> (StatusCode, String) {
+        let app = Router::new()
+            .route("/tasks/7", get(missing))
+            .route("/api/tasks/7", get(missing))
+            .layer(middleware::from_fn(render_errors));
+        let mut request = axum::http::Request::builder().uri(uri);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_are_json() {
+        let (status, body) = call("/api/tasks/7", &[]).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(value["error"], "not_found");
+        assert_eq!(value["message"], "Task '7' doesn't exist.");
+
+        let (_, body) = call("/tasks/7", &[("Accept", "application/json")]).await;
+        assert!(body.starts_with('{'));
+    }
+
+    #[tokio::test]
+    async fn test_browser_errors_are_html() {
+        let (status, body) = call("/tasks/7", &[]).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body.contains("/static/app.css"));
+        assert!(body.contains("href=\"/tasks\""));
+
+        let (status, body) = call("/tasks/7", &[("HX-Request", "true")]).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body.starts_with("<div class=\"field-error-message\">"));
+    }
+
+    #[test]
+    fn test_db_errors_hide_details() {
+        let error = AppError::from(anyhow::anyhow!("disk I/O error"));
+        assert_eq!(error.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(!error.message().contains("disk"));
+    }
+}
@@ -0,0 +1,44 @@
+use axum::response::sse::{Event, KeepAlive, Sse};
+use std::convert::Infallible;
+use std::sync::OnceLock;
+use tokio::sync::broadcast;
+use tokio_stream::{Stream, StreamExt, wrappers::BroadcastStream};
+
+/// Only the latest "something changed" matters, so a small buffer is plenty.
+/// Slow clients that fall behind just get a single refresh.
+const CHANNEL_CAPACITY: usize = 16;
+
+static CHANGES: OnceLock<broadcast::Sender<()>> = OnceLock::new();
+
+fn changes() -> &'static broadcast::Sender<()> {
+    CHANGES.get_or_init(|| broadcast::channel(CHANNEL_CAPACITY).0)
+}
+
+/// Tell connected dashboards that tasks or completions changed.
+/// A no-op when nobody is listening.
+pub fn notify_changed() {
+    let _ = changes().send(());
+}
+
+// GET /events - Server-sent events stream; emits "refresh" after every change.
+// Each client holds one receiver, dropped along with the stream when it disconnects.
+pub async fn stream() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let events = BroadcastStream::new(changes().subscribe())
+        .map(|_| Ok(Event::default().event("refresh").data("refresh")));
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_notify_reaches_subscribers_and_receivers_are_released() {
+        let before = changes().receiver_count();
+        let mut rx = changes().subscribe();
+        notify_changed();
+        assert!(rx.recv().await.is_ok());
+        drop(rx);
+        assert_eq!(changes().receiver_count(), before);
+    }
+}
@@ -0,0 +1,80 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{Html, Redirect},
+};
+use tracing::info;
+
+use crate::auth::secrets_match;
+use crate::config::{get_guest_access, GuestAccess};
+use crate::db::{self, DbPool};
+use crate::error::AppError;
+use crate::tasks::{self, View};
+
+/// The guest board exists only at its configured token; anything else is a
+/// plain 404 so a wrong guess doesn't reveal that the board is there.
+fn check_token(token: &str) -> Result<GuestAccess, AppError> {
+    get_guest_access()
+        .filter(|access| secrets_match(token, &access.token))
+        .ok_or_else(|| AppError::NotFound("There's nothing at this address.".to_string()))
+}
+
+fn view_for(access: &GuestAccess) -> View<'_> {
+    View::Guest {
+        token: &access.token,
+        can_complete: access.can_complete,
+    }
+}
+
+/// Load a task guests may see: a real one that isn't in the trash
+async fn guest_task(pool: &DbPool, id: &str) -> Result<tasks::DemoTask, AppError> {
+    let not_found = || AppError::NotFound(format!("Task '{}' doesn't exist.", id));
+    let task_id = id.parse::<i64>().map_err(|_| not_found())?;
+    db::get_task(pool, task_id)
+        .await?
+        .filter(|task| !task.is_deleted())
+        .ok_or_else(not_found)
+}
+
+// GET /guest/:token - The dashboard without any editing controls
+pub async fn board(
+    State(pool): State<DbPool>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<Html<String>, AppError> {
+    let access = check_token(&token)?;
+    Ok(Html(tasks::render_homepage(&pool, &headers, view_for(&access)).await))
+}
+
+// GET /guest/:token/tasks/:id - A task's page without edit, delete or history
+pub async fn task_show(
+    State(pool): State<DbPool>,
+    Path((token, id)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Result<Html<String>, AppError> {
+    let access = check_token(&token)?;
+    let task = guest_task(&pool, &id).await?;
+    let completions = db::get_all_completions(&pool, &id).await?;
+    let is_touch = crate::settings::is_touch_mode(&headers);
+    Ok(Html(tasks::render_task_show_page(&task, &completions, &[], is_touch, view_for(&access))))
+}
+
+// POST /guest/:token/tasks/:id/complete - Complete a task from the guest board, when allowed
+pub async fn complete(
+    State(pool): State<DbPool>,
+    Path((token, id)): Path<(String, String)>,
+) -> Result<Redirect, AppError> {
+    let access = check_token(&token)?;
+    if !access.can_complete {
+        return Err(AppError::Validation("Completing tasks isn't enabled for guests.".to_string()));
+    }
+    let task = guest_task(&pool, &id).await?;
+    if !task.completeable || task.is_inactive() {
+        return Err(AppError::Validation(format!("\"{}\" can't be completed.", task.name)));
+    }
+
+    db::add_completion(&pool, &id, None, None, None).await?;
+    info!(task_id = %id, "Task completed from the guest board");
+    tasks::record_audit(&pool, &id, "complete", "completed from the guest board").await;
+    Ok(Redirect::to(&format!("/guest/{}", token)))
+}
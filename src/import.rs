@@ -0,0 +1,687 @@
+use anyhow::Context;
+use axum::{
+    extract::{Multipart, State},
+    http::HeaderMap,
+    response::Html,
+};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use hypertext::prelude::*;
+use tracing::{error, info};
+
+use crate::config::get_timezone;
+use crate::db::{self, DbPool};
+use crate::pages::{self, NavItem};
+use crate::schedule::ScheduleKind;
+use crate::settings;
+use crate::tasks::{self, DemoTask};
+
+/// Header names accepted for each column, compared case-insensitively.
+/// Covers plain todo exports as well as Todoist's CSV (TYPE, CONTENT, DESCRIPTION, DATE).
+const TITLE_HEADERS: &[&str] = &["title", "name", "task", "content"];
+const NOTES_HEADERS: &[&str] = &["notes", "note", "description", "details"];
+const DUE_HEADERS: &[&str] = &["due", "due date", "due_date", "date"];
+const RECURRENCE_HEADERS: &[&str] = &["recurrence", "repeat", "repeats", "recurring"];
+const TYPE_HEADERS: &[&str] = &["type"];
+
+/// Time of day used when a row gives a date without a time
+const DEFAULT_DUE_HOUR: u32 = 9;
+
+/// What importing one CSV row will do
+pub enum RowPlan {
+    Create(Box<DemoTask>),
+    /// Valid, but deliberately not imported (e.g. a name that already exists)
+    Skip(String),
+    Invalid(String),
+}
+
+/// One data row of the file, numbered from 1 after the header row
+pub struct PlannedRow {
+    pub row: usize,
+    pub name: String,
+    pub plan: RowPlan,
+}
+
+/// How often an imported task repeats
+#[derive(Debug, PartialEq)]
+enum Recurrence {
+    Days(i32),
+    Weeks(i32),
+    Months(i32),
+    Years,
+}
+
+/// Split CSV text into records. Handles quoted fields with embedded commas,
+/// newlines and doubled quotes; blank lines are dropped.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+        .into_iter()
+        .filter(|r| r.iter().any(|f| !f.trim().is_empty()))
+        .collect()
+}
+
+/// Parse a due column value into a date and, if given, a time of day, local to `tz`
+fn parse_due(input: &str, tz: Tz) -> Result<(NaiveDate, Option<NaiveTime>), String> {
+    let input = input.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+        let local = dt.with_timezone(&tz);
+        return Ok((local.date_naive(), Some(local.time())));
+    }
+    for format in ["%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(input, format) {
+            return Ok((dt.date(), Some(dt.time())));
+        }
+    }
+    for format in ["%Y-%m-%d", "%Y/%m/%d", "%m/%d/%Y"] {
+        if let Ok(date) = NaiveDate::parse_from_str(input, format) {
+            return Ok((date, None));
+        }
+    }
+    Err(format!("Unrecognized due date '{}' (use YYYY-MM-DD, optionally with HH:MM)", input))
+}
+
+/// Parse a recurrence such as "daily", "weekly", "every 3 days" or "every other week"
+fn parse_recurrence(input: &str) -> Result<Recurrence, String> {
+    let lower = input.trim().to_lowercase();
+    let unknown = || format!("Unrecognized recurrence '{}' (try daily, weekly, monthly, yearly or \"every 2 weeks\")", input.trim());
+
+    match lower.as_str() {
+        "daily" => return Ok(Recurrence::Days(1)),
+        "weekly" => return Ok(Recurrence::Weeks(1)),
+        "biweekly" | "fortnightly" => return Ok(Recurrence::Weeks(2)),
+        "monthly" => return Ok(Recurrence::Months(1)),
+        "yearly" | "annually" => return Ok(Recurrence::Years),
+        _ => {}
+    }
+
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    let (count, unit) = match words.as_slice() {
+        ["every", unit] => (1, *unit),
+        ["every", "other", unit] => (2, *unit),
+        ["every", n, unit] => (n.parse::<i32>().map_err(|_| unknown())?, *unit),
+        _ => return Err(unknown()),
+    };
+    if count < 1 {
+        return Err(unknown());
+    }
+    match unit.trim_end_matches('s') {
+        "day" => Ok(Recurrence::Days(count)),
+        "week" => Ok(Recurrence::Weeks(count)),
+        "month" if 12 % count == 0 => Ok(Recurrence::Months(count)),
+        "month" => Err(format!("Every {} months doesn't divide the year evenly", count)),
+        "year" if count == 1 => Ok(Recurrence::Years),
+        _ => Err(unknown()),
+    }
+}
+
+/// Build the task for one row. `anchor` is the first due date, in `tz`; weekly,
+/// monthly and yearly tasks repeat on its weekday or day of month. Every-N-days
+/// schedules have nowhere to store a start date and always count from today,
+/// so for those rows only the time of day is kept.
+fn build_task(
+    name: &str,
+    notes: &str,
+    anchor: NaiveDate,
+    time: NaiveTime,
+    recurrence: Option<Recurrence>,
+    tz: Tz,
+) -> Result<DemoTask, String> {
+    let mut task = tasks::create_default_task();
+    task.name = name.to_string();
+    task.details = notes.to_string();
+
+    match recurrence {
+        None => {
+            task.schedule_kind = ScheduleKind::Once;
+            task.once.datetime = tz
+                .from_local_datetime(&anchor.and_time(time))
+                .earliest()
+                .ok_or_else(|| format!("{} {} doesn't exist in the local time zone", anchor, time.format("%H:%M")))?
+                .with_timezone(&Utc);
+        }
+        Some(Recurrence::Days(days)) => {
+            task.schedule_kind = ScheduleKind::NDays;
+            task.n_days.days = days;
+            task.n_days.time = time;
+        }
+        Some(Recurrence::Weeks(weeks)) => {
+            task.schedule_kind = ScheduleKind::NWeeks;
+            task.n_weeks.weeks = weeks;
+            // Same week numbering as the calendar, so the first occurrence lands on the anchor's week
+            let epoch = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+            task.n_weeks.offset = (anchor - epoch).num_days().div_euclid(7).rem_euclid(weeks as i64) as i32;
+            let days = &mut task.n_weeks.sub_schedule;
+            let weekday = anchor.weekday();
+            days.sunday = weekday == chrono::Weekday::Sun;
+            days.monday = weekday == chrono::Weekday::Mon;
+            days.tuesday = weekday == chrono::Weekday::Tue;
+            days.wednesday = weekday == chrono::Weekday::Wed;
+            days.thursday = weekday == chrono::Weekday::Thu;
+            days.friday = weekday == chrono::Weekday::Fri;
+            days.saturday = weekday == chrono::Weekday::Sat;
+            days.time = time;
+        }
+        Some(Recurrence::Months(1)) => {
+            task.schedule_kind = ScheduleKind::Monthwise;
+            task.monthwise.days = vec![anchor.day() as i32];
+            task.monthwise.time = time;
+        }
+        Some(Recurrence::Months(step)) => {
+            task.schedule_kind = ScheduleKind::CertainMonths;
+            let first = anchor.month0() as i32;
+            let mut months: Vec<i32> = (0..12 / step).map(|i| (first + i * step) % 12 + 1).collect();
+            months.sort();
+            task.certain_months.months = months;
+            task.certain_months.days = vec![anchor.day() as i32];
+            task.certain_months.time = time;
+        }
+        Some(Recurrence::Years) => {
+            task.schedule_kind = ScheduleKind::CertainMonths;
+            task.certain_months.months = vec![anchor.month() as i32];
+            task.certain_months.days = vec![anchor.day() as i32];
+            task.certain_months.time = time;
+        }
+    }
+    Ok(task)
+}
+
+/// Find the column for one of the accepted header names
+fn find_column(headers: &[String], names: &[&str]) -> Option<usize> {
+    headers.iter().position(|h| names.contains(&h.trim().to_lowercase().as_str()))
+}
+
+/// Work out what each row of a CSV file would import as, without writing anything.
+/// Rows whose name matches an existing task, or an earlier row, are skipped.
+/// Errors only when the file as a whole can't be used. Dates are read in `tz`,
+/// and rows without one are due `today`.
+pub fn plan_import(text: &str, existing: &[DemoTask], today: NaiveDate, tz: Tz) -> Result<Vec<PlannedRow>, String> {
+    let mut records = parse_csv(text).into_iter();
+    let headers = records.next().ok_or("The file is empty")?;
+    let title_col = find_column(&headers, TITLE_HEADERS)
+        .ok_or("The first row needs a title column (title, name, task or content)")?;
+    let notes_col = find_column(&headers, NOTES_HEADERS);
+    let due_col = find_column(&headers, DUE_HEADERS);
+    let recurrence_col = find_column(&headers, RECURRENCE_HEADERS);
+    let type_col = find_column(&headers, TYPE_HEADERS);
+
+    let default_time = NaiveTime::from_hms_opt(DEFAULT_DUE_HOUR, 0, 0).unwrap();
+    let mut planned: Vec<DemoTask> = Vec::new();
+    let mut rows = Vec::new();
+
+    for (index, record) in records.enumerate() {
+        let cell = |col: Option<usize>| col.and_then(|c| record.get(c)).map(|s| s.trim()).unwrap_or("");
+        let name = cell(Some(title_col)).to_string();
+        let row = index + 1;
+
+        let kind = cell(type_col);
+        if !kind.is_empty() && !kind.eq_ignore_ascii_case("task") {
+            rows.push(PlannedRow { row, name, plan: RowPlan::Skip(format!("Not a task ({})", kind)) });
+            continue;
+        }
+        if name.is_empty() {
+            rows.push(PlannedRow { row, name, plan: RowPlan::Invalid("Missing title".to_string()) });
+            continue;
+        }
+        if let Some(duplicate) = tasks::find_similar_task(existing, &name).or_else(|| tasks::find_similar_task(&planned, &name)) {
+            let reason = format!("A task named \"{}\" already exists", duplicate.name);
+            rows.push(PlannedRow { row, name, plan: RowPlan::Skip(reason) });
+            continue;
+        }
+
+        // Todoist puts recurrence in the date column ("every day")
+        let (mut due, mut recurrence) = (cell(due_col), cell(recurrence_col));
+        if recurrence.is_empty() && due.to_lowercase().starts_with("every") {
+            (due, recurrence) = ("", due);
+        }
+
+        let result = (|| {
+            let (anchor, time) = if due.is_empty() { (today, None) } else { parse_due(due, tz)? };
+            let recurrence = if recurrence.is_empty() { None } else { Some(parse_recurrence(recurrence)?) };
+            build_task(&name, cell(notes_col), anchor, time.unwrap_or(default_time), recurrence, tz)
+        })();
+
+        let plan = match result {
+            Ok(task) => {
+                planned.push(task.clone());
+                RowPlan::Create(Box::new(task))
+            }
+            Err(message) => RowPlan::Invalid(message),
+        };
+        rows.push(PlannedRow { row, name, plan });
+    }
+
+    Ok(rows)
+}
+
+/// Outcome of importing one row, for the results table
+struct RowResult {
+    row: usize,
+    name: String,
+    status: &'static str,
+    message: String,
+}
+
+/// A file that has been previewed and is waiting for the user to confirm it
+struct Pending<'a> {
+    text: &'a str,
+    new_tasks: usize,
+}
+
+// GET /import - Upload form for a todo-list CSV
+pub async fn import_page(headers: HeaderMap) -> Html<String> {
+    Html(render_import_page(settings::is_touch_mode(&headers), None, &[], None))
+}
+
+/// The CSV text from the upload form. An uploaded file wins over pasted text.
+async fn read_csv_text(multipart: &mut Multipart) -> String {
+    let mut text = String::new();
+    while let Ok(Some(field)) = multipart.next_field().await {
+        let name = field.name().unwrap_or_default().to_string();
+        let Ok(bytes) = field.bytes().await else { continue };
+        if (name == "file" && !bytes.is_empty()) || (name == "text" && text.trim().is_empty()) {
+            text = String::from_utf8_lossy(&bytes).into_owned();
+        }
+    }
+    text
+}
+
+/// Plan the import against the current tasks, or render the page with the
+/// reason the file can't be used
+async fn plan_from_upload(pool: &DbPool, is_touch: bool, text: &str) -> Result<Vec<PlannedRow>, Html<String>> {
+    if text.trim().is_empty() {
+        return Err(Html(render_import_page(is_touch, Some("Choose a CSV file or paste its contents."), &[], None)));
+    }
+    let existing = db::get_all_tasks(pool).await.unwrap_or_default();
+    let tz = get_timezone();
+    let today = Utc::now().with_timezone(&tz).date_naive();
+    plan_import(text, &existing, today, tz).map_err(|message| Html(render_import_page(is_touch, Some(&message), &[], None)))
+}
+
+/// What each row would do, for the preview table
+fn preview_results(planned: Vec<PlannedRow>) -> Vec<RowResult> {
+    planned
+        .into_iter()
+        .map(|PlannedRow { row, name, plan }| {
+            let (status, message) = match plan {
+                RowPlan::Create(task) => ("new", tasks::describe_schedule(&task)),
+                RowPlan::Skip(reason) => ("skipped", reason),
+                RowPlan::Invalid(reason) => ("invalid", reason),
+            };
+            RowResult { row, name, status, message }
+        })
+        .collect()
+}
+
+/// Create every `RowPlan::Create` row in one transaction, so a failure part
+/// way through leaves no half-imported file behind
+async fn create_tasks(pool: &DbPool, planned: Vec<PlannedRow>) -> anyhow::Result<Vec<RowResult>> {
+    let mut tx = pool.begin().await?;
+    let mut results = Vec::new();
+    let mut created = Vec::new();
+    for PlannedRow { row, name, plan } in planned {
+        let (status, message) = match plan {
+            RowPlan::Create(task) => {
+                let task_id = db::save_task_tx(&mut tx, &task).await.with_context(|| format!("Failed to save row {}", row))?;
+                let message = tasks::describe_schedule(&task);
+                created.push((task_id, task));
+                ("created", message)
+            }
+            RowPlan::Skip(reason) => ("skipped", reason),
+            RowPlan::Invalid(reason) => ("invalid", reason),
+        };
+        results.push(RowResult { row, name, status, message });
+    }
+    tx.commit().await?;
+
+    for (task_id, task) in &created {
+        tasks::record_audit_with_snapshot(pool, &task_id.to_string(), "create", "imported from CSV", task).await;
+    }
+    Ok(results)
+}
+
+// POST /import/preview - Show what a CSV would import without writing anything
+pub async fn import_preview(State(pool): State<DbPool>, headers: HeaderMap, mut multipart: Multipart) -> Html<String> {
+    let is_touch = settings::is_touch_mode(&headers);
+    let text = read_csv_text(&mut multipart).await;
+    let planned = match plan_from_upload(&pool, is_touch, &text).await {
+        Ok(planned) => planned,
+        Err(page) => return page,
+    };
+
+    let results = preview_results(planned);
+    let new_tasks = results.iter().filter(|r| r.status == "new").count();
+    Html(render_import_page(is_touch, None, &results, Some(Pending { text: &text, new_tasks })))
+}
+
+// POST /import/commit and POST /import - Create tasks from a CSV, reporting what happened to each row.
+// The file is planned again, so rows that now clash with a task created since the preview are skipped.
+pub async fn import_csv(State(pool): State<DbPool>, headers: HeaderMap, mut multipart: Multipart) -> Html<String> {
+    let is_touch = settings::is_touch_mode(&headers);
+    let text = read_csv_text(&mut multipart).await;
+    let planned = match plan_from_upload(&pool, is_touch, &text).await {
+        Ok(planned) => planned,
+        Err(page) => return page,
+    };
+
+    let results = match create_tasks(&pool, planned).await {
+        Ok(results) => results,
+        Err(e) => {
+            error!(error = %e, "Error saving imported tasks");
+            return Html(render_import_page(is_touch, Some("Couldn't save the import, so no tasks were created."), &[], None));
+        }
+    };
+
+    let created = results.iter().filter(|r| r.status == "created").count();
+    info!(rows = results.len(), created, "Imported tasks from CSV");
+    Html(render_import_page(is_touch, None, &results, None))
+}
+
+/// One-line summary of the results table, e.g. "12 row(s): 10 new, 1 skipped, 1 invalid"
+fn summarize(results: &[RowResult]) -> String {
+    let count = |status: &str| results.iter().filter(|r| r.status == status).count();
+    let (label, added) = if results.iter().any(|r| r.status == "new") {
+        ("new", count("new"))
+    } else {
+        ("created", count("created"))
+    };
+    format!(
+        "{} row(s): {} {}, {} skipped, {} invalid",
+        results.len(),
+        added,
+        label,
+        count("skipped"),
+        count("invalid")
+    )
+}
+
+fn render_import_page(is_touch: bool, error: Option<&str>, results: &[RowResult], pending: Option<Pending<'_>>) -> String {
+    let summary = summarize(results);
+    let body = maud! {
+        div .import-page {
+            div .page-header {
+                h1 { "Import Tasks" }
+                div .page-header-buttons {
+                    @if is_touch {
+                        button .btn onclick="window.location.href='/tasks'" { "Tasks" }
+                    } @else {
+                        a .btn href="/tasks" { "Tasks" }
+                    }
+                }
+            }
+
+            p .import-help {
+                "Bring over a list from another app as a CSV with a header row. "
+                "Columns: " strong { "title" } " (required), " strong { "notes" } ", "
+                strong { "due" } " (YYYY-MM-DD, optionally with HH:MM) and "
+                strong { "recurrence" } " (daily, weekly, monthly, yearly, \"every 3 days\"). "
+                "Rows with a recurrence become repeating tasks; the rest are one-time. "
+                "Todoist exports work as-is. Names that already exist are skipped."
+            }
+
+            @if let Some(message) = error {
+                div .form-error-message { (message) }
+            }
+
+            @if !results.is_empty() {
+                div .import-summary {
+                    @if pending.is_some() { "Preview — nothing has been imported yet. " }
+                    (summary)
+                }
+                table .import-results {
+                    thead {
+                        tr {
+                            th { "Row" }
+                            th { "Task" }
+                            th { "Result" }
+                        }
+                    }
+                    tbody {
+                        @for r in results {
+                            tr class=(format!("import-row-{}", r.status)) {
+                                td { (r.row) }
+                                td { (r.name) }
+                                td { strong { (r.status) } " — " (r.message) }
+                            }
+                        }
+                    }
+                }
+            }
+
+            @if let Some(pending) = &pending {
+                form .import-commit method="post" action="/import/commit" enctype="multipart/form-data" {
+                    textarea name="text" hidden { (pending.text) }
+                    div .form-actions {
+                        @if pending.new_tasks > 0 {
+                            button .btn type="submit" { "Import " (pending.new_tasks) " task(s)" }
+                        }
+                        a .btn href="/import" { "Cancel" }
+                    }
+                }
+            } @else {
+                form .import-form method="post" action="/import/preview" enctype="multipart/form-data" {
+                    div .form-group {
+                        label for="import-file" { "CSV file" }
+                        input type="file" id="import-file" name="file" accept=".csv,text/csv";
+                    }
+                    div .form-group {
+                        label for="import-text" { "Or paste CSV" }
+                        textarea id="import-text" name="text" rows="8" placeholder="title,notes,due,recurrence\nReplace fridge filter,Model ABC,2026-11-01,every 6 months" {}
+                    }
+                    div .form-actions {
+                        button .btn type="submit" { "Preview" }
+                    }
+                }
+            }
+        }
+    }
+    .render()
+    .into_inner();
+
+    pages::render_page("Import", &body, is_touch, NavItem::Tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 10).unwrap()
+    }
+
+    /// Not UTC, so a date read in the wrong zone shows up
+    fn tz() -> Tz {
+        "America/Chicago".parse().unwrap()
+    }
+
+    fn created(row: &PlannedRow) -> &DemoTask {
+        match &row.plan {
+            RowPlan::Create(task) => task,
+            RowPlan::Skip(reason) | RowPlan::Invalid(reason) => panic!("row {} was not created: {}", row.row, reason),
+        }
+    }
+
+    // ========================================================================
+    // CSV parsing tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_csv_quotes_and_blank_lines() {
+        let records = parse_csv("title,notes\r\n\"Filter, fridge\",\"Say \"\"hi\"\"\nthen go\"\n\n,\nLast,");
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1], vec!["Filter, fridge", "Say \"hi\"\nthen go"]);
+        assert_eq!(records[2], vec!["Last", ""]);
+    }
+
+    #[test]
+    fn test_parse_recurrence() {
+        assert_eq!(parse_recurrence("Daily"), Ok(Recurrence::Days(1)));
+        assert_eq!(parse_recurrence("every 3 days"), Ok(Recurrence::Days(3)));
+        assert_eq!(parse_recurrence("every other week"), Ok(Recurrence::Weeks(2)));
+        assert_eq!(parse_recurrence("every 6 months"), Ok(Recurrence::Months(6)));
+        assert_eq!(parse_recurrence("yearly"), Ok(Recurrence::Years));
+        assert!(parse_recurrence("every 5 months").is_err());
+        assert!(parse_recurrence("every 0 days").is_err());
+        assert!(parse_recurrence("sometimes").is_err());
+    }
+
+    // ========================================================================
+    // Import planning tests
+    // ========================================================================
+
+    #[test]
+    fn test_plan_import_once_and_recurring() {
+        let csv = "Title,Notes,Due Date,Recurrence\n\
+                   Dentist,Bring card,2026-04-02 14:30,\n\
+                   Water plants,,2026-03-12,every 2 weeks\n\
+                   Pay rent,,2026-03-01,monthly\n\
+                   No date,,,\n\
+                   Feed fish,,2026-03-20 07:00,every 3 days\n";
+        let rows = plan_import(csv, &[], today(), tz()).unwrap();
+        assert_eq!(rows.len(), 5);
+
+        let dentist = created(&rows[0]);
+        assert!(dentist.schedule_kind == ScheduleKind::Once);
+        assert_eq!(dentist.details, "Bring card");
+        assert_eq!(dentist.once.datetime, Utc.with_ymd_and_hms(2026, 4, 2, 19, 30, 0).unwrap());
+
+        let plants = created(&rows[1]);
+        assert!(plants.schedule_kind == ScheduleKind::NWeeks);
+        assert_eq!(plants.n_weeks.weeks, 2);
+        assert!(plants.n_weeks.sub_schedule.thursday && !plants.n_weeks.sub_schedule.monday);
+        assert!(tasks::is_due_on_date(plants, NaiveDate::from_ymd_opt(2026, 3, 12).unwrap()));
+        assert!(!tasks::is_due_on_date(plants, NaiveDate::from_ymd_opt(2026, 3, 19).unwrap()));
+
+        let rent = created(&rows[2]);
+        assert!(rent.schedule_kind == ScheduleKind::Monthwise);
+        assert_eq!(rent.monthwise.days, vec![1]);
+
+        let no_date = created(&rows[3]);
+        assert_eq!(no_date.once.datetime, Utc.with_ymd_and_hms(2026, 3, 10, 14, 0, 0).unwrap());
+
+        // Every-N-days schedules keep the row's time but not its date
+        let fish = created(&rows[4]);
+        assert!(fish.schedule_kind == ScheduleKind::NDays);
+        assert_eq!(fish.n_days.days, 3);
+        assert_eq!(fish.n_days.time, NaiveTime::from_hms_opt(7, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_plan_import_reports_bad_rows_and_duplicates() {
+        let mut existing = tasks::create_default_task();
+        existing.name = "Take out the trash".to_string();
+        let csv = "name,due\n\
+                   take out trash,2026-03-11\n\
+                   Vacuum,tomorrow\n\
+                   ,2026-03-11\n\
+                   Mop,2026-03-11\n\
+                   mop!,2026-03-12\n";
+        let rows = plan_import(csv, &[existing], today(), tz()).unwrap();
+        assert!(matches!(rows[0].plan, RowPlan::Skip(_)));
+        assert!(matches!(&rows[1].plan, RowPlan::Invalid(msg) if msg.contains("tomorrow")));
+        assert!(matches!(&rows[2].plan, RowPlan::Invalid(msg) if msg == "Missing title"));
+        created(&rows[3]);
+        assert!(matches!(rows[4].plan, RowPlan::Skip(_)));
+    }
+
+    #[test]
+    fn test_plan_import_todoist_export() {
+        let csv = "TYPE,CONTENT,DESCRIPTION,PRIORITY,DATE\n\
+                   section,Kitchen,,,\n\
+                   task,Wipe counters,,4,every day\n";
+        let rows = plan_import(csv, &[], today(), tz()).unwrap();
+        assert!(matches!(rows[0].plan, RowPlan::Skip(_)));
+        let task = created(&rows[1]);
+        assert!(task.schedule_kind == ScheduleKind::NDays);
+        assert_eq!(task.n_days.days, 1);
+    }
+
+    #[test]
+    fn test_plan_import_needs_title_column() {
+        assert!(plan_import("due,notes\n2026-03-11,x\n", &[], today(), tz()).is_err());
+        assert!(plan_import("", &[], today(), tz()).is_err());
+    }
+
+    // ========================================================================
+    // Preview tests
+    // ========================================================================
+
+    #[test]
+    fn test_preview_summarizes_without_creating() {
+        let mut existing = tasks::create_default_task();
+        existing.name = "Mop".to_string();
+        let csv = "name,due\nMop,2026-03-11\nVacuum,2026-03-11\nDust,someday\n";
+        let results = preview_results(plan_import(csv, &[existing], today(), tz()).unwrap());
+        let statuses: Vec<&str> = results.iter().map(|r| r.status).collect();
+        assert_eq!(statuses, vec!["skipped", "new", "invalid"]);
+        assert_eq!(summarize(&results), "3 row(s): 1 new, 1 skipped, 1 invalid");
+
+        let page = render_import_page(false, None, &results, Some(Pending { text: csv, new_tasks: 1 }));
+        assert!(page.contains(r#"action="/import/commit""#));
+        assert!(page.contains("Import 1 task(s)"));
+        assert!(page.contains("Vacuum,2026-03-11"));
+        assert!(!page.contains(r#"action="/import/preview""#));
+    }
+
+    #[tokio::test]
+    async fn test_import_is_all_or_nothing() {
+        use crate::db::test_support::test_pool;
+
+        let pool = test_pool("import_all_or_nothing").await;
+        let csv = "name,due\nMop,2026-03-11\nBoom,2026-03-11\nDust,2026-03-11\n";
+        sqlx::query("CREATE TRIGGER no_boom BEFORE INSERT ON tasks WHEN NEW.name = 'Boom' BEGIN SELECT RAISE(ABORT, 'boom'); END")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let planned = plan_import(csv, &[], today(), tz()).unwrap();
+        let Err(error) = create_tasks(&pool, planned).await else {
+            panic!("an import with a failing row should fail");
+        };
+        assert!(error.to_string().contains("row 2"), "{}", error);
+        assert!(db::get_all_tasks(&pool).await.unwrap().is_empty());
+        let audit: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM audit_log").fetch_one(&pool).await.unwrap();
+        assert_eq!(audit.0, 0);
+
+        sqlx::query("DROP TRIGGER no_boom").execute(&pool).await.unwrap();
+        let planned = plan_import(csv, &[], today(), tz()).unwrap();
+        let results = create_tasks(&pool, planned).await.unwrap();
+        assert!(results.iter().all(|r| r.status == "created"));
+        let mut names: Vec<String> = db::get_all_tasks(&pool).await.unwrap().into_iter().map(|t| t.name).collect();
+        names.sort();
+        assert_eq!(names, vec!["Boom", "Dust", "Mop"]);
+    }
+}
@@ -1,9 +1,22 @@
+mod api;
+mod auth;
+mod clock;
+mod completion_links;
 mod config;
 mod db;
+mod engine;
+mod error;
+mod events;
+mod guest;
+mod import;
+mod metrics;
 mod migrate;
+mod pages;
 mod photos;
+mod reminders;
 mod schedule;
 pub mod settings;
+mod share;
 mod storybook;
 mod tasks;
 
@@ -16,7 +29,7 @@ use std::fs;
 use std::path::Path;
 use tower_http::services::{ServeDir, ServeFile};
 use tower_http::trace::TraceLayer;
-use tracing::{info, Level};
+use tracing::{info, warn, Level};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
 #[derive(Parser, Debug)]
@@ -54,12 +67,42 @@ fn get_config(
     dotenv: &HashMap<String, String>,
     default: &str,
 ) -> String {
-    cli_value
-        .or_else(|| std::env::var(key).ok())
-        .or_else(|| dotenv.get(key).cloned())
+    lookup_config(key, cli_value, dotenv)
+        .map(|(value, _)| value)
         .unwrap_or_else(|| default.to_string())
 }
 
+/// Like `get_config` without the default, also returning which source the value came from.
+fn lookup_config(
+    key: &str,
+    cli_value: Option<String>,
+    dotenv: &HashMap<String, String>,
+) -> Option<(String, &'static str)> {
+    cli_value
+        .map(|v| (v, "command line"))
+        .or_else(|| std::env::var(key).ok().map(|v| (v, "environment")))
+        .or_else(|| dotenv.get(key).cloned().map(|v| (v, ".env")))
+}
+
+/// How often completions are pruned once retention is enabled
+const PRUNE_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+fn spawn_completion_pruning(pool: db::DbPool, days: i64) {
+    info!("Completion retention: keeping {} day(s), plus each task's latest", days);
+    tokio::spawn(async move {
+        // The first tick fires immediately, so this also prunes at startup
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(PRUNE_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let cutoff = chrono::Utc::now() - chrono::Duration::days(days);
+            match db::prune_completions(&pool, cutoff).await {
+                Ok(count) => info!("Pruned {} completion(s) older than {} day(s)", count, days),
+                Err(e) => tracing::error!(error = %e, "Error pruning completions"),
+            }
+        }
+    });
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Set up dual-drain logging: console + rolling file
@@ -89,10 +132,12 @@ async fn main() -> Result<()> {
     // Parse CLI arguments
     let args = Args::parse();
 
-    // Get timezone: CLI flag > env var > .env > UTC
-    let tz_str = get_config("TZ", args.tz, &dotenv, "UTC");
+    // Get timezone: CLI flag > env var > .env > system timezone > UTC
+    let (tz_str, tz_source) = lookup_config("TZ", args.tz, &dotenv)
+        .or_else(|| iana_time_zone::get_timezone().ok().map(|tz| (tz, "system")))
+        .unwrap_or_else(|| ("UTC".to_string(), "default"));
     config::init_timezone(&tz_str);
-    info!("Using timezone: {}", config::get_timezone());
+    info!("Using timezone: {} (from {})", config::get_timezone(), tz_source);
 
     // Get touch mode: CLI flag > env var > .env > false
     let touch_enabled = if args.touch {
@@ -106,6 +151,89 @@ async fn main() -> Result<()> {
         info!("Touch mode: enabled");
     }
 
+    // Get calendar week numbers: env var > .env > false
+    let week_numbers_str = get_config("SHOW_WEEK_NUMBERS", None, &dotenv, "false");
+    config::init_show_week_numbers(week_numbers_str.eq_ignore_ascii_case("true") || week_numbers_str == "1");
+
+    // Get the start of the day for date labels: env var > .env > 0 (midnight)
+    let day_start_hour = get_config("DAY_START_HOUR", None, &dotenv, "0");
+    config::init_day_start_hour(&day_start_hour);
+
+    // Get time format: env var > .env > HH:MM
+    let time_format_str = get_config("TIME_FORMAT", None, &dotenv, "HH:MM");
+    config::init_time_format(&time_format_str);
+
+    // Get landing page: env var > .env > dashboard
+    let home_route_str = get_config("HOME_ROUTE", None, &dotenv, "dashboard");
+    config::init_home_route(&home_route_str);
+
+    // Get Once-task archive age: env var > .env > never
+    let archive_after_str = get_config("ARCHIVE_AFTER_DAYS", None, &dotenv, "");
+    config::init_archive_after_days(&archive_after_str);
+
+    // Get completion retention: env var > .env > keep everything
+    let retention_str = get_config("COMPLETION_RETENTION_DAYS", None, &dotenv, "");
+    config::init_completion_retention_days(&retention_str);
+
+    // Get new-task schedule kind: env var > .env > once
+    let default_kind_str = get_config("DEFAULT_SCHEDULE_KIND", None, &dotenv, "once");
+    config::init_default_schedule_kind(&default_kind_str);
+
+    // Get reminder settings: env var > .env > disabled
+    let webhook_url = get_config("REMINDER_WEBHOOK_URL", None, &dotenv, "");
+    config::init_reminder_webhook(&webhook_url);
+    let quiet_start = get_config("QUIET_START", None, &dotenv, "");
+    let quiet_end = get_config("QUIET_END", None, &dotenv, "");
+    config::init_quiet_hours(&quiet_start, &quiet_end);
+    let digest_time = get_config("DIGEST_TIME", None, &dotenv, "");
+    let digest_mode = get_config("DIGEST_MODE", None, &dotenv, "supplement");
+    config::init_digest(&digest_time, &digest_mode);
+    let notify_channels = get_config("NOTIFY_CHANNELS", None, &dotenv, "webhook");
+    config::init_notify_channels(&notify_channels);
+    let min_notify_interval = get_config("MIN_NOTIFY_INTERVAL", None, &dotenv, "15");
+    config::init_min_notify_interval(&min_notify_interval);
+
+    // Get API access: env var > .env > disabled
+    let api_token = get_config("API_TOKEN", None, &dotenv, "");
+    let api_open_str = get_config("API_OPEN", None, &dotenv, "false");
+    config::init_api_access(&api_token, api_open_str.eq_ignore_ascii_case("true") || api_open_str == "1");
+    match config::get_api_access() {
+        config::ApiAccess::Disabled => info!("API: disabled (set API_TOKEN to enable)"),
+        config::ApiAccess::Open => info!("API: open without a token"),
+        config::ApiAccess::Token(_) => info!("API: bearer token required"),
+    }
+
+    // Get app password: env var > .env > none (open)
+    let app_password = get_config("APP_PASSWORD", None, &dotenv, "");
+    config::init_app_password(&app_password);
+    if config::get_app_password().is_some() {
+        info!("App password: required");
+    }
+
+    if config::get_app_password().is_none() {
+        info!("Share links: disabled (set APP_PASSWORD to enable)");
+    }
+
+    // Get guest board access: env var > .env > disabled
+    let mut guest_token = get_config("GUEST_TOKEN", None, &dotenv, "");
+    if !guest_token.is_empty() && config::get_app_password().is_none() {
+        warn!("Guest board: disabled, because GUEST_TOKEN needs APP_PASSWORD to keep the rest of the app private");
+        guest_token.clear();
+    }
+    let guest_complete_str = get_config("GUEST_CAN_COMPLETE", None, &dotenv, "false");
+    config::init_guest_access(&guest_token, guest_complete_str.eq_ignore_ascii_case("true") || guest_complete_str == "1");
+    if config::get_guest_access().is_some() {
+        info!("Guest board: enabled at /guest/<GUEST_TOKEN>");
+    }
+
+    // Get reminder completion links: env var > .env > disabled
+    let link_secret = get_config("COMPLETION_LINK_SECRET", None, &dotenv, "");
+    let public_url = get_config("PUBLIC_URL", None, &dotenv, "");
+    config::init_completion_links(&link_secret, &public_url);
+    if let Some(links) = config::get_completion_links() {
+        info!("Completion links: enabled at {}/complete/<token>", links.base_url);
+    }
+
     // Get database URL: env var > .env > default
     let database_url = get_config("DATABASE_URL", None, &dotenv, "chores.db");
     let database_url = format!("sqlite:{}?mode=rwc", database_url);
@@ -123,18 +251,41 @@ async fn main() -> Result<()> {
         }
     }
 
+    // Settings saved from /settings/app override the startup config above
+    match db::get_app_settings(&pool).await {
+        Ok(stored) => {
+            for (key, value) in stored {
+                match config::apply_app_setting(&key, &value) {
+                    Ok(()) => info!("App setting {} = {} (from the database)", key, value),
+                    Err(e) => warn!("Ignoring stored app setting {}: {}", key, e),
+                }
+            }
+        }
+        Err(e) => warn!("Couldn't load app settings: {}", e),
+    }
+
     // Create photos and thumbnails folders, sync photos
     fs::create_dir_all("photos")?;
     fs::create_dir_all("thumbnails")?;
     let photos_path = Path::new("photos");
     photos::sync_photos(&pool, photos_path).await?;
 
+    // Start the background reminder task (no-op unless a webhook is configured)
+    reminders::spawn(pool.clone());
+
+    // Prune old completions at startup and once a day after (no-op unless retention is set)
+    if let Some(days) = config::get_completion_retention_days() {
+        spawn_completion_pruning(pool.clone(), days);
+    }
+
     fs::create_dir_all("static")?;
     let static_dir = ServeDir::new("static");
 
-    // build our application with a single route
-    let app = Router::new()
-        .route("/", get(tasks::homepage))
+    // Everything but the guest board, share and completion links, the token-checked
+    // API and static files sits behind APP_PASSWORD when it's set
+    let protected = Router::new()
+        .route("/", get(tasks::landing))
+        .route("/dashboard", get(tasks::homepage))
         .route("/idle", get(photos::idle_page))
         .route("/idle/photos", get(photos::idle_photos))
         .route("/photos", get(photos::photos_index))
@@ -151,16 +302,57 @@ async fn main() -> Result<()> {
         .route("/thumbnails/{*path}", get(photos::serve_thumbnail))
         .route("/daily", get(tasks::daily_today))
         .route("/daily/{year}/{month}/{day}", get(tasks::daily_page))
+        .route("/day/{date}", get(tasks::day_page))
+        .route("/print", get(tasks::print_page))
         .route("/calendar", get(tasks::calendar_today))
         .route("/calendar/{year}/{month}", get(tasks::calendar_page))
+        .route("/history", get(tasks::history_page))
+        .route("/stats", get(tasks::stats_page))
+        .route("/events", get(events::stream))
+        .route("/import", get(import::import_page).post(import::import_csv))
+        .route("/import/preview", post(import::import_preview))
+        .route("/import/commit", post(import::import_csv))
+        .route("/trash", get(tasks::trash_page))
+        .route("/trash/restore-all", post(tasks::trash_restore_all))
+        .route("/trash/empty", post(tasks::trash_empty))
+        .route("/trash/{id}/restore", post(tasks::trash_restore))
+        .route("/trash/{id}/delete", post(tasks::trash_delete))
         .route("/settings", get(settings::settings_page).post(settings::save_settings))
+        .route("/settings/app", get(settings::app_settings_page).post(settings::save_app_settings))
+        .route("/settings/share", post(share::create_link))
+        .route("/settings/share/{id}/revoke", post(share::revoke_link))
         .route("/settings/people", post(settings::add_person))
         .route("/settings/people/{id}/delete", post(settings::delete_person))
         .nest("/storybook", storybook::router())
         .nest("/tasks", tasks::router())
+        .route_layer(axum::middleware::from_fn_with_state(config::get_app_password(), auth::require_login));
+
+    // Share links, like the guest board, only make sense with the rest of the app locked
+    let shared = if config::get_app_password().is_some() {
+        Router::new()
+            .route("/share/{token}", get(share::board))
+            .route("/share/{token}/tasks/{id}", get(share::task_show))
+    } else {
+        Router::new()
+    };
+
+    // build our application with a single route
+    let app = Router::new()
+        .route("/guest/{token}", get(guest::board))
+        .route("/guest/{token}/tasks/{id}", get(guest::task_show))
+        .route("/guest/{token}/tasks/{id}/complete", post(guest::complete))
+        .nest("/api", api::router(config::get_api_access()))
+        .nest("/admin", api::admin_router(config::get_api_access()))
+        .merge(metrics::router(config::get_api_access()))
+        .merge(completion_links::router(config::get_completion_links()))
+        .merge(shared)
+        .merge(protected)
         .with_state(pool)
         .nest_service("/static", get_service(static_dir))
         .route_service("/favicon.ico", ServeFile::new("static/favicon.ico"))
+        .fallback(pages::fallback)
+        .layer(axum::middleware::from_fn(error::render_errors))
+        .layer(axum::middleware::from_fn(metrics::count_requests))
         .layer(TraceLayer::new_for_http());
 
     // Get port: CLI flag > env var > .env > 3000
@@ -0,0 +1,160 @@
+use axum::{
+    extract::{Request, State},
+    http::header,
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::api;
+use crate::clock;
+use crate::config::{get_timezone, ApiAccess};
+use crate::db::{self, DbPool};
+use crate::error::AppError;
+use crate::tasks;
+
+/// Responses served since startup, by status class (1xx through 5xx)
+static RESPONSES: [AtomicU64; 5] = [const { AtomicU64::new(0) }; 5];
+
+/// Middleware that counts every response by status class
+pub async fn count_requests(request: Request, next: Next) -> Response {
+    let response = next.run(request).await;
+    let class = (response.status().as_u16() / 100) as usize;
+    if let Some(counter) = class.checked_sub(1).and_then(|i| RESPONSES.get(i)) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+    response
+}
+
+/// `/metrics`, behind the same token as the API so it's off unless the API is enabled
+pub fn router(access: ApiAccess) -> Router<DbPool> {
+    Router::new()
+        .route("/metrics", get(scrape))
+        .layer(middleware::from_fn_with_state(access, api::require_token))
+}
+
+/// The numbers behind one scrape
+struct Snapshot {
+    tasks: i64,
+    overdue: i64,
+    completions: i64,
+    completions_today: i64,
+    responses: [u64; 5],
+}
+
+// GET /metrics - Prometheus text format. Gauges are counted from the database on each scrape.
+async fn scrape(State(pool): State<DbPool>) -> Result<Response, AppError> {
+    let all_tasks = db::get_all_tasks(&pool).await?;
+    let live: Vec<_> = all_tasks.iter().filter(|task| !task.is_deleted()).collect();
+    let overdue = tasks::count_overdue(&pool, &live).await?;
+
+    let tz = get_timezone();
+    let midnight = clock::now()
+        .with_timezone(&tz)
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|dt| dt.and_local_timezone(tz).earliest())
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .unwrap_or_else(clock::now);
+
+    let snapshot = Snapshot {
+        tasks: live.len() as i64,
+        overdue,
+        completions: db::get_completion_count(&pool).await?,
+        // Strictly after `since`, so step back a second to include midnight itself
+        completions_today: db::count_all_completions_since(&pool, midnight - chrono::Duration::seconds(1)).await?,
+        responses: std::array::from_fn(|i| RESPONSES[i].load(Ordering::Relaxed)),
+    };
+
+    Ok(([(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")], render(&snapshot)).into_response())
+}
+
+fn render(snapshot: &Snapshot) -> String {
+    let mut out = String::new();
+    let mut gauge = |name: &str, help: &str, value: i64| {
+        let _ = write!(out, "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n");
+    };
+    gauge("chores_tasks", "Tasks that aren't in the trash", snapshot.tasks);
+    gauge("chores_tasks_overdue", "Completeable tasks past due and not yet completed", snapshot.overdue);
+    gauge("chores_completions", "Completions stored, across all tasks", snapshot.completions);
+    gauge("chores_completions_today", "Completions since local midnight", snapshot.completions_today);
+
+    out.push_str("# HELP chores_http_responses_total Responses served since startup, by status class\n");
+    out.push_str("# TYPE chores_http_responses_total counter\n");
+    for (i, count) in snapshot.responses.iter().enumerate() {
+        let _ = writeln!(out, "chores_http_responses_total{{class=\"{}xx\"}} {}", i + 1, count);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::test_pool;
+    use axum::body::Body;
+    use chrono::{DateTime, Utc};
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_render_prometheus_text() {
+        let text = render(&Snapshot {
+            tasks: 4,
+            overdue: 1,
+            completions: 30,
+            completions_today: 2,
+            responses: [0, 10, 1, 3, 0],
+        });
+        assert!(text.contains("# TYPE chores_tasks gauge\nchores_tasks 4\n"));
+        assert!(text.contains("chores_tasks_overdue 1\n"));
+        assert!(text.contains("chores_completions_today 2\n"));
+        assert!(text.contains("chores_http_responses_total{class=\"4xx\"} 3\n"));
+    }
+
+    #[tokio::test]
+    async fn test_scrape_counts_overdue_tasks() {
+        let pool = test_pool("metrics_test").await;
+        // Two past one-time tasks, one of them done, a counter short of its
+        // target, plus one in the trash
+        sqlx::query("INSERT INTO schedules (id, kind, once_datetime) VALUES (1, 'once', '2026-01-05T09:00:00+00:00')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO tasks (id, name, schedule_id, created_at, deleted_at, target_count) VALUES \
+             (1, 'Dishes', 1, '2026-01-01T00:00:00+00:00', NULL, 1), \
+             (2, 'Trash', 1, '2026-01-01T00:00:00+00:00', NULL, 1), \
+             (3, 'Old', 1, '2026-01-01T00:00:00+00:00', '2026-01-02T00:00:00+00:00', 1), \
+             (4, 'Water', 1, '2026-01-01T00:00:00+00:00', NULL, 2)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        db::add_completion(&pool, "1", None, None, None).await.unwrap();
+        db::add_completion(&pool, "4", None, None, None).await.unwrap();
+        // Before the occurrence, so it neither completes Trash nor counts as today
+        let early = DateTime::parse_from_rfc3339("2026-01-04T09:00:00+00:00").unwrap().with_timezone(&Utc);
+        db::add_completion_at(&pool, "2", early, None, None, None).await.unwrap();
+
+        let app = router(ApiAccess::Open).with_state(pool).layer(middleware::from_fn(count_requests));
+        let request = axum::http::Request::builder().uri("/metrics").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert!(response.headers()[header::CONTENT_TYPE].to_str().unwrap().starts_with("text/plain"));
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        assert!(text.contains("chores_tasks 3\n"), "{}", text);
+        assert!(text.contains("chores_tasks_overdue 2\n"), "{}", text);
+        assert!(text.contains("chores_completions 3\n"), "{}", text);
+        assert!(text.contains("chores_completions_today 2\n"), "{}", text);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_need_api_access() {
+        let pool = test_pool("metrics_access_test").await;
+        let request = axum::http::Request::builder().uri("/metrics").body(Body::empty()).unwrap();
+        let response = router(ApiAccess::Disabled).with_state(pool).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::UNAUTHORIZED);
+    }
+}
@@ -5,9 +5,14 @@
 //!
 //! Reads all non-missing photos from disk and stores their contents in photo_blobs table.
 
+mod clock;
 mod config;
 mod db;
+mod engine;
+mod error;
+mod events;
 mod migrate;
+mod pages;
 mod schedule;
 mod settings;
 mod tasks;
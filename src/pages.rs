@@ -0,0 +1,147 @@
+use axum::{
+    http::{HeaderMap, StatusCode},
+    response::{Html, IntoResponse, Response},
+};
+use hypertext::{prelude::*, Raw};
+
+use crate::settings;
+
+/// Entries in the touch-mode bottom navigation bar
+#[derive(Clone, Copy, PartialEq)]
+pub enum NavItem {
+    Home,
+    Tasks,
+    NewTask,
+    /// A page that isn't one of the above, so nothing is highlighted
+    Other,
+}
+
+/// Fixed bottom navigation with large tap targets, for touch mode only.
+/// Returns an empty string otherwise so callers can include it unconditionally.
+pub fn render_bottom_nav(is_touch: bool, active: NavItem) -> String {
+    if !is_touch {
+        return String::new();
+    }
+
+    let items = [
+        (NavItem::Home, "/", "Home"),
+        (NavItem::Tasks, "/tasks", "Tasks"),
+        (NavItem::NewTask, "/tasks/new", "New Task"),
+    ];
+
+    maud! {
+        // Keeps the last of the page content from hiding behind the bar
+        div .bottom-nav-spacer {}
+        nav .bottom-nav {
+            @for (item, href, label) in items {
+                a .bottom-nav-item .bottom-nav-item-active[item == active] href=(href) { (label) }
+            }
+        }
+    }
+    .render()
+    .into_inner()
+}
+
+/// Wrap already-rendered body HTML in the standard document head
+/// (stylesheets, viewport, htmx) used by full pages, plus the touch-mode
+/// bottom nav.
+pub fn render_page(title: &str, body_html: &str, is_touch: bool, active: NavItem) -> String {
+    maud! {
+        !DOCTYPE
+        html {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { (title) " - Chores" }
+                link rel="stylesheet" href="/static/system.css";
+                link rel="stylesheet" href="/static/app.css";
+                script src="/static/htmx.min.js" {}
+                script src="/static/auto-sleep.js" {}
+            }
+            body {
+                (Raw::dangerously_create(body_html))
+                (Raw::dangerously_create(&render_bottom_nav(is_touch, active)))
+            }
+        }
+    }
+    .render()
+    .into_inner()
+}
+
+/// Styled 404 page with a way back. `back` is an `(href, label)` pair for a
+/// more specific destination than the home page, e.g. the task list.
+pub fn not_found(headers: &HeaderMap, message: &str, back: Option<(&str, &str)>) -> Response {
+    error_page(headers, StatusCode::NOT_FOUND, "Not Found", message, back)
+}
+
+/// Styled error page with the given status, e.g. for an `AppError`
+pub fn error_page(
+    headers: &HeaderMap,
+    status: StatusCode,
+    title: &str,
+    message: &str,
+    back: Option<(&str, &str)>,
+) -> Response {
+    let is_touch = settings::is_touch_mode(headers);
+    let body = maud! {
+        div .not-found-page {
+            div .window {
+                div .title-bar {
+                    h1 .title { (title) }
+                }
+                div .window-pane {
+                    p { (message) }
+                    div .not-found-links {
+                        @if let Some((href, label)) = back {
+                            @if is_touch {
+                                button .btn onclick=(format!("window.location.href='{}'", href)) { (label) }
+                            } @else {
+                                a .btn href=(href) { (label) }
+                            }
+                        }
+                        @if is_touch {
+                            button .btn onclick="window.location.href='/'" { "Home" }
+                        } @else {
+                            a .btn href="/" { "Home" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    .render()
+    .into_inner();
+
+    (status, Html(render_page(title, &body, is_touch, NavItem::Other))).into_response()
+}
+
+// Fallback for any path no route matched
+pub async fn fallback(headers: HeaderMap) -> Response {
+    not_found(&headers, "There's nothing at this address. It may have been moved or deleted.", None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fallback_is_styled_404() {
+        let response = fallback(HeaderMap::new()).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("/static/app.css"));
+        assert!(html.contains("href=\"/\""));
+    }
+
+    #[test]
+    fn test_bottom_nav_only_in_touch_mode() {
+        assert_eq!(render_bottom_nav(false, NavItem::Home), "");
+
+        let nav = render_bottom_nav(true, NavItem::Tasks);
+        assert!(nav.contains("href=\"/tasks/new\""));
+        assert_eq!(nav.matches("bottom-nav-item-active").count(), 1);
+        assert!(nav.contains("bottom-nav-item bottom-nav-item-active\" href=\"/tasks\""));
+    }
+}
@@ -1613,11 +1613,7 @@ pub async fn upload_photo(
                 .unwrap_or_default();
             
             if !IMAGE_EXTENSIONS.contains(&ext.as_str()) {
-                return Response::builder()
-                    .status(StatusCode::BAD_REQUEST)
-                    .header(header::CONTENT_TYPE, "text/html")
-                    .body(Body::from(r#"<div class="upload-error">Invalid file type. Allowed: jpg, jpeg, png, gif, webp</div>"#))
-                    .unwrap();
+                return (StatusCode::BAD_REQUEST, Html(r#"<div class="upload-error">Invalid file type. Allowed: jpg, jpeg, png, gif, webp</div>"#)).into_response();
             }
             
             match field.bytes().await {
@@ -1626,11 +1622,7 @@ pub async fn upload_photo(
                 }
                 Err(e) => {
                     error!(error = %e, "Failed to read upload data");
-                    return Response::builder()
-                        .status(StatusCode::BAD_REQUEST)
-                        .header(header::CONTENT_TYPE, "text/html")
-                        .body(Body::from(r#"<div class="upload-error">Failed to read file data</div>"#))
-                        .unwrap();
+                    return (StatusCode::BAD_REQUEST, Html(r#"<div class="upload-error">Failed to read file data</div>"#)).into_response();
                 }
             }
         }
@@ -1639,11 +1631,7 @@ pub async fn upload_photo(
     let (original_filename, bytes) = match file_data {
         Some(data) => data,
         None => {
-            return Response::builder()
-                .status(StatusCode::BAD_REQUEST)
-                .header(header::CONTENT_TYPE, "text/html")
-                .body(Body::from(r#"<div class="upload-error">No file uploaded</div>"#))
-                .unwrap();
+            return (StatusCode::BAD_REQUEST, Html(r#"<div class="upload-error">No file uploaded</div>"#)).into_response();
         }
     };
     
@@ -1668,11 +1656,7 @@ pub async fn upload_photo(
     
     if let Err(e) = fs::write(&file_path, &bytes).await {
         error!(error = %e, path = %file_path.display(), "Failed to write photo file");
-        return Response::builder()
-            .status(StatusCode::INTERNAL_SERVER_ERROR)
-            .header(header::CONTENT_TYPE, "text/html")
-            .body(Body::from(r#"<div class="upload-error">Failed to save file</div>"#))
-            .unwrap();
+        return (StatusCode::INTERNAL_SERVER_ERROR, Html(r#"<div class="upload-error">Failed to save file</div>"#)).into_response();
     }
     
     let default_config = serde_json::to_string(&PhotoConfig::default()).unwrap_or_else(|_| "{}".to_string());
@@ -1689,11 +1673,7 @@ pub async fn upload_photo(
         Err(e) => {
             error!(error = %e, filename = %final_filename, "Failed to insert photo into database");
             let _ = fs::remove_file(&file_path).await;
-            return Response::builder()
-                .status(StatusCode::INTERNAL_SERVER_ERROR)
-                .header(header::CONTENT_TYPE, "text/html")
-                .body(Body::from(r#"<div class="upload-error">Failed to save to database</div>"#))
-                .unwrap();
+            return (StatusCode::INTERNAL_SERVER_ERROR, Html(r#"<div class="upload-error">Failed to save to database</div>"#)).into_response();
         }
     };
     
@@ -1704,13 +1684,12 @@ pub async fn upload_photo(
     
     info!(filename = %final_filename, id = photo_id, "Photo uploaded successfully");
     
-    Response::builder()
-        .status(StatusCode::OK)
-        .header("HX-Redirect", format!("/photo/{}/edit", photo_id))
-        .header(header::CONTENT_TYPE, "text/html")
-        .body(Body::from(format!(
+    (
+        [("HX-Redirect", format!("/photo/{}/edit", photo_id))],
+        Html(format!(
             r#"<div class="upload-success">Photo uploaded successfully as {}</div>"#,
-            final_filename
-        )))
-        .unwrap()
+            crate::tasks::html_escape(&final_filename)
+        )),
+    )
+        .into_response()
 }
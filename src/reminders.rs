@@ -0,0 +1,619 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use tracing::{error, info};
+
+use crate::clock;
+use crate::completion_links;
+use crate::config::{self, CompletionLinks, DigestMode, NotifyChannel, QuietHours};
+use crate::db::{self, DbPool};
+use crate::tasks::{self, DemoTask};
+
+/// How often the background task checks for due tasks
+const CHECK_INTERVAL_SECS: u64 = 60;
+
+/// How long an occurrence that's no longer due is remembered as reminded about
+const FORGET_AFTER_DAYS: i64 = 2;
+
+/// A single task occurrence that needs a reminder
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reminder {
+    pub task_id: String,
+    pub task_name: String,
+    pub due: DateTime<Utc>,
+    /// Due any time on the day; the text leaves the time out
+    pub all_day: bool,
+    /// When the occurrence ends (the next one is due); completion links stop working then
+    pub until: DateTime<Utc>,
+}
+
+/// Tracks which occurrences have already been reminded about and which
+/// reminders are waiting to be sent (e.g. held back by quiet hours or the
+/// per-task interval).
+#[derive(Debug, Default)]
+pub struct ReminderQueue {
+    seen: HashSet<(String, i64)>,
+    pending: Vec<Reminder>,
+    /// When each task was last included in a sent reminder
+    last_notified: HashMap<String, DateTime<Utc>>,
+    /// Shortest gap between two reminders for the same task
+    min_interval: Duration,
+}
+
+impl ReminderQueue {
+    pub fn with_min_interval(min_interval: Duration) -> Self {
+        ReminderQueue { min_interval, ..Default::default() }
+    }
+
+    /// Queue a reminder unless this occurrence was already queued. Returns true if queued.
+    pub fn enqueue(&mut self, reminder: Reminder) -> bool {
+        let key = (reminder.task_id.clone(), reminder.due.timestamp());
+        if !self.seen.insert(key) {
+            return false;
+        }
+        self.pending.push(reminder);
+        true
+    }
+
+    /// Take everything pending, unless `local_time` falls inside quiet hours,
+    /// in which case reminders keep accumulating until quiet hours end.
+    /// With a minimum interval, a task reminded about less than that long ago
+    /// stays pending, and several pending occurrences of a task go out as one.
+    /// The interval only spaces out distinct occurrences; the seen set is what
+    /// keeps any one occurrence from being sent twice.
+    pub fn take_ready(&mut self, local_time: NaiveTime, quiet_hours: Option<&QuietHours>) -> Vec<Reminder> {
+        if quiet_hours.is_some_and(|q| q.contains(local_time)) {
+            return Vec::new();
+        }
+
+        let now = clock::now();
+        let mut ready: Vec<Reminder> = Vec::new();
+        for reminder in std::mem::take(&mut self.pending) {
+            let recently_notified = self
+                .last_notified
+                .get(&reminder.task_id)
+                .is_some_and(|last| now - *last < self.min_interval);
+            if recently_notified {
+                self.pending.push(reminder);
+            } else if let Some(earlier) = ready
+                .iter_mut()
+                .find(|r| !self.min_interval.is_zero() && r.task_id == reminder.task_id)
+            {
+                *earlier = reminder;
+            } else {
+                ready.push(reminder);
+            }
+        }
+        for reminder in &ready {
+            self.last_notified.insert(reminder.task_id.clone(), now);
+        }
+        ready
+    }
+
+    /// Queue one check's worth of due reminders, then forget what's over.
+    /// Reminders still held back (by quiet hours or the interval) are dropped
+    /// once their occurrence is no longer due, e.g. because it was completed.
+    pub fn refresh(&mut self, due: Vec<Reminder>, now: DateTime<Utc>) {
+        let cutoff = now - Duration::days(FORGET_AFTER_DAYS);
+        self.prune(cutoff, &due);
+        self.pending
+            .retain(|held| due.iter().any(|r| r.task_id == held.task_id && r.due == held.due));
+        for reminder in due {
+            self.enqueue(reminder);
+        }
+    }
+
+    /// Forget occurrences that were due before the cutoff, so the seen set doesn't
+    /// grow forever. Occurrences in `still_due` are kept however old they are: a
+    /// long-overdue task is still reported every check and must not be re-sent.
+    pub fn prune(&mut self, cutoff: DateTime<Utc>, still_due: &[Reminder]) {
+        let is_still_due = |task_id: &str, due: Option<i64>| {
+            still_due
+                .iter()
+                .any(|r| r.task_id == task_id && due.is_none_or(|due| r.due.timestamp() == due))
+        };
+        self.seen
+            .retain(|(task_id, due)| *due >= cutoff.timestamp() || is_still_due(task_id, Some(*due)));
+        self.last_notified
+            .retain(|task_id, notified| *notified >= cutoff || is_still_due(task_id, None));
+    }
+}
+
+/// Build the notification text. Several reminders collapse into a single summary.
+/// With completion links enabled, each task gets a "Mark done" link.
+pub fn render_reminder(reminders: &[Reminder], links: Option<&CompletionLinks>) -> String {
+    let tz = config::get_timezone();
+    let text = match reminders {
+        [] => String::new(),
+        [single] if single.all_day => format!("{} is due today", single.task_name),
+        [single] => format!(
+            "{} is due at {}",
+            single.task_name,
+            single.due.with_timezone(&tz).format("%H:%M")
+        ),
+        many => {
+            let names: Vec<&str> = many.iter().map(|r| r.task_name.as_str()).collect();
+            format!("You have {} tasks due: {}", many.len(), names.join(", "))
+        }
+    };
+
+    let Some(links) = links else {
+        return text;
+    };
+    match reminders {
+        [] => text,
+        [single] => format!("{}. Mark done: {}", text, completion_links::url_for(links, single)),
+        many => {
+            let lines: Vec<String> = many
+                .iter()
+                .map(|r| format!("Mark {} done: {}", r.task_name, completion_links::url_for(links, r)))
+                .collect();
+            format!("{}\n{}", text, lines.join("\n"))
+        }
+    }
+}
+
+/// Build the daily digest text for the next day's occurrences, in due order
+pub fn render_digest(reminders: &[Reminder]) -> String {
+    let tz = config::get_timezone();
+    let items: Vec<String> = reminders
+        .iter()
+        .map(|r| if r.all_day {
+            format!("{} (all day)", r.task_name)
+        } else {
+            format!("{} ({})", r.task_name, r.due.with_timezone(&tz).format("%H:%M"))
+        })
+        .collect();
+    match items.len() {
+        0 => String::new(),
+        1 => format!("Tomorrow you have 1 chore due: {}", items[0]),
+        n => format!("Tomorrow you have {} chores due: {}", n, items.join(", ")),
+    }
+}
+
+/// Whether today's digest should go out: the digest time has passed and it hasn't been sent today
+pub fn digest_is_due(last_sent: Option<NaiveDate>, local_now: NaiveDateTime, digest_time: NaiveTime) -> bool {
+    local_now.time() >= digest_time && last_sent != Some(local_now.date())
+}
+
+/// Start the background reminder task if a webhook is configured
+pub fn spawn(pool: DbPool) {
+    let Some(webhook_url) = config::get_reminder_webhook() else {
+        return;
+    };
+    info!("Reminders: enabled");
+    if let Some(quiet) = config::get_quiet_hours() {
+        info!("Reminders: quiet hours {} to {}", quiet.start.format("%H:%M"), quiet.end.format("%H:%M"));
+    }
+
+    let digest = config::get_digest();
+    if let Some(digest) = digest {
+        let mode = match digest.mode {
+            DigestMode::Supplement => "alongside per-task reminders",
+            DigestMode::Replace => "instead of per-task reminders",
+        };
+        info!("Reminders: daily digest at {} {}", digest.time.format("%H:%M"), mode);
+    }
+
+    tokio::spawn(async move {
+        let client = reqwest::Client::new();
+        let mut queue = ReminderQueue::with_min_interval(config::get_min_notify_interval());
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+
+        // A restart after the digest time shouldn't send that day's digest a second time
+        let start_local = Utc::now().with_timezone(&config::get_timezone()).naive_local();
+        let mut digest_sent_on = digest
+            .filter(|d| start_local.time() >= d.time)
+            .map(|_| start_local.date());
+
+        loop {
+            interval.tick().await;
+            let now = clock::now();
+            let local_now = now.with_timezone(&config::get_timezone()).naive_local();
+
+            if let Some(digest) = digest
+                && digest_is_due(digest_sent_on, local_now, digest.time)
+            {
+                digest_sent_on = Some(local_now.date());
+                let upcoming = collect_for_date(&pool, local_now.date() + Duration::days(1)).await;
+                if !upcoming.is_empty() {
+                    send(&client, &webhook_url, &render_digest(&upcoming), &upcoming).await;
+                }
+            }
+
+            if digest.is_some_and(|d| d.mode == DigestMode::Replace) {
+                continue;
+            }
+
+            queue.refresh(collect_due(&pool).await, now);
+
+            let ready = queue.take_ready(local_now.time(), config::get_quiet_hours().as_ref());
+            if ready.is_empty() {
+                continue;
+            }
+            let text = render_reminder(&ready, config::get_completion_links().as_ref());
+            send(&client, &webhook_url, &text, &ready).await;
+        }
+    });
+}
+
+/// POST a notification to the webhook, logging the outcome
+async fn send(client: &reqwest::Client, webhook_url: &str, text: &str, reminders: &[Reminder]) {
+    let payload = json!({
+        "text": text,
+        "tasks": reminders.iter().map(|r| r.task_name.as_str()).collect::<Vec<_>>(),
+    });
+    match client.post(webhook_url).json(&payload).send().await {
+        Ok(resp) if resp.status().is_success() => {
+            info!("Sent reminder for {} task(s)", reminders.len());
+        }
+        Ok(resp) => error!("Reminder webhook returned {}", resp.status()),
+        Err(e) => error!("Failed to send reminder: {}", e),
+    }
+}
+
+/// Occurrences of webhook-notified, completeable tasks on the given local date, earliest first
+async fn collect_for_date(pool: &DbPool, date: NaiveDate) -> Vec<Reminder> {
+    let all_tasks: Vec<DemoTask> = match db::get_all_tasks(pool).await {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            error!("Reminders: failed to load tasks: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let tz = config::get_timezone();
+    let mut reminders: Vec<Reminder> = all_tasks
+        .into_iter()
+        .filter(|t| t.completeable && !t.is_deleted())
+        .filter(|t| t.effective_notify_channels().contains(&NotifyChannel::Webhook))
+        .filter(|t| tasks::is_due_on_date(t, date))
+        .filter_map(|t| {
+            let due = tz
+                .from_local_datetime(&date.and_time(tasks::get_due_time(&t, date)))
+                .earliest()?
+                .with_timezone(&Utc);
+            let until = tasks::occurrence_window(&t, due).1;
+            Some(Reminder { task_id: t.id, task_name: t.name, due, all_day: t.all_day, until })
+        })
+        .collect();
+    reminders.sort_by_key(|r| r.due);
+    reminders
+}
+
+/// Find completeable tasks that are due or alerting and haven't been completed for this occurrence
+async fn collect_due(pool: &DbPool) -> Vec<Reminder> {
+    let tasks: Vec<DemoTask> = match db::get_all_tasks(pool).await {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            error!("Reminders: failed to load tasks: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut reminders = Vec::new();
+    for task in tasks {
+        if !task.completeable || !(task.is_due() || task.is_alerting()) {
+            continue;
+        }
+        if !task.effective_notify_channels().contains(&NotifyChannel::Webhook) {
+            continue;
+        }
+        let due = task.all_day_due_today().unwrap_or_else(|| task.next_due_date());
+        // Done by the dashboard's measure (counters: target reached) means no reminder
+        match tasks::occurrence_completed(pool, &task, task.most_recent_due_date()).await {
+            Ok(true) => continue,
+            Ok(false) => {}
+            Err(e) => {
+                error!("Reminders: failed to load completions for {}: {}", task.id, e);
+                continue;
+            }
+        }
+        let until = tasks::occurrence_window(&task, due).1;
+        reminders.push(Reminder {
+            task_id: task.id,
+            task_name: task.name,
+            due,
+            all_day: task.all_day,
+            until,
+        });
+    }
+    reminders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn reminder(id: &str, name: &str, hour: u32) -> Reminder {
+        Reminder {
+            task_id: id.to_string(),
+            task_name: name.to_string(),
+            due: Utc.with_ymd_and_hms(2026, 3, 10, hour, 0, 0).unwrap(),
+            all_day: false,
+            until: Utc.with_ymd_and_hms(2026, 3, 11, hour, 0, 0).unwrap(),
+        }
+    }
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    // ========================================================================
+    // Quiet hours tests
+    // ========================================================================
+
+    #[test]
+    fn test_quiet_hours_same_day() {
+        let quiet = QuietHours { start: time(13, 0), end: time(15, 0) };
+        assert!(!quiet.contains(time(12, 59)));
+        assert!(quiet.contains(time(13, 0)));
+        assert!(quiet.contains(time(14, 30)));
+        assert!(!quiet.contains(time(15, 0)));
+    }
+
+    #[test]
+    fn test_quiet_hours_wraps_midnight() {
+        let quiet = QuietHours { start: time(22, 0), end: time(7, 0) };
+        assert!(quiet.contains(time(23, 0)));
+        assert!(quiet.contains(time(3, 0)));
+        assert!(!quiet.contains(time(7, 0)));
+        assert!(!quiet.contains(time(12, 0)));
+    }
+
+    // ========================================================================
+    // Channel tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_notify_channels() {
+        assert_eq!(config::parse_notify_channels("webhook"), vec![NotifyChannel::Webhook]);
+        assert_eq!(config::parse_notify_channels(" Webhook , none"), vec![NotifyChannel::Webhook]);
+        assert!(config::parse_notify_channels("none").is_empty());
+        assert!(config::parse_notify_channels("").is_empty());
+        assert!(config::parse_notify_channels("pigeon").is_empty());
+    }
+
+    // ========================================================================
+    // Queue tests
+    // ========================================================================
+
+    #[test]
+    fn test_queue_deduplicates_occurrences() {
+        let mut queue = ReminderQueue::default();
+        assert!(queue.enqueue(reminder("1", "Dishes", 3)));
+        assert!(!queue.enqueue(reminder("1", "Dishes", 3)));
+        assert!(queue.enqueue(reminder("1", "Dishes", 4)));
+        assert_eq!(queue.take_ready(time(12, 0), None).len(), 2);
+    }
+
+    #[test]
+    fn test_queue_defers_during_quiet_hours() {
+        let quiet = QuietHours { start: time(22, 0), end: time(7, 0) };
+        let mut queue = ReminderQueue::default();
+        queue.enqueue(reminder("1", "Dishes", 2));
+        assert!(queue.take_ready(time(2, 0), Some(&quiet)).is_empty());
+        queue.enqueue(reminder("2", "Trash", 4));
+        assert!(queue.take_ready(time(6, 59), Some(&quiet)).is_empty());
+
+        let ready = queue.take_ready(time(7, 0), Some(&quiet));
+        assert_eq!(ready.len(), 2);
+        assert!(queue.take_ready(time(7, 1), Some(&quiet)).is_empty());
+    }
+
+    #[test]
+    fn test_queue_limits_each_task_to_one_reminder_per_interval() {
+        let at = |h: u32, m: u32| Utc.with_ymd_and_hms(2026, 3, 10, h, m, 0).unwrap();
+        let mut queue = ReminderQueue::with_min_interval(Duration::minutes(15));
+
+        let _clock = clock::freeze(at(3, 0));
+        queue.enqueue(reminder("1", "Dishes", 3));
+        assert_eq!(queue.take_ready(time(3, 0), None).len(), 1);
+
+        // A new occurrence five minutes later waits out the interval, as does a
+        // third one; both go out as a single reminder for the latest occurrence
+        let mut soon = reminder("1", "Dishes", 3);
+        soon.due = at(3, 5);
+        queue.enqueue(soon);
+        queue.enqueue(reminder("2", "Trash", 3));
+        drop(_clock);
+        let _clock = clock::freeze(at(3, 5));
+        let ready = queue.take_ready(time(3, 5), None);
+        assert_eq!(ready, vec![reminder("2", "Trash", 3)]);
+
+        let mut later = reminder("1", "Dishes", 3);
+        later.due = at(3, 10);
+        queue.enqueue(later.clone());
+        drop(_clock);
+        let _clock = clock::freeze(at(3, 14));
+        assert!(queue.take_ready(time(3, 14), None).is_empty());
+
+        drop(_clock);
+        let _clock = clock::freeze(at(3, 15));
+        assert_eq!(queue.take_ready(time(3, 15), None), vec![later]);
+    }
+
+    #[test]
+    fn test_queue_interval_does_not_repeat_an_overdue_occurrence() {
+        let overdue = reminder("1", "Dentist", 3);
+        let mut queue = ReminderQueue::with_min_interval(Duration::minutes(15));
+        let mut sent = 0;
+        // Two hours of checks, long past the interval, three days after it was due
+        for minutes in 0..120 {
+            let now = overdue.due + Duration::days(3) + Duration::minutes(minutes);
+            let _clock = clock::freeze(now);
+            queue.refresh(vec![overdue.clone()], now);
+            sent += queue.take_ready(time(12, 0), None).len();
+        }
+        assert_eq!(sent, 1);
+    }
+
+    #[test]
+    fn test_queue_drops_held_reminders_once_done() {
+        let quiet = QuietHours { start: time(22, 0), end: time(7, 0) };
+        let dishes = reminder("1", "Dishes", 3);
+        let mut queue = ReminderQueue::default();
+        queue.refresh(vec![dishes.clone(), reminder("2", "Trash", 3)], dishes.due);
+        assert!(queue.take_ready(time(23, 0), Some(&quiet)).is_empty());
+
+        // Dishes is done overnight, so only Trash is left when quiet hours end
+        queue.refresh(vec![reminder("2", "Trash", 3)], dishes.due + Duration::minutes(30));
+        assert_eq!(queue.take_ready(time(7, 0), Some(&quiet)), vec![reminder("2", "Trash", 3)]);
+
+        queue.refresh(vec![dishes.clone()], dishes.due);
+        queue.refresh(Vec::new(), dishes.due + Duration::minutes(30));
+        assert!(queue.take_ready(time(7, 5), Some(&quiet)).is_empty());
+    }
+
+    #[test]
+    fn test_queue_drops_reminders_held_by_the_interval_once_done() {
+        let at = |h: u32, m: u32| Utc.with_ymd_and_hms(2026, 3, 10, h, m, 0).unwrap();
+        let mut queue = ReminderQueue::with_min_interval(Duration::minutes(15));
+        let first = reminder("1", "Dishes", 3);
+        let mut second = reminder("1", "Dishes", 3);
+        second.due = at(3, 5);
+
+        let _clock = clock::freeze(at(3, 0));
+        queue.refresh(vec![first.clone()], at(3, 0));
+        assert_eq!(queue.take_ready(time(3, 0), None).len(), 1);
+
+        // The next occurrence waits out the interval, and is done before it's over
+        drop(_clock);
+        let _clock = clock::freeze(at(3, 5));
+        queue.refresh(vec![second], at(3, 5));
+        assert!(queue.take_ready(time(3, 5), None).is_empty());
+        drop(_clock);
+        let _clock = clock::freeze(at(3, 15));
+        queue.refresh(Vec::new(), at(3, 15));
+        assert!(queue.take_ready(time(3, 15), None).is_empty());
+    }
+
+    #[test]
+    fn test_queue_prune_forgets_old_occurrences() {
+        let mut queue = ReminderQueue::default();
+        queue.enqueue(reminder("1", "Dishes", 3));
+        queue.take_ready(time(12, 0), None);
+        queue.prune(Utc.with_ymd_and_hms(2026, 3, 11, 0, 0, 0).unwrap(), &[]);
+        // Once pruned, the occurrence is forgotten and could be queued again
+        assert!(queue.enqueue(reminder("1", "Dishes", 3)));
+    }
+
+    #[test]
+    fn test_queue_sends_long_overdue_occurrence_once() {
+        // A one-time task left undone stays due, so every check reports it again
+        let overdue = reminder("1", "Dentist", 3);
+        let mut queue = ReminderQueue::default();
+        let mut sent = 0;
+        for minutes in 0..10 {
+            let now = overdue.due + Duration::days(5) + Duration::minutes(minutes);
+            queue.refresh(vec![overdue.clone()], now);
+            sent += queue.take_ready(time(12, 0), None).len();
+        }
+        assert_eq!(sent, 1);
+
+        // Once it's done (no longer reported), it's forgotten
+        queue.refresh(Vec::new(), overdue.due + Duration::days(6));
+        assert!(queue.enqueue(overdue));
+    }
+
+    // ========================================================================
+    // Message tests
+    // ========================================================================
+
+    #[test]
+    fn test_render_reminder_collapses_multiple() {
+        let ready = vec![reminder("1", "Dishes", 2), reminder("2", "Trash", 4), reminder("3", "Plants", 5)];
+        assert_eq!(render_reminder(&ready, None), "You have 3 tasks due: Dishes, Trash, Plants");
+    }
+
+    #[test]
+    fn test_render_reminder_single() {
+        assert_eq!(render_reminder(&[reminder("1", "Dishes", 2)], None), "Dishes is due at 02:00");
+    }
+
+    #[test]
+    fn test_render_reminder_with_completion_links() {
+        let links = CompletionLinks { secret: "secret".to_string(), base_url: "https://chores.example".to_string() };
+        let text = render_reminder(&[reminder("1", "Dishes", 2)], Some(&links));
+        let (message, url) = text.split_once(". Mark done: ").unwrap();
+        assert_eq!(message, "Dishes is due at 02:00");
+        let token = url.strip_prefix("https://chores.example/complete/").unwrap();
+        let target = completion_links::verify("secret", token, Utc.with_ymd_and_hms(2026, 3, 10, 2, 5, 0).unwrap()).unwrap();
+        assert_eq!(target.task_id, "1");
+
+        let text = render_reminder(&[reminder("1", "Dishes", 2), reminder("2", "Trash", 4)], Some(&links));
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "You have 2 tasks due: Dishes, Trash");
+        assert!(lines[2].starts_with("Mark Trash done: https://chores.example/complete/2."));
+    }
+
+    #[test]
+    fn test_render_all_day_reminders_leave_out_the_time() {
+        let laundry = Reminder { all_day: true, ..reminder("1", "Laundry", 0) };
+        assert_eq!(render_reminder(std::slice::from_ref(&laundry), None), "Laundry is due today");
+        assert_eq!(
+            render_digest(&[laundry, reminder("2", "Trash", 19)]),
+            "Tomorrow you have 2 chores due: Laundry (all day), Trash (19:00)"
+        );
+    }
+
+    #[test]
+    fn test_render_digest() {
+        assert_eq!(render_digest(&[]), "");
+        assert_eq!(render_digest(&[reminder("1", "Dishes", 8)]), "Tomorrow you have 1 chore due: Dishes (08:00)");
+        assert_eq!(
+            render_digest(&[reminder("1", "Dishes", 8), reminder("2", "Trash", 19)]),
+            "Tomorrow you have 2 chores due: Dishes (08:00), Trash (19:00)"
+        );
+    }
+
+    // ========================================================================
+    // Digest timing tests
+    // ========================================================================
+
+    #[test]
+    fn test_digest_is_due_once_per_day() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let at = time(20, 0);
+        assert!(!digest_is_due(None, day.and_time(time(19, 59)), at));
+        assert!(digest_is_due(None, day.and_time(time(20, 0)), at));
+        assert!(digest_is_due(None, day.and_time(time(23, 30)), at));
+        // Already sent today
+        assert!(!digest_is_due(Some(day), day.and_time(time(20, 1)), at));
+        // Sent yesterday, due again once today's time comes around
+        let next = day.succ_opt().unwrap();
+        assert!(!digest_is_due(Some(day), next.and_time(time(8, 0)), at));
+        assert!(digest_is_due(Some(day), next.and_time(time(20, 0)), at));
+    }
+
+    // ========================================================================
+    // Collection tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_collect_due_uses_the_dashboards_occurrence_window() {
+        use crate::db::test_support::test_pool;
+        use crate::schedule::ScheduleKind;
+
+        let pool = test_pool("reminders_collect_due").await;
+        let due = Utc::now() - Duration::minutes(5);
+        let mut task = tasks::create_default_task();
+        task.name = "Water plants".to_string();
+        task.schedule_kind = ScheduleKind::Once;
+        task.once.datetime = due;
+        task.alerting_time = 60;
+        task.target_count = 2;
+        task.notify_channels = "webhook".to_string();
+        let id = db::save_task(&pool, &task).await.unwrap().to_string();
+
+        // Before the due time, so not part of the occurrence on the dashboard either
+        db::add_completion_at(&pool, &id, due - Duration::minutes(10), None, None, None).await.unwrap();
+        db::add_completion_at(&pool, &id, due + Duration::minutes(1), None, None, None).await.unwrap();
+        let reminders = collect_due(&pool).await;
+        assert_eq!(reminders.iter().map(|r| r.task_id.as_str()).collect::<Vec<_>>(), vec![id.as_str()]);
+
+        db::add_completion_at(&pool, &id, due + Duration::minutes(2), None, None, None).await.unwrap();
+        assert!(collect_due(&pool).await.is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use chrono::{DateTime, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, PartialEq)]
 pub enum ScheduleKind {
@@ -10,23 +11,116 @@ pub enum ScheduleKind {
     Once,
 }
 
+impl ScheduleKind {
+    /// The identifier used for this kind in the database and in forms
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScheduleKind::NDays => "n_days",
+            ScheduleKind::NWeeks => "n_weeks",
+            ScheduleKind::Monthwise => "monthwise",
+            ScheduleKind::WeeksOfMonth => "weeks_of_month",
+            ScheduleKind::CertainMonths => "certain_months",
+            ScheduleKind::Once => "once",
+        }
+    }
+
+    /// Parse an identifier from `as_str`; None if it isn't a known kind
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "n_days" => Some(ScheduleKind::NDays),
+            "n_weeks" => Some(ScheduleKind::NWeeks),
+            "monthwise" => Some(ScheduleKind::Monthwise),
+            "weeks_of_month" => Some(ScheduleKind::WeeksOfMonth),
+            "certain_months" => Some(ScheduleKind::CertainMonths),
+            "once" => Some(ScheduleKind::Once),
+            _ => None,
+        }
+    }
+}
+
+/// A whole schedule as one value: the kind plus that kind's fields. Tasks
+/// keep every kind's fields side by side; the API reads and writes this.
+/// The tag is the same identifier as `ScheduleKind::as_str`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Schedule {
+    NDays(NDays),
+    NWeeks(NWeeks),
+    Monthwise(Monthwise),
+    WeeksOfMonth(WeeksOfMonth),
+    CertainMonths(CertainMonths),
+    Once(Once),
+}
+
+impl Schedule {
+    pub fn kind(&self) -> ScheduleKind {
+        match self {
+            Schedule::NDays(_) => ScheduleKind::NDays,
+            Schedule::NWeeks(_) => ScheduleKind::NWeeks,
+            Schedule::Monthwise(_) => ScheduleKind::Monthwise,
+            Schedule::WeeksOfMonth(_) => ScheduleKind::WeeksOfMonth,
+            Schedule::CertainMonths(_) => ScheduleKind::CertainMonths,
+            Schedule::Once(_) => ScheduleKind::Once,
+        }
+    }
+}
+
 /// A one-time event at a specific date and time
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Once {
     pub datetime: DateTime<Utc>,
 }
 
 /// Every so-and-so-many days, at a certain time.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct NDays {
     pub days: i32,
     pub time: NaiveTime,
+    #[serde(default)]
+    pub skip_weekends: SkipWeekends,
+}
+
+/// How an NDays schedule treats Saturdays and Sundays
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkipWeekends {
+    /// Weekends are ordinary days
+    #[default]
+    Off,
+    /// The cadence counts calendar days, but an occurrence landing on a
+    /// weekend is shown on the following Monday instead
+    #[serde(rename = "calendar")]
+    CalendarDays,
+    /// Weekends don't exist as far as the cadence is concerned:
+    /// "every 2 days" means every other weekday
+    #[serde(rename = "weekdays")]
+    WeekdaysOnly,
+}
+
+impl SkipWeekends {
+    /// The identifier used in the database and in forms
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkipWeekends::Off => "off",
+            SkipWeekends::CalendarDays => "calendar",
+            SkipWeekends::WeekdaysOnly => "weekdays",
+        }
+    }
+
+    /// Parse a stored identifier; anything unknown means weekends are included
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "calendar" => SkipWeekends::CalendarDays,
+            "weekdays" => SkipWeekends::WeekdaysOnly,
+            _ => SkipWeekends::Off,
+        }
+    }
 }
 
 /// Every so-and-so-many weeks on certain days,
 /// e.g. Every other week on Tuesdays
 /// Or, every Tuesday and Thursday
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct NWeeks {
     pub weeks: i32,
     pub offset: i32,
@@ -35,8 +129,9 @@ pub struct NWeeks {
 
 /// On certain days of each month, e.g. 1st and 15th
 /// at a certain time
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Monthwise {
+    /// Days of the month; a negative -N means "N through the end of the month"
     pub days: Vec<i32>,
     pub time: NaiveTime,
 }
@@ -44,7 +139,7 @@ pub struct Monthwise {
 /// On certain nth weekdays,
 /// e.g. Every 2nd and 3rd Tuesday
 /// or every Tuesday and Thursday except if it's the fifth week of the month
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WeeksOfMonth {
     pub weeks: Vec<i32>,
     pub sub_schedule: DaysOfWeek,
@@ -52,14 +147,15 @@ pub struct WeeksOfMonth {
 
 /// On certain days of certain months,
 /// e.g. the 15th and 20th of February and March
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct CertainMonths {
     pub months: Vec<i32>,
+    /// Days of the month; a negative -N means "N through the end of the month"
     pub days: Vec<i32>,
     pub time: NaiveTime,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct DaysOfWeek {
     pub sunday: bool,
     pub monday: bool,
@@ -83,6 +179,11 @@ impl DaysOfWeek {
             Weekday::Sat => self.saturday,
         }
     }
+
+    /// No day of the week is selected, so the schedule never fires
+    pub fn is_empty(&self) -> bool {
+        !(self.sunday || self.monday || self.tuesday || self.wednesday || self.thursday || self.friday || self.saturday)
+    }
 }
 
 #[cfg(test)]
@@ -110,4 +211,27 @@ mod tests {
         assert!(!schedule.active(Weekday::Fri));
         assert!(schedule.active(Weekday::Sat));
     }
+
+    #[test]
+    fn test_skip_weekends_round_trips() {
+        for mode in [SkipWeekends::Off, SkipWeekends::CalendarDays, SkipWeekends::WeekdaysOnly] {
+            assert_eq!(SkipWeekends::parse(mode.as_str()), mode);
+        }
+        assert_eq!(SkipWeekends::parse(""), SkipWeekends::Off);
+    }
+
+    #[test]
+    fn test_schedule_kind_round_trips() {
+        for kind in [
+            ScheduleKind::NDays,
+            ScheduleKind::NWeeks,
+            ScheduleKind::Monthwise,
+            ScheduleKind::WeeksOfMonth,
+            ScheduleKind::CertainMonths,
+            ScheduleKind::Once,
+        ] {
+            assert!(ScheduleKind::parse(kind.as_str()) == Some(kind));
+        }
+        assert!(ScheduleKind::parse("weekly").is_none());
+    }
 }
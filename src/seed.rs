@@ -1,40 +1,90 @@
 //! Seed binary for populating the chores database with initial tasks.
 //!
-//! Usage: cargo run --bin seed
+//! Usage: cargo run --bin seed [-- --file tasks.json] [--format json]
 //!
-//! Reads from seed.toml in the project root and inserts tasks into the database.
+//! Reads from seed.toml in the project root (or the given file) and inserts
+//! tasks into the database. TOML and JSON files share the same shape: a
+//! top-level `tasks` list of the fields in `SeedTask`.
 
+mod clock;
 mod config;
 mod db;
+mod engine;
+mod error;
+mod events;
 mod migrate;
+mod pages;
 mod schedule;
 mod settings;
 mod tasks;
 
 use anyhow::Result;
 use chrono::NaiveTime;
+use clap::{Parser, ValueEnum};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
 
-use crate::schedule::{CertainMonths, DaysOfWeek, Monthwise, NDays, NWeeks, Once, ScheduleKind, WeeksOfMonth};
+use crate::schedule::{CertainMonths, DaysOfWeek, Monthwise, NDays, NWeeks, Once, ScheduleKind, SkipWeekends, WeeksOfMonth};
 use crate::tasks::DemoTask;
 
+#[derive(Parser, Debug)]
+#[command(name = "seed")]
+#[command(about = "Seed the chores database with tasks from a TOML or JSON file")]
+struct Args {
+    /// Seed file to read
+    #[arg(long, default_value = "seed.toml")]
+    file: String,
+
+    /// File format (default: from the file extension, falling back to TOML)
+    #[arg(long, value_enum)]
+    format: Option<SeedFormat>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum SeedFormat {
+    Toml,
+    Json,
+}
+
+impl SeedFormat {
+    fn from_path(path: &str) -> Self {
+        if path.to_lowercase().ends_with(".json") {
+            SeedFormat::Json
+        } else {
+            SeedFormat::Toml
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct SeedData {
     tasks: Vec<SeedTask>,
 }
 
+fn parse_seed_data(content: &str, format: SeedFormat) -> Result<SeedData> {
+    Ok(match format {
+        SeedFormat::Toml => toml::from_str(content)?,
+        SeedFormat::Json => serde_json::from_str(content)?,
+    })
+}
+
 #[derive(Debug, Deserialize)]
 struct SeedTask {
     name: String,
     #[serde(default)]
     details: String,
+    /// How-to notes shown when completing, not on cards
+    #[serde(default)]
+    instructions: String,
     schedule_type: String,
     
     // NDays fields
     #[serde(default)]
     n_days: Option<i32>,
+    /// "calendar" or "weekdays"; see `SkipWeekends`
+    #[serde(default)]
+    skip_weekends: Option<String>,
     
     // NWeeks fields
     #[serde(default)]
@@ -65,6 +115,30 @@ struct SeedTask {
     // Whether the task needs to be marked as complete (default: true)
     #[serde(default = "default_completeable")]
     completeable: bool,
+
+    // Label for the completion button (default: "Complete")
+    #[serde(default)]
+    complete_label: String,
+
+    // Minutes after the due time before the task counts as overdue (default: 0)
+    #[serde(default)]
+    grace_minutes: i64,
+
+    // Reminder channels, e.g. "webhook" or "none" (default: the global NOTIFY_CHANNELS)
+    #[serde(default)]
+    notify_channels: String,
+
+    // Completions needed per occurrence; more than 1 makes a counter (default: 1)
+    #[serde(default)]
+    target_count: Option<i64>,
+
+    // Days before due to list the task under Planning on the dashboard (default: 0, never)
+    #[serde(default)]
+    show_ahead_days: i64,
+
+    // Due any time on the day rather than at a set time (default: false)
+    #[serde(default)]
+    all_day: bool,
 }
 
 fn default_completeable() -> bool {
@@ -89,6 +163,7 @@ impl SeedTask {
         let n_days = NDays {
             days: self.n_days.unwrap_or(1),
             time,
+            skip_weekends: SkipWeekends::parse(self.skip_weekends.as_deref().unwrap_or_default()),
         };
         
         let days_of_week = self.parse_days_of_week(time);
@@ -128,6 +203,14 @@ impl SeedTask {
             once: Once { datetime: chrono::Utc::now() },
             alerting_time: self.alerting_time.unwrap_or(1440), // Default 24 hours
             completeable: self.completeable,
+            complete_label: self.complete_label.clone(),
+            grace_minutes: self.grace_minutes,
+            notify_channels: self.notify_channels.clone(),
+            target_count: self.target_count.unwrap_or(1).max(1),
+            instructions: self.instructions.clone(),
+            show_ahead_days: self.show_ahead_days.max(0),
+            all_day: self.all_day,
+            snoozed_until: None,
             created_at: None,
             deleted_at: None,
         }
@@ -169,6 +252,7 @@ fn get_config(
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args = Args::parse();
     println!("🌱 Seeding database...");
     
     let dotenv: HashMap<String, String> = dotenvy::dotenv_iter()
@@ -196,10 +280,11 @@ async fn main() -> Result<()> {
     }
     
     // Read seed file
-    let seed_content = fs::read_to_string("seed.toml")?;
-    let seed_data: SeedData = toml::from_str(&seed_content)?;
+    let format = args.format.unwrap_or_else(|| SeedFormat::from_path(&args.file));
+    let seed_content = fs::read_to_string(&args.file)?;
+    let seed_data = parse_seed_data(&seed_content, format)?;
     
-    println!("📋 Found {} tasks to seed", seed_data.tasks.len());
+    println!("📋 Found {} tasks to seed from {}", seed_data.tasks.len(), args.file);
     
     // Insert each task
     for seed_task in seed_data.tasks {
@@ -215,3 +300,41 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_format_from_extension() {
+        assert_eq!(SeedFormat::from_path("seed.toml"), SeedFormat::Toml);
+        assert_eq!(SeedFormat::from_path("chores.JSON"), SeedFormat::Json);
+        assert_eq!(SeedFormat::from_path("seed"), SeedFormat::Toml);
+    }
+
+    #[test]
+    fn test_json_and_toml_seed_agree() {
+        let toml_data = parse_seed_data(
+            r#"
+            [[tasks]]
+            name = "Water Plants"
+            schedule_type = "n_days"
+            n_days = 3
+            time = "09:00"
+            "#,
+            SeedFormat::Toml,
+        )
+        .unwrap();
+        let json_data = parse_seed_data(
+            r#"{"tasks": [{"name": "Water Plants", "schedule_type": "n_days", "n_days": 3, "time": "09:00"}]}"#,
+            SeedFormat::Json,
+        )
+        .unwrap();
+
+        let from_toml = toml_data.tasks[0].to_demo_task();
+        let from_json = json_data.tasks[0].to_demo_task();
+        assert_eq!(from_toml.name, from_json.name);
+        assert_eq!(from_toml.n_days.days, from_json.n_days.days);
+        assert_eq!(from_toml.n_days.time, from_json.n_days.time);
+        assert!(from_json.completeable);
+    }
+}
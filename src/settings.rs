@@ -7,7 +7,9 @@ use chrono::Timelike;
 use hypertext::{prelude::*, Raw};
 use serde::{Deserialize, Serialize};
 
+use crate::config::{self, TimeFormat};
 use crate::db::{self, DbPool};
+use crate::pages::{self, NavItem};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Settings {
@@ -21,6 +23,21 @@ pub struct Settings {
     pub night_tags: String,
     #[serde(default)]
     pub touch_mode: bool,
+    /// Show due and upcoming tasks as one chronological list on the dashboard
+    #[serde(default)]
+    pub combined_due: bool,
+    /// Refresh the dashboard when tasks change on another device
+    #[serde(default)]
+    pub live_updates: bool,
+    /// Play a short animation after completing a task
+    #[serde(default)]
+    pub celebrate: bool,
+    /// Also play a sound with the completion animation
+    #[serde(default)]
+    pub celebrate_sound: bool,
+    /// List the next few occurrences on each dashboard card
+    #[serde(default)]
+    pub expanded_cards: bool,
 }
 
 fn parse_tag_str(s: &str) -> Vec<String> {
@@ -47,10 +64,16 @@ pub fn is_touch_mode(headers: &HeaderMap) -> bool {
     read_settings(headers).touch_mode
 }
 
+/// This device's settings from its cookie. A device that hasn't saved any
+/// gets the defaults, with touch mode following the app-wide setting.
 pub fn read_settings(headers: &HeaderMap) -> Settings {
+    let defaults = || Settings {
+        touch_mode: config::is_touch_mode(),
+        ..Settings::default()
+    };
     let cookie_header = match headers.get(header::COOKIE) {
         Some(h) => h.to_str().unwrap_or(""),
-        None => return Settings::default(),
+        None => return defaults(),
     };
 
     for part in cookie_header.split(';') {
@@ -64,7 +87,7 @@ pub fn read_settings(headers: &HeaderMap) -> Settings {
         }
     }
 
-    Settings::default()
+    defaults()
 }
 
 fn set_cookie_header(settings: &Settings) -> String {
@@ -81,6 +104,11 @@ pub struct SettingsForm {
     evening_tags: Option<String>,
     night_tags: Option<String>,
     touch_mode: Option<String>,
+    combined_due: Option<String>,
+    live_updates: Option<String>,
+    celebrate: Option<String>,
+    celebrate_sound: Option<String>,
+    expanded_cards: Option<String>,
 }
 
 pub async fn settings_page(State(pool): State<DbPool>, headers: HeaderMap) -> Html<String> {
@@ -100,6 +128,11 @@ pub async fn save_settings(
     let evening_tags = form.evening_tags.unwrap_or_default();
     let night_tags = form.night_tags.unwrap_or_default();
     let touch_mode = form.touch_mode.is_some();
+    let combined_due = form.combined_due.is_some();
+    let live_updates = form.live_updates.is_some();
+    let celebrate = form.celebrate.is_some();
+    let celebrate_sound = form.celebrate_sound.is_some();
+    let expanded_cards = form.expanded_cards.is_some();
 
     let current_settings = read_settings(&headers);
     let people = db::get_all_people(&pool).await.unwrap_or_default();
@@ -117,6 +150,11 @@ pub async fn save_settings(
                     evening_tags,
                     night_tags,
                     touch_mode,
+                    combined_due,
+                    live_updates,
+                    celebrate,
+                    celebrate_sound,
+                    expanded_cards,
                 };
                 return render_settings_page(
                     &error_settings,
@@ -141,6 +179,11 @@ pub async fn save_settings(
                     evening_tags,
                     night_tags,
                     touch_mode,
+                    combined_due,
+                    live_updates,
+                    celebrate,
+                    celebrate_sound,
+                    expanded_cards,
                 };
                 return render_settings_page(
                     &error_settings,
@@ -159,6 +202,11 @@ pub async fn save_settings(
         evening_tags,
         night_tags,
         touch_mode,
+        combined_due,
+        live_updates,
+        celebrate,
+        celebrate_sound,
+        expanded_cards,
     };
 
     let cookie = set_cookie_header(&new_settings);
@@ -215,6 +263,11 @@ fn render_settings_page(settings: &Settings, people: &[db::Person], error: Optio
                         }
                     }
                     h1 { "Settings" }
+                    p .form-help {
+                        "These settings apply to this device only. "
+                        a href="/settings/app" { "App settings" }
+                        " apply everywhere."
+                    }
 
                     @if let Some(err) = error {
                         div .error-message {
@@ -292,6 +345,56 @@ fn render_settings_page(settings: &Settings, people: &[db::Person], error: Optio
                                 label for="touch_mode" { "Touch mode" }
                                 p .form-help { "Use larger buttons instead of links for touchscreen devices." }
                             }
+
+                            div .form-group .form-group-checkbox {
+                                input
+                                    type="checkbox"
+                                    id="combined_due"
+                                    name="combined_due"
+                                    checked[settings.combined_due];
+                                label for="combined_due" { "Combine due and upcoming" }
+                                p .form-help { "Show due and upcoming tasks as one list ordered by due time, instead of separate sections." }
+                            }
+
+                            div .form-group .form-group-checkbox {
+                                input
+                                    type="checkbox"
+                                    id="live_updates"
+                                    name="live_updates"
+                                    checked[settings.live_updates];
+                                label for="live_updates" { "Live updates" }
+                                p .form-help { "Refresh the dashboard on this device when someone completes or changes a task elsewhere." }
+                            }
+
+                            div .form-group .form-group-checkbox {
+                                input
+                                    type="checkbox"
+                                    id="celebrate"
+                                    name="celebrate"
+                                    checked[settings.celebrate];
+                                label for="celebrate" { "Celebrate completions" }
+                                p .form-help { "Flash a short animation when a task is marked complete, so you know the tap registered." }
+                            }
+
+                            div .form-group .form-group-checkbox {
+                                input
+                                    type="checkbox"
+                                    id="celebrate_sound"
+                                    name="celebrate_sound"
+                                    checked[settings.celebrate_sound];
+                                label for="celebrate_sound" { "Completion sound" }
+                                p .form-help { "Also play a short chime. Only used when Celebrate completions is on." }
+                            }
+
+                            div .form-group .form-group-checkbox {
+                                input
+                                    type="checkbox"
+                                    id="expanded_cards"
+                                    name="expanded_cards"
+                                    checked[settings.expanded_cards];
+                                label for="expanded_cards" { "Show upcoming times on cards" }
+                                p .form-help { "List each task's next few due times under its due line, e.g. \"Tue 09:00, Thu 09:00\"." }
+                            }
                         }
 
                         div .form-actions {
@@ -329,6 +432,188 @@ fn render_settings_page(settings: &Settings, people: &[db::Person], error: Optio
     Html(html.render().into_inner())
 }
 
+// ============================================================================
+// App Settings
+// ============================================================================
+
+#[derive(Deserialize)]
+pub struct AppSettingsForm {
+    timezone: String,
+    time_format: String,
+    touch_mode: Option<String>,
+    show_week_numbers: Option<String>,
+}
+
+// GET /settings/app - Settings shared by every device, editable without a restart
+pub async fn app_settings_page(State(pool): State<DbPool>, headers: HeaderMap) -> Html<String> {
+    let timezone = config::get_timezone().name().to_string();
+    let shares = share_links(&pool).await;
+    Html(render_app_settings_page(&headers, &timezone, None, false, shares.as_deref()))
+}
+
+/// Share links for the settings page, or None when they're off because
+/// APP_PASSWORD isn't set (like the guest board, they'd leave the app open)
+async fn share_links(pool: &DbPool) -> Option<Vec<db::ShareToken>> {
+    config::get_app_password()?;
+    Some(db::get_share_tokens(pool).await.unwrap_or_default())
+}
+
+// POST /settings/app - Check every value, then store and apply them together
+pub async fn save_app_settings(
+    State(pool): State<DbPool>,
+    headers: HeaderMap,
+    Form(form): Form<AppSettingsForm>,
+) -> Response {
+    let shares = share_links(&pool).await;
+    let timezone = match config::normalize_timezone(&form.timezone) {
+        Ok(tz) => tz.name().to_string(),
+        Err(e) => {
+            let page = render_app_settings_page(&headers, &form.timezone, Some(&e), false, shares.as_deref());
+            return (StatusCode::UNPROCESSABLE_ENTITY, Html(page)).into_response();
+        }
+    };
+    let Some(time_format) = TimeFormat::parse(&form.time_format) else {
+        let page = render_app_settings_page(&headers, &timezone, Some("Choose a time format."), false, shares.as_deref());
+        return (StatusCode::UNPROCESSABLE_ENTITY, Html(page)).into_response();
+    };
+
+    let values = [
+        ("timezone", timezone),
+        ("time_format", time_format.name().to_string()),
+        ("touch_mode", form.touch_mode.is_some().to_string()),
+        ("show_week_numbers", form.show_week_numbers.is_some().to_string()),
+    ];
+    if let Err(e) = db::set_app_settings(&pool, &values).await {
+        tracing::error!(error = %e, "Error saving app settings");
+        let page = render_app_settings_page(&headers, &values[0].1, Some("Couldn't save the settings. Please try again."), false, shares.as_deref());
+        return (StatusCode::INTERNAL_SERVER_ERROR, Html(page)).into_response();
+    }
+    // Only once they're stored, so a failed save changes nothing. Already
+    // validated above, so this can't fail.
+    for (key, value) in &values {
+        let _ = config::apply_app_setting(key, value);
+    }
+    tracing::info!("App settings updated");
+
+    Html(render_app_settings_page(&headers, &values[0].1, None, true, shares.as_deref())).into_response()
+}
+
+fn render_app_settings_page(
+    headers: &HeaderMap,
+    timezone: &str,
+    error: Option<&str>,
+    saved: bool,
+    shares: Option<&[db::ShareToken]>,
+) -> String {
+    let is_touch = is_touch_mode(headers);
+    let active_shares: Vec<&db::ShareToken> = shares
+        .unwrap_or_default()
+        .iter()
+        .filter(|share| share.revoked_at.is_none())
+        .collect();
+    let time_format = config::get_time_format();
+    let touch_mode = config::is_touch_mode();
+    let week_numbers = config::show_week_numbers();
+
+    let body = maud! {
+        div .settings-page {
+            div .settings-page-header {
+                a href="/settings" { "← Device settings" }
+            }
+            h1 { "App Settings" }
+            p .form-help { "These apply to every device and take effect right away, without a restart. They override TZ, TIME_FORMAT, TOUCH and SHOW_WEEK_NUMBERS once saved." }
+
+            @if let Some(err) = error {
+                div .error-message { (err) }
+            }
+            @if saved {
+                div .settings-saved { "Saved." }
+            }
+
+            form method="post" action="/settings/app" {
+                fieldset {
+                    legend { "Time" }
+
+                    div .form-group {
+                        label for="timezone" { "Timezone" }
+                        input type="text" id="timezone" name="timezone" value=(timezone) placeholder="America/Chicago";
+                        p .form-help { "An IANA name such as Europe/London. Due times and \"today\" follow it." }
+                    }
+
+                    div .form-group {
+                        label for="time_format" { "Time format" }
+                        select id="time_format" name="time_format" {
+                            @for format in [TimeFormat::Minutes, TimeFormat::Seconds] {
+                                option value=(format.name()) selected[format == time_format] { (format.name()) }
+                            }
+                        }
+                    }
+                }
+
+                fieldset {
+                    legend { "Display" }
+
+                    div .form-group .form-group-checkbox {
+                        input type="checkbox" id="touch_mode" name="touch_mode" checked[touch_mode];
+                        label for="touch_mode" { "Touch mode by default" }
+                        p .form-help { "Devices that haven't saved their own settings use large buttons." }
+                    }
+
+                    div .form-group .form-group-checkbox {
+                        input type="checkbox" id="show_week_numbers" name="show_week_numbers" checked[week_numbers];
+                        label for="show_week_numbers" { "Week numbers" }
+                        p .form-help { "Start each row of a task's calendar with its ISO week number." }
+                    }
+                }
+
+                div .form-actions {
+                    button .btn type="submit" { "Save" }
+                }
+            }
+
+            fieldset {
+                legend { "Share links" }
+                p .form-help { "Read-only links to the board for people without the app password. Anyone with a link sees every task until it's revoked." }
+
+                @if shares.is_none() {
+                    p .form-help { "Set APP_PASSWORD to use share links, so the rest of the app isn't open to whoever has one." }
+                } @else {
+                    @if active_shares.is_empty() {
+                        p .form-help { "No share links yet." }
+                    } @else {
+                        ul .people-list {
+                            @for share in &active_shares {
+                                li .person-item .share-item {
+                                    span .share-label {
+                                        @if share.label.is_empty() { "Unlabelled" } @else { (share.label) }
+                                    }
+                                    input .share-url type="text" readonly value=(format!("/share/{}", share.token));
+                                    button .btn
+                                        type="button"
+                                        onclick=(format!("navigator.clipboard.writeText(new URL('/share/{}', location.href).href).then(() => this.textContent = 'Copied')", share.token))
+                                    { "Copy" }
+                                    form method="post" action=(format!("/settings/share/{}/revoke", share.id)) {
+                                        button .btn .person-delete type="submit" { "Revoke" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    form .people-add-form method="post" action="/settings/share" {
+                        input type="text" name="label" placeholder="Label, e.g. Grandma";
+                        button .btn type="submit" { "Create link" }
+                    }
+                }
+            }
+        }
+    }
+    .render()
+    .into_inner();
+
+    pages::render_page("App Settings", &body, is_touch, NavItem::Other)
+}
+
 // ============================================================================
 // People Management
 // ============================================================================
@@ -366,3 +651,36 @@ pub async fn delete_person(
         .unwrap()
         .into_response()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn share(id: i64, label: &str, revoked: bool) -> db::ShareToken {
+        db::ShareToken {
+            id,
+            token: format!("token{}", id),
+            label: label.to_string(),
+            created_at: "2026-03-10T09:00:00+00:00".to_string(),
+            revoked_at: revoked.then(|| "2026-03-11T09:00:00+00:00".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_app_settings_page_lists_active_share_links() {
+        let shares = [share(1, "Grandma", false), share(2, "", false), share(3, "Old", true)];
+        let page = render_app_settings_page(&HeaderMap::new(), "UTC", None, false, Some(&shares));
+        assert!(page.contains(r#"value="/share/token1""#), "{}", page);
+        assert!(page.contains("Grandma"));
+        assert!(page.contains("Unlabelled"));
+        assert!(page.contains("new URL('/share/token2', location.href)"));
+        assert!(page.contains(r#"action="/settings/share/1/revoke""#));
+        assert!(!page.contains("token3"));
+        assert!(page.contains(r#"action="/settings/share""#));
+
+        // Without APP_PASSWORD there's nothing to create or copy
+        let page = render_app_settings_page(&HeaderMap::new(), "UTC", None, false, None);
+        assert!(page.contains("Set APP_PASSWORD to use share links"));
+        assert!(!page.contains(r#"action="/settings/share""#));
+    }
+}
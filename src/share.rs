@@ -0,0 +1,128 @@
+use axum::{
+    extract::{Form, Path, State},
+    http::HeaderMap,
+    response::{Html, Redirect},
+};
+use serde::Deserialize;
+use rand::distr::{Alphanumeric, SampleString};
+
+use crate::config;
+use crate::db::{self, DbPool};
+use crate::error::AppError;
+use crate::tasks::{self, View};
+
+/// Length of a generated share token; long enough that guessing one is hopeless
+const TOKEN_LENGTH: usize = 32;
+
+/// A fresh random token for a share link
+pub fn generate_token() -> String {
+    Alphanumeric.sample_string(&mut rand::rng(), TOKEN_LENGTH)
+}
+
+/// Unknown and revoked tokens get the same plain 404, so a dead link doesn't
+/// reveal that it ever worked.
+async fn check_token(pool: &DbPool, token: &str) -> Result<(), AppError> {
+    if db::is_share_token_active(pool, token).await? {
+        Ok(())
+    } else {
+        Err(AppError::NotFound("There's nothing at this address.".to_string()))
+    }
+}
+
+/// Load a task a shared link may show: a real one that isn't in the trash
+async fn shared_task(pool: &DbPool, id: &str) -> Result<tasks::DemoTask, AppError> {
+    let not_found = || AppError::NotFound(format!("Task '{}' doesn't exist.", id));
+    let task_id = id.parse::<i64>().map_err(|_| not_found())?;
+    db::get_task(pool, task_id)
+        .await?
+        .filter(|task| !task.is_deleted())
+        .ok_or_else(not_found)
+}
+
+// GET /share/:token - The dashboard, read-only
+pub async fn board(
+    State(pool): State<DbPool>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<Html<String>, AppError> {
+    check_token(&pool, &token).await?;
+    Ok(Html(tasks::render_homepage(&pool, &headers, View::Shared { token: &token }).await))
+}
+
+// GET /share/:token/tasks/:id - A task's page without any controls
+pub async fn task_show(
+    State(pool): State<DbPool>,
+    Path((token, id)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Result<Html<String>, AppError> {
+    check_token(&pool, &token).await?;
+    let task = shared_task(&pool, &id).await?;
+    let completions = db::get_all_completions(&pool, &id).await?;
+    let is_touch = crate::settings::is_touch_mode(&headers);
+    Ok(Html(tasks::render_task_show_page(&task, &completions, &[], is_touch, View::Shared { token: &token })))
+}
+
+#[derive(Deserialize)]
+pub struct NewShareLinkForm {
+    #[serde(default)]
+    label: String,
+}
+
+// POST /settings/share - Make a new share link from the app settings page
+pub async fn create_link(State(pool): State<DbPool>, Form(form): Form<NewShareLinkForm>) -> Result<Redirect, AppError> {
+    if config::get_app_password().is_none() {
+        return Err(AppError::Validation("Share links need APP_PASSWORD to be set.".to_string()));
+    }
+    let share = db::create_share_token(&pool, &generate_token(), form.label.trim()).await?;
+    tracing::info!(share_id = share.id, "Share link created");
+    Ok(Redirect::to("/settings/app"))
+}
+
+// POST /settings/share/:id/revoke - Revoke a share link; its URL 404s from then on
+pub async fn revoke_link(State(pool): State<DbPool>, Path(id): Path<i64>) -> Result<Redirect, AppError> {
+    if !db::revoke_share_token(&pool, id).await? {
+        return Err(AppError::NotFound(format!("No active share link with id {}.", id)));
+    }
+    tracing::info!(share_id = id, "Share link revoked");
+    Ok(Redirect::to("/settings/app"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::test_pool;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    async fn post_to(pool: &DbPool, uri: &str, body: &str) -> StatusCode {
+        let app = Router::new()
+            .route("/settings/share", post(create_link))
+            .route("/settings/share/{id}/revoke", post(revoke_link))
+            .with_state(pool.clone());
+        let request = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        app.oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_settings_revoke_and_refuse_without_app_password() {
+        let pool = test_pool("share_settings").await;
+
+        // Tests run without APP_PASSWORD, so no link can be made
+        assert_eq!(post_to(&pool, "/settings/share", "label=Grandma").await, StatusCode::BAD_REQUEST);
+        assert!(db::get_share_tokens(&pool).await.unwrap().is_empty());
+
+        let share = db::create_share_token(&pool, &generate_token(), "Grandma").await.unwrap();
+        let revoke = format!("/settings/share/{}/revoke", share.id);
+        assert_eq!(post_to(&pool, &revoke, "").await, StatusCode::SEE_OTHER);
+        assert!(!db::is_share_token_active(&pool, &share.token).await.unwrap());
+        assert_eq!(post_to(&pool, &revoke, "").await, StatusCode::NOT_FOUND);
+    }
+}
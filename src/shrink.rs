@@ -6,9 +6,14 @@
 //! Deletes all photo_blobs entries for photos that are not missing (exist on disk),
 //! then VACUUMs the database to reclaim space.
 
+mod clock;
 mod config;
 mod db;
+mod engine;
+mod error;
+mod events;
 mod migrate;
+mod pages;
 mod schedule;
 mod settings;
 mod tasks;
@@ -1,27 +1,37 @@
 use axum::{
     extract::{Path, Query, State},
     http::HeaderMap,
-    response::Html,
+    response::{Html, IntoResponse, Redirect, Response},
     routing::{get, post},
     Form, Router,
 };
-use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use hypertext::{prelude::*, Raw};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex, OnceLock};
 use tracing::{error, info};
 
-use crate::config::get_timezone;
+use crate::config::{
+    get_archive_after_days, get_day_start_hour, get_default_schedule_kind, get_home_route, get_notify_channels, get_time_format, get_timezone, parse_notify_channels, HomeRoute, NotifyChannel,
+    TimeFormat,
+};
+use crate::clock;
 use crate::settings;
+use crate::error::AppError;
 use crate::db::{self, DbPool};
-use crate::schedule::{CertainMonths, DaysOfWeek, Monthwise, NDays, NWeeks, Once, ScheduleKind, WeeksOfMonth};
+use crate::engine;
+use crate::events;
+use crate::pages;
+use crate::schedule::{CertainMonths, DaysOfWeek, Monthwise, NDays, NWeeks, Once, Schedule, ScheduleKind, SkipWeekends, WeeksOfMonth};
 
 // ============================================================================
 // Day Range Parsing and Formatting
 // ============================================================================
 
 /// Parse a day range string like "1, 4-7, 10, 15-17" into a sorted, deduplicated list of days.
+/// "20-" means the 20th through the end of the month and is kept as the marker -20
+/// (see `day_in_range`), since month lengths vary; "-5" is shorthand for "1-5".
 /// Returns Ok(days) on success, or Err(message) on parse error.
 pub fn parse_day_range(input: &str) -> Result<Vec<i32>, String> {
     let input = input.trim();
@@ -44,10 +54,27 @@ pub fn parse_day_range(input: &str) -> Result<Vec<i32>, String> {
                 return Err(format!("Invalid range format: '{}'", part));
             }
 
-            let start: i32 = parts[0].trim().parse()
-                .map_err(|_| format!("Invalid number: '{}'", parts[0].trim()))?;
-            let end: i32 = parts[1].trim().parse()
-                .map_err(|_| format!("Invalid number: '{}'", parts[1].trim()))?;
+            let (start_str, end_str) = (parts[0].trim(), parts[1].trim());
+            if start_str.is_empty() && end_str.is_empty() {
+                return Err(format!("Invalid range format: '{}'", part));
+            }
+            let start: i32 = if start_str.is_empty() {
+                1
+            } else {
+                start_str.parse().map_err(|_| format!("Invalid number: '{}'", start_str))?
+            };
+
+            // Open-ended: through the end of whichever month it is
+            if end_str.is_empty() {
+                if !(1..=31).contains(&start) {
+                    return Err(format!("Day {} is out of range (1-31)", start));
+                }
+                days.push(-start);
+                continue;
+            }
+
+            let end: i32 = end_str.parse()
+                .map_err(|_| format!("Invalid number: '{}'", end_str))?;
 
             if start > end {
                 return Err(format!("Range start must be <= end: '{}'", part));
@@ -75,24 +102,45 @@ pub fn parse_day_range(input: &str) -> Result<Vec<i32>, String> {
         return Err("Please enter at least one day".to_string());
     }
 
-    // Sort and deduplicate
-    days.sort();
-    days.dedup();
+    Ok(normalize_days(days))
+}
+
+/// Sort and deduplicate days. An open-ended range swallows the days it covers
+/// and any run of days leading straight into it, so "15-19, 20-" becomes "15-".
+fn normalize_days(days: Vec<i32>) -> Vec<i32> {
+    let open_from = days.iter().filter(|&&d| d < 0).map(|d| -d).min();
+    let mut fixed: Vec<i32> = days.into_iter().filter(|&d| d > 0).collect();
+    fixed.sort();
+    fixed.dedup();
+
+    let Some(mut open_from) = open_from else {
+        return fixed;
+    };
+    fixed.retain(|&d| d < open_from);
+    while fixed.last() == Some(&(open_from - 1)) {
+        fixed.pop();
+        open_from -= 1;
+    }
+    fixed.push(-open_from);
+    fixed
+}
 
-    Ok(days)
+/// Whether a day of the month is in a list from `parse_day_range`, resolving
+/// an open-ended "N-" marker against whatever month the day is in
+pub fn day_in_range(days: &[i32], day: i32) -> bool {
+    days.iter().any(|&d| d == day || (d < 0 && day >= -d))
 }
 
 /// Format a list of days into the simplest range format.
 /// e.g., [1, 2, 4, 5, 6, 7, 10, 15, 16, 17] -> "1-2, 4-7, 10, 15-17"
+/// An open-ended marker comes last: [1, -20] -> "1, 20-"
 pub fn format_day_range(days: &[i32]) -> String {
-    if days.is_empty() {
-        return String::new();
+    let mut sorted_days = normalize_days(days.to_vec());
+    let open_from = sorted_days.pop_if(|d| *d < 0).map(|d| -d);
+    if sorted_days.is_empty() {
+        return open_from.map(|d| format!("{}-", d)).unwrap_or_default();
     }
 
-    let mut sorted_days = days.to_vec();
-    sorted_days.sort();
-    sorted_days.dedup();
-
     let mut ranges: Vec<String> = Vec::new();
     let mut range_start = sorted_days[0];
     let mut range_end = sorted_days[0];
@@ -119,10 +167,28 @@ pub fn format_day_range(days: &[i32]) -> String {
     } else {
         ranges.push(format!("{}-{}", range_start, range_end));
     }
+    if let Some(open_from) = open_from {
+        ranges.push(format!("{}-", open_from));
+    }
 
     ranges.join(", ")
 }
 
+/// Inline feedback shown under a day-range field while the user types:
+/// the normalized form of what they entered, or why it doesn't parse.
+fn render_day_range_preview(input: &str) -> String {
+    match parse_day_range(input) {
+        Ok(days) => format!(
+            r#"<span class="day-range-preview-ok">→ {}</span>"#,
+            format_day_range(&days)
+        ),
+        Err(msg) => format!(
+            r#"<span class="field-error-message day-range-preview-error">{}</span>"#,
+            html_escape(&msg)
+        ),
+    }
+}
+
 // ============================================================================
 // Form Validation
 // ============================================================================
@@ -130,17 +196,39 @@ pub fn format_day_range(days: &[i32]) -> String {
 /// Holds validation errors for the task form
 #[derive(Default, Clone)]
 pub struct FormErrors {
+    pub name: Option<String>,
     pub monthwise_days: Option<String>,
     pub certain_months_days: Option<String>,
+    pub complete_label: Option<String>,
+    pub active_until: Option<String>,
     pub general: Option<String>,
+    /// Soft warning: shown with the form but doesn't block saving (see `has_errors`)
+    pub duplicate_warning: Option<String>,
 }
 
 impl FormErrors {
     pub fn has_errors(&self) -> bool {
-        self.monthwise_days.is_some() || self.certain_months_days.is_some() || self.general.is_some()
+        self.name.is_some()
+            || self.monthwise_days.is_some()
+            || self.certain_months_days.is_some()
+            || self.complete_label.is_some()
+            || self.active_until.is_some()
+            || self.general.is_some()
     }
 }
 
+/// Longest allowed task name
+const MAX_NAME_LEN: usize = 100;
+
+/// Longest allowed custom completion button label
+const MAX_COMPLETE_LABEL_LEN: usize = 30;
+
+/// Most completions a counter task can ask for per occurrence
+const MAX_TARGET_COUNT: i64 = 100;
+
+/// Furthest ahead a task can be listed under Planning
+const MAX_SHOW_AHEAD_DAYS: i64 = 365;
+
 // Shared state for demo tasks (in-memory)
 pub type DemoTasksMap = Arc<Mutex<HashMap<String, DemoTask>>>;
 pub static DEMO_TASKS: OnceLock<DemoTasksMap> = OnceLock::new();
@@ -160,6 +248,7 @@ pub fn get_demo_tasks() -> &'static DemoTasksMap {
                 n_days: NDays {
                     days: 3,
                     time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                    skip_weekends: SkipWeekends::Off,
                 },
                 n_weeks: default_n_weeks(),
                 monthwise: default_monthwise(),
@@ -168,6 +257,14 @@ pub fn get_demo_tasks() -> &'static DemoTasksMap {
                 once: default_once(),
                 alerting_time: 1440, // 24 hours
                 completeable: true,
+                complete_label: String::new(),
+                grace_minutes: 0,
+                notify_channels: String::new(),
+                target_count: 1,
+                instructions: String::new(),
+                show_ahead_days: 0,
+                all_day: false,
+                snoozed_until: None,
                 created_at: None,
                 deleted_at: None,
             },
@@ -201,6 +298,14 @@ pub fn get_demo_tasks() -> &'static DemoTasksMap {
                 once: default_once(),
                 alerting_time: 720, // 12 hours
                 completeable: true,
+                complete_label: String::new(),
+                grace_minutes: 0,
+                notify_channels: String::new(),
+                target_count: 1,
+                instructions: String::new(),
+                show_ahead_days: 0,
+                all_day: false,
+                snoozed_until: None,
                 created_at: None,
                 deleted_at: None,
             },
@@ -224,6 +329,14 @@ pub fn get_demo_tasks() -> &'static DemoTasksMap {
                 once: default_once(),
                 alerting_time: 4320, // 3 days (72 hours)
                 completeable: true,
+                complete_label: String::new(),
+                grace_minutes: 0,
+                notify_channels: String::new(),
+                target_count: 1,
+                instructions: String::new(),
+                show_ahead_days: 0,
+                all_day: false,
+                snoozed_until: None,
                 created_at: None,
                 deleted_at: None,
             },
@@ -256,6 +369,14 @@ pub fn get_demo_tasks() -> &'static DemoTasksMap {
                 once: default_once(),
                 alerting_time: 60, // 1 hour
                 completeable: true,
+                complete_label: String::new(),
+                grace_minutes: 0,
+                notify_channels: String::new(),
+                target_count: 1,
+                instructions: String::new(),
+                show_ahead_days: 0,
+                all_day: false,
+                snoozed_until: None,
                 created_at: None,
                 deleted_at: None,
             },
@@ -276,32 +397,197 @@ pub fn router() -> Router<DbPool> {
         .route("/list", get(tasks_list))
         .route("/new", get(new_task_modal).post(create_task))
         .route("/new/schedule-type", post(new_task_schedule_type))
+        .route("/examples", post(load_example_tasks))
+        .route("/parse-days", post(parse_days_preview))
+        .route("/alerting/batch", post(batch_alerting_time))
+        .route("/templates", get(templates_index))
+        .route("/templates/{template_id}/delete", post(delete_template))
         .route("/{id}/edit", get(task_edit))
         .route("/{id}/edit-modal", get(task_edit_modal))
         .route("/{id}", get(task_show).post(save_task))
         .route("/{id}/schedule-type", post(change_schedule_type))
         .route("/{id}/complete-picker", get(complete_picker))
         .route("/{id}/complete", post(complete_task))
+        .route("/{id}/complete-on", post(complete_on_date))
+        .route("/{id}/not-today", post(snooze_task))
+        .route("/{id}/reschedule", post(reschedule_task))
         .route("/{id}/delete", post(delete_task))
         .route("/{id}/restore", post(restore_task))
+        .route("/{id}/revert/{entry_id}", post(revert_schedule))
+        .route("/{id}/save-template", post(save_as_template))
         .route("/{id}/completions/{completion_id}", axum::routing::delete(delete_completion))
+        .route("/{id}/completions/{completion_id}/undo", post(undo_completion))
+}
+
+/// Number of audit entries shown on a task's page
+const AUDIT_LOG_LIMIT: i64 = 20;
+
+/// Write an entry to the task's audit log. Failures are logged, never surfaced to the user.
+/// Every task change and completion comes through here, so it also tells live dashboards to refresh.
+pub async fn record_audit(pool: &DbPool, task_id: &str, action: &str, summary: &str) {
+    if let Err(e) = db::add_audit_entry(pool, task_id, action, summary).await {
+        error!(task_id = %task_id, error = %e, "Error writing audit log");
+    }
+    events::notify_changed();
+}
+
+/// Schedule snapshots kept per task for "Revert to this version"
+const SCHEDULE_SNAPSHOT_LIMIT: i64 = 10;
+
+/// Like `record_audit`, but also stores the task's schedule as it stands after this change
+pub async fn record_audit_with_snapshot(pool: &DbPool, task_id: &str, action: &str, summary: &str, task: &DemoTask) {
+    let snapshot = db::schedule_snapshot(task);
+    if let Err(e) = db::add_audit_entry_with_snapshot(pool, task_id, action, summary, Some(&snapshot), SCHEDULE_SNAPSHOT_LIMIT).await {
+        error!(task_id = %task_id, error = %e, "Error writing audit log");
+    }
+    events::notify_changed();
+}
+
+/// Audit wording for a changed alert time
+fn describe_alerting_change(from: i64, to: i64) -> String {
+    format!("changed alert from {} to {}", format_alerting_time(from), format_alerting_time(to))
+}
+
+/// Describe what changed between the previous and updated versions of a task,
+/// one human-readable phrase per change (e.g. "changed schedule from ... to ...").
+pub fn describe_task_changes(previous: &DemoTask, updated: &DemoTask) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    if previous.name != updated.name {
+        changes.push(format!("renamed from \"{}\" to \"{}\"", previous.name, updated.name));
+    }
+    if previous.details != updated.details {
+        changes.push("edited details".to_string());
+    }
+    if previous.instructions != updated.instructions {
+        changes.push("edited instructions".to_string());
+    }
+
+    let old_schedule = describe_schedule(previous);
+    let new_schedule = describe_schedule(updated);
+    if old_schedule != new_schedule {
+        changes.push(format!("changed schedule from {} to {}", old_schedule, new_schedule));
+    } else if updated.schedule_kind != ScheduleKind::Once && previous.all_day == updated.all_day {
+        let today = clock::now().with_timezone(&get_timezone()).date_naive();
+        let old_time = get_due_time(previous, today);
+        let new_time = get_due_time(updated, today);
+        if old_time != new_time {
+            changes.push(format!(
+                "changed time from {} to {}",
+                old_time.format("%H:%M"),
+                new_time.format("%H:%M")
+            ));
+        }
+    }
+
+    if previous.all_day != updated.all_day {
+        changes.push(if updated.all_day { "made all-day" } else { "set a due time" }.to_string());
+    }
+
+    if previous.alerting_time != updated.alerting_time {
+        changes.push(describe_alerting_change(previous.alerting_time, updated.alerting_time));
+    }
+    if previous.grace_minutes != updated.grace_minutes {
+        changes.push(format!(
+            "changed grace period from {} to {}",
+            format_alerting_time(previous.grace_minutes),
+            format_alerting_time(updated.grace_minutes)
+        ));
+    }
+    if previous.notify_channels != updated.notify_channels {
+        changes.push(format!(
+            "changed reminders from {} to {}",
+            describe_notify_channels(&previous.notify_channels),
+            describe_notify_channels(&updated.notify_channels)
+        ));
+    }
+    if previous.show_ahead_days != updated.show_ahead_days {
+        changes.push(format!(
+            "changed show ahead from {} to {} days",
+            previous.show_ahead_days, updated.show_ahead_days
+        ));
+    }
+    if previous.target_count != updated.target_count {
+        changes.push(format!(
+            "changed times per occurrence from {} to {}",
+            previous.target_count, updated.target_count
+        ));
+    }
+    if previous.deleted_at != updated.deleted_at {
+        match updated.scheduled_end() {
+            Some(end) => changes.push(format!(
+                "set active until {}",
+                end.with_timezone(&get_timezone()).format("%b %-d, %Y")
+            )),
+            None if updated.deleted_at.is_none() => changes.push("cleared end date".to_string()),
+            None => {}
+        }
+    }
+    if previous.complete_label != updated.complete_label {
+        changes.push(format!(
+            "changed button label from \"{}\" to \"{}\"",
+            previous.completion_button_label(),
+            updated.completion_button_label()
+        ));
+    }
+    if previous.completeable != updated.completeable {
+        changes.push(if updated.completeable {
+            "made completeable".to_string()
+        } else {
+            "made not completeable".to_string()
+        });
+    }
+
+    changes
 }
 
 // GET /tasks/:id/complete-picker - Return person picker buttons
 async fn complete_picker(State(pool): State<DbPool>, Path(id): Path<String>) -> Html<String> {
     let people = db::get_all_people(&pool).await.unwrap_or_default();
+    let instructions = match id.parse::<i64>() {
+        Ok(task_id) => db::get_task(&pool, task_id).await.ok().flatten().map(|t| t.instructions).unwrap_or_default(),
+        Err(_) => String::new(),
+    };
+    let duration_id = format!("duration-{}", id);
+    let note_id = format!("note-{}", id);
     let buttons: String = people
         .iter()
         .map(|p| {
             format!(
-                r##"<button class="btn person-picker-btn" hx-post="/tasks/{}/complete?person_id={}" hx-target="#homepage" hx-swap="outerHTML">{}</button>"##,
-                id, p.id, html_escape(&p.initials)
+                r##"<button class="btn person-picker-btn" hx-post="/tasks/{}/complete?person_id={}" hx-include="#{}, #{}" hx-target="#homepage" hx-swap="outerHTML">{}</button>"##,
+                id, p.id, duration_id, note_id, html_escape(&p.initials)
             )
         })
         .collect::<Vec<_>>()
         .join("\n");
 
-    Html(format!(r#"<div class="person-picker">{}</div>"#, buttons))
+    // Optional time spent; left blank, completing is still a single tap
+    let duration_input = format!(
+        r#"<input type="number" class="person-picker-duration" id="{}" name="duration_minutes" min="1" max="{}" placeholder="Minutes (optional)">"#,
+        duration_id, MAX_DURATION_MINUTES
+    );
+
+    // Collapsed by default so the note never gets in the way of a quick tap
+    let note_input = format!(
+        r#"<details class="person-picker-note"><summary>Add a note</summary><input type="text" id="{}" name="note" maxlength="{}" placeholder="Note (optional)"></details>"#,
+        note_id, MAX_NOTE_LEN
+    );
+
+    Html(format!(
+        r#"<div class="person-picker">{}{}{}{}</div>"#,
+        render_picker_instructions(&instructions),
+        duration_input,
+        note_input,
+        buttons
+    ))
+}
+
+/// The task's instructions as a reminder above the completion buttons, if it has any
+fn render_picker_instructions(instructions: &str) -> String {
+    if instructions.is_empty() {
+        return String::new();
+    }
+    format!(r#"<div class="person-picker-instructions">{}</div>"#, html_escape(instructions))
 }
 
 #[derive(Deserialize)]
@@ -309,75 +595,341 @@ pub struct CompleteQuery {
     person_id: Option<i64>,
 }
 
+#[derive(Deserialize, Default)]
+pub struct CompleteForm {
+    #[serde(default)]
+    duration_minutes: Option<String>,
+    #[serde(default)]
+    note: Option<String>,
+    /// "task" to come back to the task's page after a plain POST, rather than the dashboard
+    #[serde(rename = "return", default)]
+    return_to: Option<String>,
+}
+
+/// Longest completion duration accepted (one day)
+const MAX_DURATION_MINUTES: i64 = 1440;
+
+/// Longest completion note kept; anything past this is cut off
+const MAX_NOTE_LEN: usize = 500;
+
+/// Trim the optional note field, dropping it when blank
+fn parse_note(input: Option<&str>) -> Option<String> {
+    input
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.chars().take(MAX_NOTE_LEN).collect())
+}
+
+/// Parse the optional "minutes spent" field. Blank or invalid input records no duration.
+fn parse_duration_minutes(input: Option<&str>) -> Option<i64> {
+    input
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .and_then(|s| s.parse::<i64>().ok())
+        .filter(|m| (1..=MAX_DURATION_MINUTES).contains(m))
+}
+
+/// Format minutes as e.g. "3h 20m", "45m" or "2h"
+fn format_duration_minutes(minutes: i64) -> String {
+    let hours = minutes / 60;
+    let mins = minutes % 60;
+    match (hours, mins) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+/// True when the request came from HTMX rather than a plain form submit or link
+fn is_htmx_request(headers: &HeaderMap) -> bool {
+    headers.get("HX-Request").is_some_and(|v| v == "true")
+}
+
+/// `HX-Trigger` payload sent after a completion is recorded. The dashboard's
+/// celebrate.js listens for `taskCompleted` when the celebrate setting is on.
+fn completion_trigger(task_id: &str) -> String {
+    serde_json::json!({ "taskCompleted": { "taskId": task_id } }).to_string()
+}
+
 // POST /tasks/:id/complete - Mark a task as complete
+// HTMX requests get the re-rendered homepage; plain form posts (no JS) are
+// recorded without a person and redirected back to `/`.
 async fn complete_task(
     State(pool): State<DbPool>,
     Path(id): Path<String>,
     Query(query): Query<CompleteQuery>,
     headers: HeaderMap,
-) -> Html<String> {
-    if let Some(person_id) = query.person_id {
-        match db::add_completion(&pool, &id, person_id).await {
-            Ok(_) => info!(task_id = %id, person_id = person_id, "Task completed"),
+    Form(form): Form<CompleteForm>,
+) -> Response {
+    let is_htmx = is_htmx_request(&headers);
+    let mut completed = false;
+    if query.person_id.is_some() || !is_htmx {
+        let duration_minutes = parse_duration_minutes(form.duration_minutes.as_deref());
+        let note = parse_note(form.note.as_deref());
+        match db::add_completion(&pool, &id, query.person_id, duration_minutes, note.as_deref()).await {
+            Ok(_) => {
+                info!(task_id = %id, person_id = ?query.person_id, "Task completed");
+                let people = db::get_all_people(&pool).await.unwrap_or_default();
+                let summary = match people.iter().find(|p| Some(p.id) == query.person_id) {
+                    Some(person) => format!("completed by {}", person.initials),
+                    None => "completed".to_string(),
+                };
+                record_audit(&pool, &id, "complete", &summary).await;
+                completed = true;
+            }
             Err(e) => error!(task_id = %id, error = %e, "Error adding completion"),
         }
     }
 
-    homepage(State(pool), headers).await
+    if !is_htmx {
+        if form.return_to.as_deref() == Some("task") {
+            return Redirect::to(&format!("/tasks/{}", id)).into_response();
+        }
+        return Redirect::to("/").into_response();
+    }
+    let page = homepage(State(pool), headers).await;
+    if completed {
+        ([("HX-Trigger", completion_trigger(&id))], page).into_response()
+    } else {
+        page.into_response()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CompleteOnForm {
+    date: String,
+}
+
+// POST /tasks/:id/complete-on - Backfill a completion for a past occurrence from the calendar.
+// The completion is stamped at that occurrence's due time; already-covered or
+// future occurrences are left alone.
+async fn complete_on_date(
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    Form(form): Form<CompleteOnForm>,
+) -> Response {
+    if let Ok(task_id) = id.parse::<i64>()
+        && let Ok(Some(task)) = db::get_task(&pool, task_id).await
+        && let Ok(date) = chrono::NaiveDate::parse_from_str(&form.date, "%Y-%m-%d")
+        && is_due_on_date(&task, date)
+    {
+        let tz = get_timezone();
+        let due = tz
+            .from_local_datetime(&date.and_time(get_due_time(&task, date)))
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc));
+        let completions = db::get_all_completions(&pool, &id).await.unwrap_or_default();
+
+        if let Some(due) = due
+            && due <= clock::now()
+            && occurrence_completion(&task, &completions, due).is_none()
+        {
+            match db::add_completion_at(&pool, &id, due, None, None, None).await {
+                Ok(_) => {
+                    info!(task_id = %id, date = %date, "Completion backfilled");
+                    let summary = format!("marked {} as done", date.format("%b %-d, %Y"));
+                    record_audit(&pool, &id, "complete", &summary).await;
+                }
+                Err(e) => error!(task_id = %id, error = %e, "Error backfilling completion"),
+            }
+        }
+    }
+
+    if !is_htmx_request(&headers) {
+        return Redirect::to(&format!("/tasks/{}", id)).into_response();
+    }
+    task_show(State(pool), Path(id), headers).await.into_response()
+}
+
+// POST /tasks/:id/not-today - Hide the current occurrence of a recurring task until it passes.
+// Nothing is recorded in the completion history.
+async fn snooze_task(State(pool): State<DbPool>, Path(id): Path<String>, headers: HeaderMap) -> Response {
+    if let Ok(task_id) = id.parse::<i64>()
+        && let Ok(Some(task)) = db::get_task(&pool, task_id).await
+        && task.schedule_kind != ScheduleKind::Once
+    {
+        let until = task.next_due_date() + Duration::seconds(1);
+        match db::set_task_snoozed_until(&pool, task_id, Some(until)).await {
+            Ok(_) => {
+                info!(task_id = %id, "Task snoozed until next occurrence");
+                record_audit(&pool, &id, "snooze", "skipped this occurrence (not marked done)").await;
+            }
+            Err(e) => error!(task_id = %id, error = %e, "Error snoozing task"),
+        }
+    }
+
+    if !is_htmx_request(&headers) {
+        return Redirect::to("/").into_response();
+    }
+    homepage(State(pool), headers).await.into_response()
+}
+
+#[derive(Deserialize)]
+pub struct RescheduleForm {
+    once_date: String,
+    #[serde(default)]
+    once_time: Option<String>,
+    /// "page" when sent from the task page, which is re-rendered instead of the dashboard
+    #[serde(default)]
+    from: Option<String>,
+}
+
+// POST /tasks/:id/reschedule - Move a Once task to a new date and time without the full editor
+async fn reschedule_task(
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    Form(form): Form<RescheduleForm>,
+) -> Result<Response, AppError> {
+    let not_found = || AppError::NotFound(format!("Task '{}' doesn't exist.", id));
+    let task_id = id.parse::<i64>().map_err(|_| not_found())?;
+    let existing = db::get_task(&pool, task_id).await?.ok_or_else(not_found)?;
+    if existing.schedule_kind != ScheduleKind::Once {
+        return Err(AppError::Validation("Only one-time tasks can be rescheduled.".to_string()));
+    }
+    let datetime = parse_once_datetime(Some(&form.once_date), form.once_time.as_deref())
+        .ok_or_else(|| AppError::Validation("Please enter a valid date and time.".to_string()))?;
+
+    let mut updated = existing.clone();
+    updated.once.datetime = datetime;
+    db::save_task(&pool, &updated).await?;
+    info!(task_id = %id, "Task rescheduled");
+    let changes = describe_task_changes(&existing, &updated);
+    if !changes.is_empty() {
+        record_audit_with_snapshot(&pool, &id, "edit", &format!("rescheduled: {}", changes.join("; ")), &updated).await;
+    }
+
+    let from_page = form.from.as_deref() == Some("page");
+    if !is_htmx_request(&headers) {
+        let back = if from_page { format!("/tasks/{}", id) } else { "/".to_string() };
+        return Ok(Redirect::to(&back).into_response());
+    }
+    if from_page {
+        return Ok(task_show(State(pool), Path(id), headers).await.into_response());
+    }
+    Ok(homepage(State(pool), headers).await.into_response())
+}
+
+/// Date and time inputs for moving a Once task, collapsed behind a "Reschedule" toggle.
+/// `from_page` swaps the task page rather than the dashboard after saving.
+fn render_reschedule_form(task: &DemoTask, from_page: bool) -> String {
+    let local = task.once.datetime.with_timezone(&get_timezone());
+    let action = format!("/tasks/{}/reschedule", html_escape(&task.id));
+    let (target, from) = if from_page { ("#task-show-page", "page") } else { ("#homepage", "card") };
+    format!(
+        r##"<details class="task-reschedule"><summary>Reschedule</summary><form method="post" action="{action}" hx-post="{action}" hx-target="{target}" hx-swap="outerHTML"><input type="hidden" name="from" value="{from}"><input type="date" name="once_date" value="{date}" required> <input type="time" name="once_time" value="{time}"> <button class="btn" type="submit">Move</button></form></details>"##,
+        action = action,
+        target = target,
+        from = from,
+        date = local.format("%Y-%m-%d"),
+        time = local.format("%H:%M"),
+    )
 }
 
 // POST /tasks/:id/delete - Mark a task as deleted (set deleted_at)
-async fn delete_task(State(pool): State<DbPool>, Path(id): Path<String>, headers: HeaderMap) -> Html<String> {
+async fn delete_task(State(pool): State<DbPool>, Path(id): Path<String>, headers: HeaderMap) -> Response {
     if let Ok(task_id) = id.parse::<i64>() {
-        match db::set_task_deleted_at(&pool, task_id, Some(Utc::now())).await {
-            Ok(_) => info!(task_id = %id, "Task deleted"),
+        match db::set_task_deleted_at(&pool, task_id, Some(clock::now())).await {
+            Ok(_) => {
+                info!(task_id = %id, "Task deleted");
+                record_audit(&pool, &id, "delete", "deleted").await;
+            }
             Err(e) => error!(task_id = %id, error = %e, "Error deleting task"),
         }
     }
 
     // Re-render the task show page
-    task_show(State(pool), Path(id), headers).await
+    task_show(State(pool), Path(id), headers).await.into_response()
+}
+
+// POST /tasks/:id/revert/:entry_id - Put back the schedule saved with an audit entry
+async fn revert_schedule(
+    State(pool): State<DbPool>,
+    Path((id, entry_id)): Path<(String, i64)>,
+    headers: HeaderMap,
+) -> Response {
+    let Ok(task_id) = id.parse::<i64>() else {
+        return pages::not_found(&headers, "That task doesn't exist.", Some(("/tasks", "Back to Tasks")));
+    };
+    let (Ok(Some(existing)), Ok(Some((saved_at, snapshot)))) = (
+        db::get_task(&pool, task_id).await,
+        db::get_audit_snapshot(&pool, &id, entry_id).await,
+    ) else {
+        let back = format!("/tasks/{}", id);
+        return pages::not_found(&headers, "That version is no longer available.", Some((&back, "Back to Task")));
+    };
+
+    let mut reverted = existing.clone();
+    if db::apply_schedule_snapshot(&mut reverted, &snapshot) {
+        match db::save_task(&pool, &reverted).await {
+            Ok(_) => {
+                info!(task_id = %id, entry_id, "Schedule reverted");
+                let saved_at = saved_at.with_timezone(&get_timezone()).format("%b %-d, %Y %H:%M");
+                let mut summary = format!("reverted schedule to the version from {}", saved_at);
+                let changes = describe_task_changes(&existing, &reverted);
+                if !changes.is_empty() {
+                    summary = format!("{}: {}", summary, changes.join("; "));
+                }
+                record_audit_with_snapshot(&pool, &id, "revert", &summary, &reverted).await;
+            }
+            Err(e) => error!(task_id = %id, error = %e, "Error reverting schedule"),
+        }
+    }
+
+    task_show(State(pool), Path(id), headers).await.into_response()
 }
 
 // POST /tasks/:id/restore - Restore a deleted task (clear deleted_at)
-async fn restore_task(State(pool): State<DbPool>, Path(id): Path<String>, headers: HeaderMap) -> Html<String> {
+async fn restore_task(State(pool): State<DbPool>, Path(id): Path<String>, headers: HeaderMap) -> Response {
     if let Ok(task_id) = id.parse::<i64>() {
         match db::set_task_deleted_at(&pool, task_id, None).await {
-            Ok(_) => info!(task_id = %id, "Task restored"),
+            Ok(_) => {
+                info!(task_id = %id, "Task restored");
+                record_audit(&pool, &id, "restore", "restored").await;
+            }
             Err(e) => error!(task_id = %id, error = %e, "Error restoring task"),
         }
     }
 
     // Re-render the task show page
-    task_show(State(pool), Path(id), headers).await
+    task_show(State(pool), Path(id), headers).await.into_response()
 }
 
 // GET /tasks/:id - Show page for a single task
-async fn task_show(State(pool): State<DbPool>, Path(id): Path<String>, headers: HeaderMap) -> Html<String> {
+async fn task_show(
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
     let is_touch = settings::is_touch_mode(&headers);
     let task = if is_demo_id(&id) {
         let tasks = get_demo_tasks();
         let tasks_guard = tasks.lock().unwrap();
         tasks_guard.get(&id).cloned()
     } else {
-        if let Ok(task_id) = id.parse::<i64>() {
-            db::get_task(&pool, task_id).await.ok().flatten()
-        } else {
-            None
+        match id.parse::<i64>() {
+            Ok(task_id) => db::get_task(&pool, task_id).await?,
+            Err(_) => None,
         }
     };
 
     let Some(task) = task else {
-        return Html(format!(
-            "<!DOCTYPE html><html><head><title>Not Found</title></head><body><h1>Task '{}' not found</h1><a href=\"/tasks\">Back to Tasks</a></body></html>",
+        return Err(AppError::NotFound(format!(
+            "Task '{}' doesn't exist. It may have been permanently deleted.",
             id
-        ));
+        )));
     };
 
     // Get all completions for calendar and list
     let completions = db::get_all_completions(&pool, &id).await.unwrap_or_default();
+    let audit_log = db::get_audit_log(&pool, &id, AUDIT_LOG_LIMIT).await.unwrap_or_default();
 
-    Html(render_task_show_page(&task, &completions, is_touch))
+    let page = Html(render_task_show_page(&task, &completions, &audit_log, is_touch, View::Full));
+    Ok(match task.next_due_rfc3339() {
+        Some(next_due) => ([("X-Next-Due", next_due)], page).into_response(),
+        None => page.into_response(),
+    })
 }
 
 // DELETE /tasks/:id/completions/:completion_id - Delete a completion
@@ -385,36 +937,188 @@ async fn delete_completion(
     State(pool): State<DbPool>,
     Path((task_id, completion_id)): Path<(String, i64)>,
     headers: HeaderMap,
-) -> Html<String> {
-    match db::delete_completion(&pool, completion_id).await {
-        Ok(_) => info!(task_id = %task_id, completion_id = %completion_id, "Completion deleted"),
+) -> Response {
+    remove_completion(&pool, &task_id, completion_id).await;
+
+    // Re-render the task show page
+    task_show(State(pool), Path(task_id), headers).await.into_response()
+}
+
+// POST /tasks/:id/completions/:completion_id/undo - Take back a completion from the dashboard's recent strip
+async fn undo_completion(
+    State(pool): State<DbPool>,
+    Path((task_id, completion_id)): Path<(String, i64)>,
+    headers: HeaderMap,
+) -> Response {
+    remove_completion(&pool, &task_id, completion_id).await;
+
+    if !is_htmx_request(&headers) {
+        return Redirect::to("/").into_response();
+    }
+    homepage(State(pool), headers).await.into_response()
+}
+
+/// Delete a completion and note it in the task's audit log
+async fn remove_completion(pool: &DbPool, task_id: &str, completion_id: i64) {
+    // Look up the completion first so the audit entry can say which one was removed
+    let removed = db::get_all_completions(pool, task_id)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .find(|c| c.id == completion_id);
+
+    match db::delete_completion(pool, completion_id).await {
+        Ok(_) => {
+            info!(task_id = %task_id, completion_id = %completion_id, "Completion deleted");
+            let summary = match removed {
+                Some(c) => format!(
+                    "removed completion from {}",
+                    c.completed_at.with_timezone(&get_timezone()).format("%b %-d, %Y %H:%M")
+                ),
+                None => "removed a completion".to_string(),
+            };
+            record_audit(pool, task_id, "delete_completion", &summary).await;
+        }
         Err(e) => error!(task_id = %task_id, completion_id = %completion_id, error = %e, "Error deleting completion"),
     }
+}
 
-    // Re-render the task show page
-    task_show(State(pool), Path(task_id), headers).await
+/// Completions shown in the dashboard's "recently completed" strip
+const RECENT_STRIP_LIMIT: i64 = 5;
+
+/// How far back the recent strip looks
+const RECENT_STRIP_HOURS: i64 = 24;
+
+/// Short relative time for the recent strip, e.g. "just now", "5m ago", "3h ago"
+fn format_relative_time(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(then);
+    if elapsed < Duration::minutes(1) {
+        "just now".to_string()
+    } else if elapsed < Duration::hours(1) {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed < Duration::days(1) {
+        format!("{}h ago", elapsed.num_hours())
+    } else {
+        format!("{}d ago", elapsed.num_days())
+    }
+}
+
+/// Horizontal strip of the latest completions across all tasks, each with an undo
+/// button. Empty when nothing was completed recently.
+fn render_recent_strip(entries: &[db::TimelineEntry], now: DateTime<Utc>) -> String {
+    let items: String = entries
+        .iter()
+        .filter(|e| now.signed_duration_since(e.completed_at) <= Duration::hours(RECENT_STRIP_HOURS))
+        .map(|e| {
+            let name = e.task_name.as_deref().unwrap_or("(deleted task)");
+            let who = e
+                .person_initials
+                .as_deref()
+                .map(|i| format!(" by {}", html_escape(i)))
+                .unwrap_or_default();
+            format!(
+                r##"<div class="recent-item"><a href="/tasks/{task_id}">{name}</a><span class="recent-when">{when}{who}</span><form method="post" action="/tasks/{task_id}/completions/{id}/undo"><button class="btn recent-undo-btn" type="submit" hx-post="/tasks/{task_id}/completions/{id}/undo" hx-target="#homepage" hx-swap="outerHTML" title="Remove this completion">Undo</button></form></div>"##,
+                task_id = html_escape(&e.task_id),
+                id = e.id,
+                name = html_escape(name),
+                when = format_relative_time(e.completed_at, now),
+                who = who
+            )
+        })
+        .collect();
+
+    if items.is_empty() {
+        return String::new();
+    }
+    format!(r#"<div class="recent-strip" aria-label="Recently completed">{}</div>"#, items)
+}
+
+/// Handler for `/`: renders the dashboard or redirects to the task list,
+/// depending on the HOME_ROUTE setting.
+pub async fn landing(state: State<DbPool>, headers: HeaderMap) -> Response {
+    match get_home_route() {
+        HomeRoute::Dashboard => homepage(state, headers).await.into_response(),
+        HomeRoute::Tasks => Redirect::to("/tasks").into_response(),
+    }
+}
+
+/// Who a page is rendered for. The guest board is read-only: no edit, delete
+/// or restore controls, and its links stay under `/guest/{token}`. A shared
+/// link is the same read-only board under `/share/{token}`, never completeable.
+#[derive(Clone, Copy)]
+pub enum View<'a> {
+    Full,
+    Guest { token: &'a str, can_complete: bool },
+    Shared { token: &'a str },
+}
+
+impl View<'_> {
+    /// Whether this is one of the read-only boards
+    pub fn is_guest(&self) -> bool {
+        matches!(self, View::Guest { .. } | View::Shared { .. })
+    }
+
+    fn home_url(&self) -> String {
+        match self {
+            View::Full => "/".to_string(),
+            View::Guest { token, .. } => format!("/guest/{}", token),
+            View::Shared { token } => format!("/share/{}", token),
+        }
+    }
+
+    fn task_url(&self, id: &str) -> String {
+        match self {
+            View::Full => format!("/tasks/{}", id),
+            View::Guest { token, .. } => format!("/guest/{}/tasks/{}", token, id),
+            View::Shared { token } => format!("/share/{}/tasks/{}", token, id),
+        }
+    }
 }
 
 // GET / - Homepage with task cards
 pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<String> {
-    let is_touch = settings::is_touch_mode(&headers);
-    // Collect all tasks from database only (demo tasks are excluded from index)
-    let all_tasks: Vec<DemoTask> = db::get_all_tasks(&pool).await.unwrap_or_default();
-    let now = Utc::now();
+    Html(render_homepage(&pool, &headers, View::Full).await)
+}
+
+/// The dashboard's sections, each sorted the way it's shown
+pub struct TaskCategories {
+    pub due_tasks: Vec<DemoTask>,
+    pub alerting_tasks: Vec<DemoTask>,
+    /// With the initials of whoever completed the current occurrence
+    pub completed_tasks: Vec<(DemoTask, Option<String>)>,
+    pub planning_tasks: Vec<DemoTask>,
+    pub other_tasks: Vec<DemoTask>,
+    /// Skipped with "Not today"; kept out of the other sections until the occurrence is over
+    pub snoozed_tasks: Vec<DemoTask>,
+    pub recurring_events: Vec<DemoTask>,
+    pub inactive_tasks: Vec<DemoTask>,
+    pub attention_tasks: Vec<DemoTask>,
+    /// Completions so far in the current occurrence, for counter tasks only
+    pub counts: HashMap<String, i64>,
+}
 
-    // Categorize tasks; completed_tasks carries (task, who_completed_initials)
+/// Sort tasks into the dashboard's sections. The dashboard and the printable
+/// checklist both go through this, so they agree on what's due and what's done.
+pub async fn categorize_tasks(pool: &DbPool, all_tasks: Vec<DemoTask>, now: DateTime<Utc>) -> TaskCategories {
     let mut due_tasks = Vec::new();
     let mut alerting_tasks = Vec::new();
     let mut completed_tasks: Vec<(DemoTask, Option<String>)> = Vec::new();
+    let mut planning_tasks = Vec::new();
     let mut other_tasks = Vec::new();
+    let mut snoozed_tasks = Vec::new();
     let mut recurring_events = Vec::new();
     let mut inactive_tasks = Vec::new();
+    let mut attention_tasks = Vec::new();
+    // Completions so far in the current occurrence, for counter tasks only
+    let mut counts: HashMap<String, i64> = HashMap::new();
 
     for task in all_tasks {
         let is_inactive = task.is_inactive();
         
         if is_inactive {
             inactive_tasks.push(task);
+        } else if task.has_empty_schedule() {
+            attention_tasks.push(task);
         } else if task.is_once_completed() && !task.completeable {
             completed_tasks.push((task, None));
         } else if !task.completeable {
@@ -425,35 +1129,109 @@ pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<St
                 alerting_tasks.push(task);
             } else if most_recent_due <= now && time_since_due <= Duration::days(1) {
                 completed_tasks.push((task, None));
+            } else if task.is_planning() {
+                planning_tasks.push(task);
             } else {
                 recurring_events.push(task);
             }
         } else {
-            let (is_completed, completed_by) = if let Ok(Some((completion_time, initials))) = db::get_latest_completion(&pool, &task.id).await {
-                (completion_time > task.most_recent_due_date(), initials)
-            } else {
-                (false, None)
+            // Completions inside the current occurrence's window decide it
+            let (start, end) = occurrence_window(&task, task.most_recent_due_date());
+            let count = db::count_completions_between(pool, &task.id, start, end).await.unwrap_or(0);
+            let is_completed = occurrence_done(&task, count);
+            if task.is_counter() {
+                counts.insert(task.id.clone(), count);
+            }
+            let completed_by = match db::get_latest_completion(pool, &task.id).await {
+                Ok(Some((_, initials))) if is_completed => initials,
+                _ => None,
             };
             
             if is_completed {
                 completed_tasks.push((task, completed_by));
+            } else if task.is_snoozed() {
+                snoozed_tasks.push(task);
             } else if task.is_due() {
                 due_tasks.push(task);
             } else if task.is_alerting() {
                 alerting_tasks.push(task);
+            } else if task.is_planning() {
+                planning_tasks.push(task);
             } else {
                 other_tasks.push(task);
             }
         }
     }
 
+    // Long-past one-time tasks are archived off the dashboard (still on /tasks)
+    let archive_after = get_archive_after_days();
+    completed_tasks.retain(|(task, _)| !task.is_archived(archive_after, now));
+
     // Sort each category by next due date
     due_tasks.sort_by(|a, b| a.next_due_date().cmp(&b.next_due_date()));
     alerting_tasks.sort_by(|a, b| a.next_due_date().cmp(&b.next_due_date()));
     completed_tasks.sort_by(|a, b| a.0.next_due_date().cmp(&b.0.next_due_date()));
+    planning_tasks.sort_by(|a, b| a.next_due_date().cmp(&b.next_due_date()));
     other_tasks.sort_by(|a, b| a.next_due_date().cmp(&b.next_due_date()));
+    snoozed_tasks.sort_by(|a, b| a.next_due_date().cmp(&b.next_due_date()));
     recurring_events.sort_by(|a, b| a.next_due_date().cmp(&b.next_due_date()));
     inactive_tasks.sort_by(|a, b| a.name.cmp(&b.name));
+    attention_tasks.sort_by(|a, b| a.name.cmp(&b.name));
+
+    TaskCategories {
+        due_tasks,
+        alerting_tasks,
+        completed_tasks,
+        planning_tasks,
+        other_tasks,
+        snoozed_tasks,
+        recurring_events,
+        inactive_tasks,
+        attention_tasks,
+        counts,
+    }
+}
+
+/// The dashboard, either the full app or the read-only guest board
+pub async fn render_homepage(pool: &DbPool, headers: &HeaderMap, view: View<'_>) -> String {
+    let user_settings = settings::read_settings(headers);
+    let is_touch = user_settings.touch_mode;
+    let expanded = user_settings.expanded_cards;
+    // Collect all tasks from database only (demo tasks are excluded from index)
+    let all_tasks: Vec<DemoTask> = db::get_all_tasks(pool).await.unwrap_or_default();
+    let now = clock::now();
+    let is_guest = view.is_guest();
+    // First run: nothing at all in the database, not even in the trash
+    let needs_setup = !is_guest && db::get_task_count(pool).await.unwrap_or(0) == 0;
+
+    let TaskCategories {
+        mut due_tasks,
+        mut alerting_tasks,
+        completed_tasks,
+        planning_tasks,
+        other_tasks,
+        snoozed_tasks,
+        recurring_events,
+        inactive_tasks,
+        attention_tasks,
+        counts,
+    } = categorize_tasks(pool, all_tasks, now).await;
+
+    let count_of = |id: &str| counts.get(id).copied().unwrap_or(0);
+
+    // The recent strip offers undo, so guests don't get it
+    let recent_strip = if is_guest {
+        String::new()
+    } else {
+        let recent = db::get_recent_completions(pool, 0, RECENT_STRIP_LIMIT).await.unwrap_or_default();
+        render_recent_strip(&recent, now)
+    };
+
+    let combined_tasks = if user_settings.combined_due {
+        merge_due_and_alerting(std::mem::take(&mut due_tasks), std::mem::take(&mut alerting_tasks))
+    } else {
+        Vec::new()
+    };
 
     let html = maud! {
         !DOCTYPE
@@ -466,28 +1244,51 @@ pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<St
                 link rel="stylesheet" href="/static/app.css";
                 script src="/static/htmx.min.js" {}
                 script src="/static/auto-sleep.js" {}
+                @if user_settings.live_updates {
+                    script src="/static/live-refresh.js" {}
+                }
+                @if user_settings.celebrate {
+                    script src="/static/celebrate.js" data-sound=(user_settings.celebrate_sound.to_string()) {}
+                }
             }
             body {
-                div .corner-links {
-                    @if is_touch {
-                        button .btn onclick="window.location.href='/idle?return=home'" { "Sleep" }
-                        button .btn onclick="window.location.href='/settings'" { "Settings" }
-                    } @else {
-                        a href="/idle?return=home" { "Sleep" }
-                        a href="/photos" { "photos" }
-                        a href="/settings" { "settings" }
+                @if !is_guest {
+                    div .corner-links {
+                        @if is_touch {
+                            button .btn onclick="window.location.href='/idle?return=home'" { "Sleep" }
+                            button .btn onclick="window.location.href='/settings'" { "Settings" }
+                        } @else {
+                            a href="/idle?return=home" { "Sleep" }
+                            a href="/photos" { "photos" }
+                            a href="/settings" { "settings" }
+                        }
                     }
                 }
                 div .homepage id="homepage" {
                     div .page-header {
                         h1 { "Chores" }
-                        div .page-header-buttons {
-                            @if is_touch {
-                                button .btn onclick="window.location.href='/daily'" { "Daily" }
-                                button .btn onclick="window.location.href='/calendar'" { "Calendar" }
-                            } @else {
-                                a .btn href="/daily" { "Daily" }
-                                a .btn href="/calendar" { "Calendar" }
+                        @if !is_guest {
+                            div .page-header-buttons {
+                                @if is_touch {
+                                    button .btn onclick="window.location.href='/daily'" { "Daily" }
+                                    button .btn onclick="window.location.href='/calendar'" { "Calendar" }
+                                } @else {
+                                    a .btn href="/daily" { "Daily" }
+                                    a .btn href="/calendar" { "Calendar" }
+                                }
+                            }
+                        }
+                    }
+
+                    (Raw::dangerously_create(&recent_strip))
+
+                    @if !combined_tasks.is_empty() {
+                        section .task-section {
+                            h2 { "Due Soon" }
+                            div .task-card-grid {
+                                @for (task, status) in &combined_tasks {
+                                    (Raw::dangerously_create(&render_tagged_task_card(task, status, is_touch, count_of(&task.id), expanded, view)))
+                                }
                             }
                         }
                     }
@@ -497,7 +1298,7 @@ pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<St
                             h2 { "Due Tasks" }
                             div .task-card-grid {
                                 @for task in &due_tasks {
-                                    (Raw::dangerously_create(&render_task_card(task, "due", is_touch, None)))
+                                    (Raw::dangerously_create(&render_task_card(task, "due", is_touch, None, count_of(&task.id), expanded, view)))
                                 }
                             }
                         }
@@ -508,7 +1309,18 @@ pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<St
                             h2 { "Upcoming" }
                             div .task-card-grid {
                                 @for task in &alerting_tasks {
-                                    (Raw::dangerously_create(&render_task_card(task, "alerting", is_touch, None)))
+                                    (Raw::dangerously_create(&render_task_card(task, "alerting", is_touch, None, count_of(&task.id), expanded, view)))
+                                }
+                            }
+                        }
+                    }
+
+                    @if !planning_tasks.is_empty() {
+                        section .task-section {
+                            h2 { "Planning" }
+                            div .task-card-grid {
+                                @for task in &planning_tasks {
+                                    (Raw::dangerously_create(&render_task_card(task, "planning", is_touch, None, count_of(&task.id), expanded, view)))
                                 }
                             }
                         }
@@ -519,7 +1331,7 @@ pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<St
                             h2 { "Completed" }
                             div .task-card-grid {
                                 @for (task, initials) in &completed_tasks {
-                                    (Raw::dangerously_create(&render_task_card(task, "completed", is_touch, initials.as_deref())))
+                                    (Raw::dangerously_create(&render_task_card(task, "completed", is_touch, initials.as_deref(), count_of(&task.id), expanded, view)))
                                 }
                             }
                         }
@@ -530,7 +1342,18 @@ pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<St
                             h2 { "Other Tasks" }
                             div .task-card-grid {
                                 @for task in &other_tasks {
-                                    (Raw::dangerously_create(&render_task_card(task, "normal", is_touch, None)))
+                                    (Raw::dangerously_create(&render_task_card(task, "normal", is_touch, None, count_of(&task.id), expanded, view)))
+                                }
+                            }
+                        }
+                    }
+
+                    @if !snoozed_tasks.is_empty() {
+                        details .task-section.task-section-snoozed {
+                            summary { h2 { "Snoozed (" (snoozed_tasks.len()) ")" } }
+                            div .task-card-grid {
+                                @for task in &snoozed_tasks {
+                                    (Raw::dangerously_create(&render_task_card(task, "normal", is_touch, None, count_of(&task.id), expanded, view)))
                                 }
                             }
                         }
@@ -541,7 +1364,19 @@ pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<St
                             h2 { "Recurring Events" }
                             div .task-card-grid {
                                 @for task in &recurring_events {
-                                    (Raw::dangerously_create(&render_task_card(task, "event", is_touch, None)))
+                                    (Raw::dangerously_create(&render_task_card(task, "event", is_touch, None, 0, expanded, view)))
+                                }
+                            }
+                        }
+                    }
+
+                    @if !attention_tasks.is_empty() {
+                        section .task-section {
+                            h2 { "Needs Attention" }
+                            p .task-section-note { "These schedules have nothing selected, so they never come due. Edit them to pick days." }
+                            div .task-card-grid {
+                                @for task in &attention_tasks {
+                                    (Raw::dangerously_create(&render_task_card(task, "attention", is_touch, None, 0, expanded, view)))
                                 }
                             }
                         }
@@ -552,36 +1387,258 @@ pub async fn homepage(State(pool): State<DbPool>, headers: HeaderMap) -> Html<St
                             h2 { "Inactive" }
                             div .task-card-grid {
                                 @for task in &inactive_tasks {
-                                    (Raw::dangerously_create(&render_task_card(task, "inactive", is_touch, None)))
+                                    (Raw::dangerously_create(&render_task_card(task, "inactive", is_touch, None, 0, expanded, view)))
                                 }
                             }
                         }
                     }
 
-                    @if due_tasks.is_empty() && alerting_tasks.is_empty() && completed_tasks.is_empty() && other_tasks.is_empty() && recurring_events.is_empty() && inactive_tasks.is_empty() {
+                    @if combined_tasks.is_empty() && due_tasks.is_empty() && alerting_tasks.is_empty() && planning_tasks.is_empty() && completed_tasks.is_empty() && other_tasks.is_empty() && snoozed_tasks.is_empty() && recurring_events.is_empty() && inactive_tasks.is_empty() && attention_tasks.is_empty() {
                         div .empty-state {
-                            p { "No tasks yet!" }
-                            @if is_touch {
-                                button .btn onclick="window.location.href='/tasks'" { "Go to Tasks →" }
+                            @if needs_setup {
+                                (Raw::dangerously_create(&render_onboarding("home")))
                             } @else {
-                                a href="/tasks" { "Go to Tasks →" }
+                                p { "No tasks yet!" }
+                                @if !is_guest {
+                                    @if is_touch {
+                                        button .btn onclick="window.location.href='/tasks'" { "Go to Tasks →" }
+                                    } @else {
+                                        a href="/tasks" { "Go to Tasks →" }
+                                    }
+                                }
                             }
                         }
                     }
 
-                    div .homepage-footer {
-                        @if is_touch {
-                            button .btn.btn-default onclick="window.location.href='/tasks'" { "Manage Tasks →" }
-                        } @else {
-                            a href="/tasks" { "Manage Tasks →" }
+                    @if !is_guest {
+                        div .homepage-footer {
+                            @if is_touch {
+                                button .btn.btn-default onclick="window.location.href='/tasks'" { "Manage Tasks →" }
+                                " "
+                                button .btn onclick="window.location.href='/history'" { "History" }
+                                " "
+                                button .btn onclick="window.location.href='/stats'" { "Stats" }
+                                " "
+                                button .btn onclick="window.location.href='/print'" { "Print" }
+                            } @else {
+                                a href="/tasks" { "Manage Tasks →" }
+                                " | "
+                                a href="/history" { "History" }
+                                " | "
+                                a href="/stats" { "Stats" }
+                                " | "
+                                a href="/print" { "Print" }
+                            }
                         }
                     }
+                    p .timezone-note { (timezone_note()) }
+                }
+                @if !is_guest {
+                    (Raw::dangerously_create(&pages::render_bottom_nav(is_touch, pages::NavItem::Home)))
                 }
             }
         }
     };
 
-    Html(html.render().into_inner())
+    html.render().into_inner()
+}
+
+/// Active tasks with an occurrence on the given local date, with their due
+/// times, earliest first
+pub fn tasks_due_on(tasks: &[DemoTask], date: chrono::NaiveDate) -> Vec<(&DemoTask, NaiveTime)> {
+    let mut due: Vec<(&DemoTask, NaiveTime)> = tasks
+        .iter()
+        .filter(|task| !task.is_inactive() && is_due_on_date(task, date))
+        .map(|task| (task, get_due_time(task, date)))
+        .collect();
+    due.sort_by_key(|(_, time)| *time);
+    due
+}
+
+/// Shown instead of a due time for all-day tasks
+const ALL_DAY_LABEL: &str = "All day";
+
+/// The time shown next to a task in a day's list: "All day" for all-day
+/// tasks, otherwise the local "HH:MM"
+fn event_time_label(task: &DemoTask, time: NaiveTime) -> String {
+    if task.all_day {
+        ALL_DAY_LABEL.to_string()
+    } else {
+        time.format("%H:%M").to_string()
+    }
+}
+
+/// Parse a "YYYY-MM-DD" date from a URL
+pub fn parse_day(date: &str) -> Result<chrono::NaiveDate, AppError> {
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| AppError::Validation(format!("\"{}\" isn't a date. Use YYYY-MM-DD.", date)))
+}
+
+// GET /day/:date - Every task due on one date (YYYY-MM-DD), as a plain list
+pub async fn day_page(
+    State(pool): State<DbPool>,
+    Path(date): Path<String>,
+    headers: HeaderMap,
+) -> Result<Html<String>, AppError> {
+    let date = parse_day(&date)?;
+    let all_tasks = db::get_all_tasks(&pool).await?;
+    let is_touch = settings::is_touch_mode(&headers);
+    Ok(Html(pages::render_page(
+        &date.format("%b %-d, %Y").to_string(),
+        &render_day_list(&tasks_due_on(&all_tasks, date), date),
+        is_touch,
+        pages::NavItem::Other,
+    )))
+}
+
+/// How many days the printable checklist covers by default
+const PRINT_DAYS: i64 = 7;
+
+#[derive(Deserialize, Default)]
+pub struct PrintQuery {
+    /// "today" for just today; anything else prints the week ahead
+    #[serde(default)]
+    range: String,
+}
+
+// GET /print - The dashboard as a paper checklist: anything overdue, then each
+// day's tasks with a box to tick. Only the Print and Home links are on screen,
+// and print.css hides those on paper.
+pub async fn print_page(State(pool): State<DbPool>, Query(query): Query<PrintQuery>) -> Html<String> {
+    Html(render_print_checklist(&pool, query.range == "today").await)
+}
+
+async fn render_print_checklist(pool: &DbPool, today_only: bool) -> String {
+    let all_tasks: Vec<DemoTask> = db::get_all_tasks(pool).await.unwrap_or_default();
+    let now = clock::now();
+    let tz = get_timezone();
+    let local_now = now.with_timezone(&tz);
+    let today = local_now.date_naive();
+    let categories = categorize_tasks(pool, all_tasks.clone(), now).await;
+
+    // Today's own occurrences are in today's list, so only earlier ones count as overdue
+    let overdue: Vec<&DemoTask> = categories
+        .due_tasks
+        .iter()
+        .filter(|task| task.most_recent_due_date().with_timezone(&tz).date_naive() < today)
+        .collect();
+    let done_ids: HashSet<&str> = categories.completed_tasks.iter().map(|(task, _)| task.id.as_str()).collect();
+    let days: Vec<(chrono::NaiveDate, Vec<(&DemoTask, NaiveTime)>)> = (0..if today_only { 1 } else { PRINT_DAYS })
+        .map(|offset| today + Duration::days(offset))
+        .map(|date| (date, tasks_due_on(&all_tasks, date)))
+        .collect();
+
+    // Events get a bullet; today's occurrences that are already done come pre-ticked
+    let mark = |task: &DemoTask, date: chrono::NaiveDate, time: NaiveTime| {
+        if !task.completeable {
+            "•"
+        } else if date == today && time <= local_now.time() && done_ids.contains(task.id.as_str()) {
+            "☑"
+        } else {
+            "☐"
+        }
+    };
+    let times = |task: &DemoTask| if task.is_counter() { format!(" (×{})", task.target_count) } else { String::new() };
+    let (title, other_range) = if today_only {
+        ("Today", ("/print", "This week"))
+    } else {
+        ("This Week", ("/print?range=today", "Today only"))
+    };
+
+    maud! {
+        !DOCTYPE
+        html {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "Checklist - Chores" }
+                link rel="stylesheet" href="/static/print.css";
+            }
+            body {
+                div .print-page {
+                    div .print-controls {
+                        a href="/" { "← Home" }
+                        " "
+                        a href=(other_range.0) { (other_range.1) }
+                        " "
+                        button type="button" onclick="window.print()" { "Print" }
+                    }
+                    h1 { (title) }
+                    @if !overdue.is_empty() {
+                        section .print-section {
+                            h2 { "Overdue" }
+                            ul .print-list {
+                                @for task in &overdue {
+                                    li {
+                                        span .print-box { "☐" }
+                                        span .print-name { (task.name) (times(task)) }
+                                        span .print-time { (task.most_recent_due_date().with_timezone(&tz).format("was due %a %b %-d").to_string()) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    @for (date, tasks_on_day) in &days {
+                        section .print-section {
+                            h2 { (date.format("%A, %B %-d").to_string()) }
+                            @if tasks_on_day.is_empty() {
+                                p .print-empty { "Nothing due." }
+                            } @else {
+                                ul .print-list {
+                                    @for (task, time) in tasks_on_day {
+                                        li {
+                                            span .print-box { (mark(task, *date, *time)) }
+                                            span .print-time { (event_time_label(task, *time)) }
+                                            span .print-name { (task.name) (times(task)) }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    p .timezone-note { (timezone_note()) }
+                }
+            }
+        }
+    }
+    .render()
+    .into_inner()
+}
+
+fn render_day_list(tasks_on_day: &[(&DemoTask, NaiveTime)], date: chrono::NaiveDate) -> String {
+    let day_url = |d: chrono::NaiveDate| format!("/day/{}", d.format("%Y-%m-%d"));
+    let daily_url = format!("/daily/{}/{}/{}", date.year(), date.month(), date.day());
+    maud! {
+        div .daily-page {
+            div .tasks-page-header {
+                a href="/" { "← Home" }
+                " | "
+                a href=(day_url(date - Duration::days(1))) { "Previous day" }
+                " | "
+                a href=(day_url(date + Duration::days(1))) { "Next day" }
+                " | "
+                a href=(daily_url) { "Day view" }
+            }
+            div .daily-date-display {
+                h2 { (date.format("%A, %B %-d, %Y").to_string()) }
+            }
+            @if tasks_on_day.is_empty() {
+                div .daily-empty { "Nothing is due on this day." }
+            } @else {
+                div .daily-events {
+                    @for (task, time) in tasks_on_day {
+                        div .daily-event {
+                            span .daily-event-time { (event_time_label(task, *time)) }
+                            a .daily-event-name href=(format!("/tasks/{}", task.id)) { (task.name) }
+                            span .day-event-schedule { (describe_schedule(task)) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    .render()
+    .into_inner()
 }
 
 // Query params for daily date selection
@@ -599,7 +1656,7 @@ pub async fn daily_today(
     headers: HeaderMap,
 ) -> Html<String> {
     let tz = get_timezone();
-    let now = Utc::now().with_timezone(&tz);
+    let now = clock::now().with_timezone(&tz);
     let is_touch = settings::is_touch_mode(&headers);
     
     // Use query params if provided, otherwise use today
@@ -627,7 +1684,7 @@ async fn daily_page_inner(pool: &DbPool, year: i32, month: u32, day: u32, is_tou
     let date = NaiveDate::from_ymd_opt(year, month, day)
         .unwrap_or_else(|| {
             let tz = get_timezone();
-            Utc::now().with_timezone(&tz).date_naive()
+            clock::now().with_timezone(&tz).date_naive()
         });
     
     let year = date.year();
@@ -639,14 +1696,10 @@ async fn daily_page_inner(pool: &DbPool, year: i32, month: u32, day: u32, is_tou
     
     // Get today's date for carried task check
     let tz = get_timezone();
-    let today = Utc::now().with_timezone(&tz).date_naive();
+    let today = clock::now().with_timezone(&tz).date_naive();
     
     // Filter tasks that are due on this date and get their times
-    let mut tasks_on_day: Vec<(&DemoTask, chrono::NaiveTime)> = all_tasks
-        .iter()
-        .filter(|task| !task.is_inactive() && is_due_on_date(task, date))
-        .map(|task| (task, get_due_time(task, date)))
-        .collect();
+    let mut tasks_on_day = tasks_due_on(&all_tasks, date);
     
     // Also include "carried" Once tasks on today:
     // Once tasks that are completeable, scheduled in the past, and not yet completed
@@ -695,7 +1748,7 @@ async fn daily_page_inner(pool: &DbPool, year: i32, month: u32, day: u32, is_tou
     let next_url = format!("/daily/{}/{}/{}", next_date.year(), next_date.month(), next_date.day());
     
     // Generate year options (current year +/- 5 years)
-    let current_year = Utc::now().year();
+    let current_year = clock::now().year();
     let year_options: String = ((current_year - 5)..=(current_year + 5))
         .map(|y| {
             let selected = if y == year { " selected" } else { "" };
@@ -729,7 +1782,7 @@ async fn daily_page_inner(pool: &DbPool, year: i32, month: u32, day: u32, is_tou
     let display_date = date.format("%A, %B %-d, %Y").to_string();
     
     // Check if this is today
-    let today = Utc::now().with_timezone(&tz).date_naive();
+    let today = clock::now().with_timezone(&tz).date_naive();
     let is_today = date == today;
     
     // Build daily controls with HTMX attributes (as string since maud doesn't support custom attrs)
@@ -761,7 +1814,7 @@ async fn daily_page_inner(pool: &DbPool, year: i32, month: u32, day: u32, is_tou
         r#"<div class="daily-empty"><p>No events on this day</p></div>"#.to_string()
     } else {
         tasks_on_day.iter().map(|(task, time)| {
-            let time_str = time.format("%H:%M").to_string();
+            let time_str = event_time_label(task, *time);
             let task_url = format!("/tasks/{}", task.id);
             if is_touch {
                 format!(
@@ -902,7 +1955,7 @@ pub async fn calendar_today(
     headers: HeaderMap,
 ) -> Html<String> {
     let tz = get_timezone();
-    let now = Utc::now().with_timezone(&tz);
+    let now = clock::now().with_timezone(&tz);
     let is_touch = settings::is_touch_mode(&headers);
     
     // Use query params if provided, otherwise use current month
@@ -939,7 +1992,7 @@ async fn calendar_page_inner(pool: &DbPool, year: i32, month: u32, is_touch: boo
     let next_url = format!("/calendar/{}/{}", next_year, next_month);
     
     // Generate year options (current year +/- 5 years)
-    let current_year = Utc::now().year();
+    let current_year = clock::now().year();
     let year_options: String = ((current_year - 5)..=(current_year + 5))
         .map(|y| {
             let selected = if y == year { " selected" } else { "" };
@@ -961,7 +2014,7 @@ async fn calendar_page_inner(pool: &DbPool, year: i32, month: u32, is_touch: boo
     
     // Get timezone and today
     let tz = get_timezone();
-    let today = Utc::now().with_timezone(&tz).date_naive();
+    let today = clock::now().with_timezone(&tz).date_naive();
     let is_current_month = today.year() == year && today.month() == month;
     
     // Build calendar controls
@@ -1023,11 +2076,7 @@ async fn calendar_page_inner(pool: &DbPool, year: i32, month: u32, is_touch: boo
         let is_today = date == today;
         
         // Get tasks due on this day, sorted by time
-        let mut tasks_on_day: Vec<(&DemoTask, chrono::NaiveTime)> = all_tasks
-            .iter()
-            .filter(|task| !task.is_inactive() && is_due_on_date(task, date))
-            .map(|task| (task, get_due_time(task, date)))
-            .collect();
+        let mut tasks_on_day = tasks_due_on(&all_tasks, date);
         
         // Also include "carried" Once tasks on today
         if is_today {
@@ -1074,7 +2123,7 @@ async fn calendar_page_inner(pool: &DbPool, year: i32, month: u32, is_touch: boo
             // Touch mode: entire cell is clickable, no individual task links
             let tasks_html: String = tasks_on_day.iter()
                 .map(|(task, time)| {
-                    let time_str = time.format("%H:%M").to_string();
+                    let time_str = event_time_label(task, *time);
                     format!(
                         r#"<div class="calendar-cell-event"><span class="calendar-cell-event-time">{}</span> <span class="calendar-cell-event-name">{}</span></div>"#,
                         time_str, task.name
@@ -1093,7 +2142,7 @@ async fn calendar_page_inner(pool: &DbPool, year: i32, month: u32, is_touch: boo
             // Non-touch mode: individual task links
             let tasks_html: String = tasks_on_day.iter()
                 .map(|(task, time)| {
-                    let time_str = time.format("%H:%M").to_string();
+                    let time_str = event_time_label(task, *time);
                     let task_url = format!("/tasks/{}", task.id);
                     format!(
                         r#"<div class="calendar-cell-event"><span class="calendar-cell-event-time">{}</span> <a href="{}" class="calendar-cell-event-name">{}</a></div>"#,
@@ -1191,14 +2240,83 @@ async fn calendar_page_inner(pool: &DbPool, year: i32, month: u32, is_touch: boo
     Html(html)
 }
 
-fn render_task_card(task: &DemoTask, status: &str, is_touch: bool, completed_by: Option<&str>) -> String {
+/// Merge the due and alerting buckets into one list ordered by next due date,
+/// keeping each task's card status so overdue and upcoming can be told apart.
+fn merge_due_and_alerting(due: Vec<DemoTask>, alerting: Vec<DemoTask>) -> Vec<(DemoTask, &'static str)> {
+    let mut merged: Vec<(DemoTask, &'static str)> = due
+        .into_iter()
+        .map(|t| (t, "due"))
+        .chain(alerting.into_iter().map(|t| (t, "alerting")))
+        .collect();
+    merged.sort_by_key(|(task, _)| task.next_due_date());
+    merged
+}
+
+/// Card for the combined "Due Soon" list, with a small Overdue/Upcoming tag
+fn render_tagged_task_card(task: &DemoTask, status: &str, is_touch: bool, count: i64, expanded: bool, view: View<'_>) -> String {
+    let tag = if status == "due" {
+        r#" <span class="task-card-tag task-card-tag-overdue">Overdue</span>"#
+    } else {
+        r#" <span class="task-card-tag task-card-tag-upcoming">Upcoming</span>"#
+    };
+    render_task_card_inner(task, status, is_touch, None, count, tag, expanded, view)
+}
+
+fn render_task_card(
+    task: &DemoTask,
+    status: &str,
+    is_touch: bool,
+    completed_by: Option<&str>,
+    count: i64,
+    expanded: bool,
+    view: View<'_>,
+) -> String {
+    render_task_card_inner(task, status, is_touch, completed_by, count, "", expanded, view)
+}
+
+/// Number of upcoming occurrences listed on expanded cards
+const CARD_AGENDA_LENGTH: usize = 3;
+
+/// Short label for an upcoming occurrence on a card: "Tue 09:00" within the
+/// coming week, with the date added beyond that ("Tue Nov 3 09:00")
+fn format_agenda_time(due: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let local = due.with_timezone(&get_timezone());
+    if due - now < Duration::days(6) {
+        local.format("%a %H:%M").to_string()
+    } else {
+        local.format("%a %b %-d %H:%M").to_string()
+    }
+}
+
+/// `count` is the number of completions in the current occurrence; it's only shown for counter tasks.
+/// `expanded` lists the next few occurrences under the due line.
+#[allow(clippy::too_many_arguments)]
+fn render_task_card_inner(
+    task: &DemoTask,
+    status: &str,
+    is_touch: bool,
+    completed_by: Option<&str>,
+    count: i64,
+    tag: &str,
+    expanded: bool,
+    view: View<'_>,
+) -> String {
     let status_class = format!("task-card task-card-{}", status);
     let due_str = task.time_as_readable_string();
     let picker_url = format!("/tasks/{}/complete-picker", task.id);
-    let show_url = format!("/tasks/{}", task.id);
+    let show_url = view.task_url(&task.id);
     let is_completed = status == "completed";
     let is_inactive = status == "inactive";
     let complete_area_id = format!("task-{}-complete", task.id);
+    let counter_html = if task.is_counter() && task.completeable && !is_inactive {
+        format!(
+            r#"<span class="task-card-count" title="Completions this time">{}/{}</span>"#,
+            count.min(task.target_count),
+            task.target_count
+        )
+    } else {
+        String::new()
+    };
 
     let complete_button = if is_inactive {
         String::new()
@@ -1213,34 +2331,84 @@ fn render_task_card(task: &DemoTask, status: &str, is_touch: bool, completed_by:
             Some(initials) => format!("Done by {}", html_escape(initials)),
             None => "✓ Done".to_string(),
         };
-        format!(r#"<div class="task-card-completed-label">{}</div>"#, label)
+        format!(r#"<div class="task-card-completed-label">{}{}</div>"#, counter_html, label)
+    } else if let View::Guest { token, can_complete: true } = view {
+        // Guests get a plain Complete (no picker, no "Not today"), and only when allowed
+        format!(
+            r#"<div class="task-card-complete-area">{}<form method="post" action="/guest/{}/tasks/{}/complete"><button class="btn task-card-complete-btn" type="submit">{}</button></form></div>"#,
+            counter_html, token, task.id, html_escape(task.completion_button_label())
+        )
+    } else if view.is_guest() {
+        counter_html.clone()
     } else {
+        // "Not today" dismisses the current occurrence of a recurring task without completing it
+        let not_today = if task.schedule_kind != ScheduleKind::Once && (status == "due" || status == "alerting") {
+            format!(
+                r##"<form method="post" action="/tasks/{id}/not-today"><button class="btn task-card-not-today-btn" type="submit" hx-post="/tasks/{id}/not-today" hx-target="#homepage" hx-swap="outerHTML" title="Hide until the next occurrence. This does not count as done.">Not today</button></form>"##,
+                id = task.id
+            )
+        } else {
+            String::new()
+        };
+        // The form lets the button complete the task as a plain POST when JS is off
         format!(
-            r##"<div id="{}" class="task-card-complete-area"><button class="btn task-card-complete-btn" hx-get="{}" hx-target="#{}" hx-swap="innerHTML">Complete</button></div>"##,
-            complete_area_id, picker_url, complete_area_id
+            r##"<div id="{}" class="task-card-complete-area">{}<form method="post" action="/tasks/{}/complete"><button class="btn task-card-complete-btn" type="submit" hx-get="{}" hx-target="#{}" hx-swap="innerHTML">{}</button></form>{}</div>"##,
+            complete_area_id, counter_html, task.id, picker_url, complete_area_id, html_escape(task.completion_button_label()), not_today
         )
     };
 
-    // Add "(inactive)" label for inactive tasks
+    // One-time tasks have nothing beyond their single due line
+    let agenda = if expanded && !is_inactive && task.schedule_kind != ScheduleKind::Once {
+        let now = clock::now();
+        upcoming_occurrences(task, CARD_AGENDA_LENGTH)
+            .into_iter()
+            .map(|due| format_agenda_time(due, now))
+            .collect::<Vec<_>>()
+            .join(", ")
+    } else {
+        String::new()
+    };
+
+    let ends = match task.scheduled_end() {
+        Some(end) if !is_inactive => end.with_timezone(&get_timezone()).format("Ends %b %-d").to_string(),
+        _ => String::new(),
+    };
+
+    let reschedule = if task.schedule_kind == ScheduleKind::Once
+        && !view.is_guest()
+        && !is_inactive
+        && !is_completed
+        && !is_demo_id(&task.id)
+    {
+        render_reschedule_form(task, false)
+    } else {
+        String::new()
+    };
+
+    // Add "(inactive)" label for inactive tasks, and say plainly that a snoozed task isn't done
     let inactive_label = if is_inactive {
         r#" <span class="task-inactive-label">(inactive)</span>"#
+    } else if task.is_snoozed() {
+        r#" <span class="task-inactive-label">(not today, not done)</span>"#
     } else {
         ""
     };
 
     let title_html = if is_touch {
         format!(
-            r##"<button class="btn task-card-title-btn" onclick="window.location.href='{}'"><span class="task-card-title">{}</span>{}</button>"##,
+            r##"<button class="btn task-card-title-btn" onclick="window.location.href='{}'"><span class="task-card-title">{}</span>{}</button>{}"##,
             show_url,
             html_escape(&task.name),
-            inactive_label
+            inactive_label,
+            tag
         )
     } else {
         format!(
-            r##"<a class="task-card-title" href="{}">{}</a>{}"##,
+            r##"<a class="task-card-title" href="{}">{}</a>{}{}"##,
             show_url,
             html_escape(&task.name),
-            inactive_label
+            inactive_label,
+            tag
         )
     };
 
@@ -1252,15 +2420,33 @@ fn render_task_card(task: &DemoTask, status: &str, is_touch: bool, completed_by:
             }
             (Raw::dangerously_create(&complete_button))
             div .task-card-due { (due_str) }
+            (Raw::dangerously_create(&reschedule))
+            @if !agenda.is_empty() {
+                div .task-card-agenda { (agenda) }
+            }
+            @if !ends.is_empty() {
+                div .task-card-ends { (ends) }
+            }
         }
     }
     .render()
     .into_inner()
 }
 
-fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord], is_touch: bool) -> String {
-    let schedule_type_label = match task.schedule_kind {
-        ScheduleKind::NDays => format!("Every {} day(s)", task.n_days.days),
+/// Shown in place of the schedule for tasks with nothing selected
+const EMPTY_SCHEDULE_LABEL: &str = "No days selected — this task will never recur";
+
+/// Human-readable description of a task's schedule, e.g. "Every week on Mon, Thu"
+pub fn describe_schedule(task: &DemoTask) -> String {
+    if task.has_empty_schedule() {
+        return EMPTY_SCHEDULE_LABEL.to_string();
+    }
+    match task.schedule_kind {
+        ScheduleKind::NDays => match task.n_days.skip_weekends {
+            SkipWeekends::Off => format!("Every {} day(s)", task.n_days.days),
+            SkipWeekends::CalendarDays => format!("Every {} day(s), weekends moved to Monday", task.n_days.days),
+            SkipWeekends::WeekdaysOnly => format!("Every {} weekday(s)", task.n_days.days),
+        },
         ScheduleKind::NWeeks => {
             let days: Vec<&str> = [
                 ("Sun", task.n_weeks.sub_schedule.sunday),
@@ -1282,8 +2468,7 @@ fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord],
             }
         }
         ScheduleKind::Monthwise => {
-            let days_str = task.monthwise.days.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(", ");
-            format!("Monthly on day(s) {}", days_str)
+            format!("Monthly on day(s) {}", format_day_range(&task.monthwise.days))
         }
         ScheduleKind::WeeksOfMonth => {
             let weeks_str = task.weeks_of_month.weeks.iter().map(|w| {
@@ -1326,21 +2511,113 @@ fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord],
         ScheduleKind::Once => {
             let tz = get_timezone();
             let local_dt = task.once.datetime.with_timezone(&tz);
-            format!("Once on {}", local_dt.format("%b %d, %Y at %l:%M %p"))
+            if task.all_day {
+                format!("Once on {}", local_dt.format("%b %d, %Y"))
+            } else {
+                format!("Once on {}", local_dt.format("%b %d, %Y at %l:%M %p"))
+            }
         }
+    }
+}
+
+/// How many full days back the completion-rate insight looks
+const INSIGHT_WINDOW_DAYS: i64 = 90;
+
+/// With fewer expected occurrences than this there's too little to go on
+const INSIGHT_MIN_OCCURRENCES: i64 = 4;
+
+/// Below this completion rate the insight suggests a lighter schedule
+const INSIGHT_NUDGE_PERCENT: i64 = 60;
+
+/// Percentage of expected completions actually recorded over the last
+/// `INSIGHT_WINDOW_DAYS` full days (today excluded, since it may still be
+/// pending). None for one-time and non-completeable tasks, or when the window
+/// holds too few occurrences to be meaningful.
+fn completion_rate(task: &DemoTask, completions: &[db::CompletionRecord], today: chrono::NaiveDate) -> Option<i64> {
+    if !task.completeable || task.schedule_kind == ScheduleKind::Once {
+        return None;
+    }
+
+    let start = today - Duration::days(INSIGHT_WINDOW_DAYS);
+    let expected = start
+        .iter_days()
+        .take_while(|date| *date < today)
+        .filter(|date| is_due_on_date(task, *date))
+        .count() as i64;
+    if expected < INSIGHT_MIN_OCCURRENCES {
+        return None;
+    }
+
+    let tz = get_timezone();
+    let actual = completions
+        .iter()
+        .map(|c| c.completed_at.with_timezone(&tz).date_naive())
+        .filter(|date| *date >= start && *date < today)
+        .count() as i64;
+
+    Some((actual * 100 / (expected * task.target_count)).min(100))
+}
+
+fn render_completion_insight(rate: i64) -> String {
+    let nudge = if rate < INSIGHT_NUDGE_PERCENT {
+        " If it keeps slipping, a less frequent schedule might fit better."
+    } else {
+        ""
     };
+    maud! {
+        div .task-show-insight {
+            "Over the last " (INSIGHT_WINDOW_DAYS) " days you've completed this ~" (rate) "% of the time." (nudge)
+        }
+    }
+    .render()
+    .into_inner()
+}
 
-    let next_due_str = task.time_as_readable_string();
-    let calendar_html = render_calendar(task, completions);
-    let completions_html = render_completions_list(&task.id, completions);
+/// The task's page. For guests, the edit, template, delete and restore
+/// controls and the change history are left out.
+pub fn render_task_show_page(
+    task: &DemoTask,
+    completions: &[db::CompletionRecord],
+    audit_log: &[db::AuditEntry],
+    is_touch: bool,
+    view: View<'_>,
+) -> String {
+    let is_guest = view.is_guest();
+    let schedule_type_label = describe_schedule(task);
+    let empty_schedule = task.has_empty_schedule();
+
+    let next_due_str = if empty_schedule { "Never".to_string() } else { task.time_as_readable_string() };
+    let next_due_meta = task.next_due_rfc3339();
+    let calendar_html = render_calendar(task, completions, is_guest, crate::config::show_week_numbers());
+    let completions_html = render_completions_list(&task.id, completions, is_guest);
+    let audit_html = render_audit_log(task, audit_log);
+    let today = clock::now().with_timezone(&get_timezone()).date_naive();
+    let insight_html = completion_rate(task, completions, today)
+        .map(render_completion_insight)
+        .unwrap_or_default();
     let edit_url = format!("/tasks/{}/edit-modal", task.id);
     let is_inactive = task.is_inactive();
+    let occurrence_html = if task.completeable && !is_guest && !is_inactive && !empty_schedule && !is_demo_id(&task.id) {
+        render_current_occurrence(task, completions)
+    } else {
+        String::new()
+    };
+    let reschedule_html = if task.schedule_kind == ScheduleKind::Once && !is_guest && !is_inactive && !is_demo_id(&task.id) {
+        render_reschedule_form(task, true)
+    } else {
+        String::new()
+    };
 
     let edit_button = format!(
         r##"<button class="btn" hx-get="{}" hx-target="#modal-container" hx-swap="innerHTML">Edit</button>"##,
         edit_url
     );
 
+    let template_button = format!(
+        r##"<button class="btn" hx-post="/tasks/{}/save-template" hx-prompt="Template name" hx-target="#template-status" hx-swap="innerHTML">Save as Template</button>"##,
+        task.id
+    );
+
     // Delete or Restore button depending on inactive state
     let delete_restore_button = if is_inactive {
         format!(
@@ -1391,6 +2668,9 @@ fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord],
                 meta charset="utf-8";
                 meta name="viewport" content="width=device-width, initial-scale=1";
                 title { (task.name) " - Chores" }
+                @if let Some(next_due) = &next_due_meta {
+                    meta name="next-due" content=(next_due);
+                }
                 link rel="stylesheet" href="/static/system.css";
                 link rel="stylesheet" href="/static/app.css";
                 script src="/static/htmx.min.js" {}
@@ -1399,7 +2679,9 @@ fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord],
             body {
                 div .task-show-page id="task-show-page" {
                     div .task-show-header {
-                        @if is_touch {
+                        @if is_guest {
+                            a href=(view.home_url()) { "← Board" }
+                        } @else if is_touch {
                             button .btn onclick="window.location.href='/'" { "← Home" }
                             " "
                             button .btn onclick="window.location.href='/tasks'" { "Tasks" }
@@ -1412,15 +2694,22 @@ fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord],
 
                     div .task-show-title-row {
                         h1 { (task.name) }
-                        div .task-show-actions {
-                            (Raw::dangerously_create(&edit_button))
-                            " "
-                            (Raw::dangerously_create(&delete_restore_button))
+                        @if !is_guest {
+                            div .task-show-actions {
+                                (Raw::dangerously_create(&edit_button))
+                                " "
+                                (Raw::dangerously_create(&template_button))
+                                " "
+                                (Raw::dangerously_create(&delete_restore_button))
+                            }
                         }
                     }
+                    @if !is_guest {
+                        div .task-show-template-status id="template-status" {}
 
-                    (Raw::dangerously_create(&delete_modal))
-                    (Raw::dangerously_create(&restore_modal))
+                        (Raw::dangerously_create(&delete_modal))
+                        (Raw::dangerously_create(&restore_modal))
+                    }
 
                     @if !task.details.is_empty() {
                         div .task-show-details {
@@ -1428,19 +2717,50 @@ fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord],
                         }
                     }
 
+                    @if !task.instructions.is_empty() {
+                        details .task-show-instructions {
+                            summary { "Instructions" }
+                            p { (task.instructions) }
+                        }
+                    }
+
                     div .task-show-info {
                         div .task-show-info-row {
                             strong { "Schedule: " }
-                            span { (schedule_type_label) }
+                            @if empty_schedule {
+                                span .task-show-empty-schedule { (schedule_type_label) }
+                            } @else {
+                                span { (schedule_type_label) }
+                            }
                         }
                         div .task-show-info-row {
                             strong { "Next Due: " }
                             span { (next_due_str) }
                         }
+                        (Raw::dangerously_create(&occurrence_html))
+                        @if !reschedule_html.is_empty() {
+                            div .task-show-info-row {
+                                (Raw::dangerously_create(&reschedule_html))
+                            }
+                        }
                         div .task-show-info-row {
                             strong { "Alert Before: " }
                             span { (format_alerting_time(task.alerting_time)) }
                         }
+                        @if task.grace_minutes > 0 {
+                            div .task-show-info-row {
+                                strong { "Grace Period: " }
+                                span { (format_alerting_time(task.grace_minutes)) }
+                            }
+                        }
+                        @if task.is_counter() {
+                            div .task-show-info-row {
+                                strong { "Times per Occurrence: " }
+                                span { (task.target_count) }
+                            }
+                        }
+                        (Raw::dangerously_create(&insight_html))
+                        p .timezone-note { (timezone_note()) }
                     }
 
                     section .task-show-section {
@@ -1453,6 +2773,13 @@ fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord],
                         (Raw::dangerously_create(&completions_html))
                     }
 
+                    @if !audit_log.is_empty() && !is_guest {
+                        section .task-show-section {
+                            h2 { "Changes" }
+                            (Raw::dangerously_create(&audit_html))
+                        }
+                    }
+
                     // Modal container for edit
                     div #modal-container {}
                 }
@@ -1463,24 +2790,115 @@ fn render_task_show_page(task: &DemoTask, completions: &[db::CompletionRecord],
     .into_inner()
 }
 
-fn render_calendar(task: &DemoTask, completions: &[db::CompletionRecord]) -> String {
+/// The completions that count toward the task's current occurrence: the same
+/// window the dashboard uses to move a task under Completed
+fn current_occurrence_completions<'a>(
+    task: &DemoTask,
+    completions: &'a [db::CompletionRecord],
+) -> Vec<&'a db::CompletionRecord> {
+    let window = occurrence_window(task, task.most_recent_due_date());
+    completions.iter().filter(|c| completion_satisfies(c.completed_at, window)).collect()
+}
+
+/// Show page row saying whether the current occurrence is done, with a Complete
+/// button that asks first when it already is
+fn render_current_occurrence(task: &DemoTask, completions: &[db::CompletionRecord]) -> String {
+    let done = current_occurrence_completions(task, completions);
+    let already_done = occurrence_done(task, done.len() as i64);
+
+    let status = match done.iter().map(|c| c.completed_at).max() {
+        Some(latest) if already_done => {
+            let when = latest.with_timezone(&get_timezone()).format("%a %b %-d at %H:%M");
+            if task.is_counter() {
+                format!("Already completed for this occurrence ({} of {}, last {})", done.len(), task.target_count, when)
+            } else {
+                format!("Already completed for this occurrence ({})", when)
+            }
+        }
+        _ if task.is_counter() => format!("{} of {} done", done.len(), task.target_count),
+        _ => "Not done yet".to_string(),
+    };
+    let confirm = if already_done {
+        r#" onsubmit="return confirm('This occurrence is already done. Complete it again?')""#
+    } else {
+        ""
+    };
+
+    format!(
+        r#"<div class="task-show-info-row task-show-occurrence"><strong>This Occurrence: </strong><span class="{}">{}</span><form class="task-show-complete" method="post" action="/tasks/{}/complete"{}><input type="hidden" name="return" value="task"><button class="btn" type="submit">{}</button></form></div>"#,
+        if already_done { "task-show-already-done" } else { "task-show-not-done" },
+        html_escape(&status),
+        task.id,
+        confirm,
+        html_escape(task.completion_button_label())
+    )
+}
+
+/// One day of a task's month calendar, as both the HTML grid and the JSON API see it
+pub struct CalendarDay<'a> {
+    pub date: chrono::NaiveDate,
+    /// Local due time when the task has an occurrence on this day
+    pub due_time: Option<chrono::NaiveTime>,
+    /// When this day's occurrence falls due
+    pub due_at: Option<DateTime<Utc>>,
+    /// The completion that covers this day's occurrence, if any
+    pub completion: Option<&'a db::CompletionRecord>,
+    /// A completeable occurrence whose window closed without a completion
+    pub missed: bool,
+}
+
+/// Every day of the given month for a task, in the configured timezone.
+/// Returns None for an invalid year/month.
+pub fn calendar_month<'a>(
+    task: &DemoTask,
+    completions: &'a [db::CompletionRecord],
+    year: i32,
+    month: u32,
+) -> Option<Vec<CalendarDay<'a>>> {
+    let tz = get_timezone();
+    let first_of_month = chrono::NaiveDate::from_ymd_opt(year, month, 1)?;
+    let days = first_of_month
+        .iter_days()
+        .take_while(|date| date.month() == month)
+        .map(|date| {
+            if !is_due_on_date(task, date) {
+                return CalendarDay { date, due_time: None, due_at: None, completion: None, missed: false };
+            }
+            let time = get_due_time(task, date);
+            let due_at = tz
+                .from_local_datetime(&date.and_time(time))
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc));
+            let completion = due_at.and_then(|due| occurrence_completion(task, completions, due));
+            // Past due alone isn't missed: the occurrence can be completed until its window closes
+            let missed = task.completeable
+                && completion.is_none()
+                && due_at.is_some_and(|due| occurrence_window(task, due).1 <= clock::now());
+            CalendarDay {
+                date,
+                due_time: Some(time),
+                due_at,
+                completion,
+                missed,
+            }
+        })
+        .collect();
+    Some(days)
+}
+
+/// `read_only` leaves out the "+ Mark done" backfill buttons (guest view)
+fn render_calendar(task: &DemoTask, completions: &[db::CompletionRecord], read_only: bool, week_numbers: bool) -> String {
     use chrono::{Datelike, NaiveDate, Weekday};
 
     let tz = get_timezone();
-    let now = Utc::now().with_timezone(&tz);
+    let now = clock::now().with_timezone(&tz);
     let year = now.year();
     let month = now.month();
 
-    // Get first day of month and number of days
+    let Some(days) = calendar_month(task, completions, year, month) else {
+        return String::new();
+    };
     let first_of_month = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
-    let days_in_month = if month == 12 {
-        NaiveDate::from_ymd_opt(year + 1, 1, 1)
-    } else {
-        NaiveDate::from_ymd_opt(year, month + 1, 1)
-    }
-    .unwrap()
-    .signed_duration_since(first_of_month)
-    .num_days() as u32;
 
     let first_weekday = first_of_month.weekday();
     let start_offset = match first_weekday {
@@ -1509,22 +2927,25 @@ fn render_calendar(task: &DemoTask, completions: &[db::CompletionRecord]) -> Str
         _ => "",
     };
 
-    // Calculate due dates for this month
-    let mut due_dates: std::collections::HashMap<u32, chrono::NaiveTime> = std::collections::HashMap::new();
-
-    for day in 1..=days_in_month {
-        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
-        if is_due_on_date(task, date) {
-            let time = get_due_time(task, date);
-            due_dates.insert(day, time);
+    // Rows run Sunday to Saturday, so a row is numbered by the ISO week of its Monday
+    let grid_start = first_of_month - Duration::days(start_offset);
+    let open_row = |row: i64| {
+        let mut html = r#"<div class="calendar-row">"#.to_string();
+        if week_numbers {
+            let monday = grid_start + Duration::days(row * 7 + 1);
+            html.push_str(&format!(r#"<div class="calendar-week-number">{}</div>"#, monday.iso_week().week()));
         }
-    }
+        html
+    };
 
     // Build calendar grid
     let mut cells = String::new();
 
     // Header row
     cells.push_str(r#"<div class="calendar-header-row">"#);
+    if week_numbers {
+        cells.push_str(r#"<div class="calendar-header-cell calendar-week-number" title="ISO week">Wk</div>"#);
+    }
     for day_name in &["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"] {
         cells.push_str(&format!(r#"<div class="calendar-header-cell">{}</div>"#, day_name));
     }
@@ -1532,7 +2953,7 @@ fn render_calendar(task: &DemoTask, completions: &[db::CompletionRecord]) -> Str
 
     // Day cells
     let mut cell_count = 0;
-    cells.push_str(r#"<div class="calendar-row">"#);
+    cells.push_str(&open_row(0));
 
     // Empty cells before first day
     for _ in 0..start_offset {
@@ -1540,13 +2961,13 @@ fn render_calendar(task: &DemoTask, completions: &[db::CompletionRecord]) -> Str
         cell_count += 1;
     }
 
-    for day in 1..=days_in_month {
+    for calendar_day in &days {
         if cell_count > 0 && cell_count % 7 == 0 {
             cells.push_str("</div>");
-            cells.push_str(r#"<div class="calendar-row">"#);
+            cells.push_str(&open_row(cell_count / 7));
         }
 
-        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let date = calendar_day.date;
         let is_today = date == now.date_naive();
 
         let mut cell_class = "calendar-cell".to_string();
@@ -1554,33 +2975,45 @@ fn render_calendar(task: &DemoTask, completions: &[db::CompletionRecord]) -> Str
             cell_class.push_str(" calendar-cell-today");
         }
 
-        let mut content = format!(r#"<span class="calendar-day-number">{}</span>"#, day);
+        let mut content = format!(r#"<span class="calendar-day-number">{}</span>"#, date.day());
 
         // Check if due on this day
-        if let Some(time) = due_dates.get(&day) {
-            content.push_str(&format!(
-                r#"<div class="calendar-due">Due at {}</div>"#,
-                time.format("%H:%M")
-            ));
-
-            // Check if completed after this due date but before next due
-            let due_datetime = tz.from_local_datetime(&date.and_time(*time))
-                .unwrap()
-                .with_timezone(&Utc);
-
-            // Find next due date after this one
-            let next_due = find_next_due_after(task, due_datetime);
-
-            let completed_record = completions.iter().find(|c| {
-                c.completed_at > due_datetime && c.completed_at <= next_due
-            });
+        if let (Some(time), Some(due_datetime)) = (calendar_day.due_time, calendar_day.due_at) {
+            let grace = if task.grace_minutes > 0 {
+                format!(" (+{} grace)", format_duration_minutes(task.grace_minutes))
+            } else {
+                String::new()
+            };
+            let due = if task.all_day { "Due all day".to_string() } else { format!("Due at {}", time.format("%H:%M")) };
+            content.push_str(&format!(r#"<div class="calendar-due">{}{}</div>"#, due, grace));
+            if calendar_day.missed {
+                cell_class.push_str(" calendar-cell-missed");
+                content.push_str(r#"<div class="calendar-missed">Missed</div>"#);
+            }
 
-            if let Some(c) = completed_record {
+            if let Some(c) = calendar_day.completion {
                 let label = match &c.person_initials {
                     Some(initials) => format!("✓ Done by {}", html_escape(initials)),
                     None => "✓ Completed".to_string(),
                 };
-                content.push_str(&format!(r#"<div class="calendar-completed">{}</div>"#, label));
+                // Undo removes exactly the completion that covers this occurrence
+                let undo = if is_demo_id(&task.id) || read_only {
+                    String::new()
+                } else {
+                    format!(
+                        r##" <button class="calendar-undo-btn" hx-delete="/tasks/{}/completions/{}" hx-target="#task-show-page" hx-swap="outerHTML" hx-confirm="Mark this occurrence as not done?" title="Remove this completion">Undo</button>"##,
+                        html_escape(&task.id), c.id
+                    )
+                };
+                content.push_str(&format!(r#"<div class="calendar-completed">{}{}</div>"#, label, undo));
+            } else if task.completeable && due_datetime <= clock::now() && !is_demo_id(&task.id) && !read_only {
+                // Missed occurrence: offer to log it after the fact
+                let action = format!("/tasks/{}/complete-on", html_escape(&task.id));
+                content.push_str(&format!(
+                    r##"<form class="calendar-backfill" method="post" action="{action}" hx-post="{action}" hx-target="#task-show-page" hx-swap="outerHTML"><input type="hidden" name="date" value="{date}"><button class="calendar-backfill-btn" type="submit" title="Record this occurrence as done">+ Mark done</button></form>"##,
+                    action = action,
+                    date = date.format("%Y-%m-%d")
+                ));
             }
         }
 
@@ -1599,109 +3032,99 @@ fn render_calendar(task: &DemoTask, completions: &[db::CompletionRecord]) -> Str
     cells.push_str("</div>");
 
     let calendar_url = format!("/calendar/{}/{}", year, month);
+    let grid_class = if week_numbers { "calendar-grid calendar-grid-weeks" } else { "calendar-grid" };
     format!(
         r#"<div class="calendar">
             <div class="calendar-title"><a href="{}" class="calendar-title-link">{} {}</a></div>
-            <div class="calendar-grid">{}</div>
+            <div class="{}">{}</div>
         </div>"#,
-        calendar_url, month_name, year, cells
+        calendar_url, month_name, year, grid_class, cells
     )
 }
 
-fn is_due_on_date(task: &DemoTask, date: chrono::NaiveDate) -> bool {
-    use chrono::Datelike;
+/// Whether the task has an occurrence on the given local date
+pub fn is_due_on_date(task: &DemoTask, date: chrono::NaiveDate) -> bool {
+    engine::is_due_on_date(task, date, get_timezone(), clock::now())
+}
 
-    // Check if date is within created_at/deleted_at bounds
-    let tz = get_timezone();
-    if let Some(created_at) = task.created_at {
-        let created_date = created_at.with_timezone(&tz).date_naive();
-        if date < created_date {
-            return false;
-        }
-    }
-    if let Some(deleted_at) = task.deleted_at {
-        let deleted_date = deleted_at.with_timezone(&tz).date_naive();
-        if date > deleted_date {
-            return false;
-        }
-    }
+/// The task's next `count` due times after now, earliest first
+pub fn upcoming_occurrences(task: &DemoTask, count: usize) -> Vec<DateTime<Utc>> {
+    let now = clock::now();
+    engine::upcoming_after(task, now, count, get_timezone(), now)
+}
 
-    match task.schedule_kind {
-        ScheduleKind::NDays => {
-            // For NDays, calculate based on interval from today
-            // A task is due every N days, so we check if the date is N days apart from today
-            let today = Utc::now().with_timezone(&tz).date_naive();
-            let days_diff = (date - today).num_days().abs();
-            days_diff % (task.n_days.days as i64) == 0
-        }
-        ScheduleKind::NWeeks => {
-            let weekday = date.weekday();
-            if !task.n_weeks.sub_schedule.active(weekday) {
-                return false;
-            }
-            if task.n_weeks.weeks <= 1 {
-                return true;
-            }
-            let epoch = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
-            let days_since = (date - epoch).num_days();
-            let week_num = days_since.div_euclid(7);
-            week_num.rem_euclid(task.n_weeks.weeks as i64) == task.n_weeks.offset as i64
-        }
-        ScheduleKind::Monthwise => {
-            let day = date.day() as i32;
-            task.monthwise.days.contains(&day)
-        }
-        ScheduleKind::WeeksOfMonth => {
-            let weekday = date.weekday();
-            let week_num = ((date.day() - 1) / 7 + 1) as i32;
-            task.weeks_of_month.sub_schedule.active(weekday) && task.weeks_of_month.weeks.contains(&week_num)
-        }
-        ScheduleKind::CertainMonths => {
-            let month = date.month() as i32;
-            let day = date.day() as i32;
-            task.certain_months.months.contains(&month) && task.certain_months.days.contains(&day)
-        }
-        ScheduleKind::Once => {
-            let once_date = task.once.datetime.with_timezone(&tz).date_naive();
-            date == once_date
-        }
-    }
+/// Local time of day the task's occurrence on the given date is due
+pub fn get_due_time(task: &DemoTask, _date: chrono::NaiveDate) -> chrono::NaiveTime {
+    engine::due_time(task, get_timezone())
 }
 
-fn get_due_time(task: &DemoTask, _date: chrono::NaiveDate) -> chrono::NaiveTime {
-    match task.schedule_kind {
-        ScheduleKind::NDays => task.n_days.time,
-        ScheduleKind::NWeeks => task.n_weeks.sub_schedule.time,
-        ScheduleKind::Monthwise => task.monthwise.time,
-        ScheduleKind::WeeksOfMonth => task.weeks_of_month.sub_schedule.time,
-        ScheduleKind::CertainMonths => task.certain_months.time,
-        ScheduleKind::Once => {
-            let tz = get_timezone();
-            task.once.datetime.with_timezone(&tz).time()
-        }
-    }
+/// The span of time in which a completion counts toward the occurrence due at
+/// `due`: from the due time itself up to, but not including, the next occurrence.
+/// The dashboard, the calendar and backfilling all go through this.
+pub fn occurrence_window(task: &DemoTask, due: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    (due, engine::next_due_after(task, due, get_timezone(), clock::now()))
 }
 
-fn find_next_due_after(task: &DemoTask, after: DateTime<Utc>) -> DateTime<Utc> {
-    let tz = get_timezone();
-    let tz_after = after.with_timezone(&tz);
-
-    // Look ahead up to 60 days for the next due date
-    for days_ahead in 1..=60 {
-        let check_date = (tz_after + Duration::days(days_ahead)).date_naive();
-        if is_due_on_date(task, check_date) {
-            let time = get_due_time(task, check_date);
-            return tz.from_local_datetime(&check_date.and_time(time))
-                .unwrap()
-                .with_timezone(&Utc);
-        }
+/// Whether a completion at `completed_at` falls inside an occurrence window
+fn completion_satisfies(completed_at: DateTime<Utc>, window: (DateTime<Utc>, DateTime<Utc>)) -> bool {
+    let (start, end) = window;
+    completed_at >= start && completed_at < end
+}
+
+/// How many of `tasks` are completeable and have come due without their current
+/// occurrence being completed (for counters, without reaching the target).
+/// One query fetches the completions for every task's window.
+pub async fn count_overdue(pool: &DbPool, tasks: &[&DemoTask]) -> anyhow::Result<i64> {
+    let windows: Vec<_> = tasks
+        .iter()
+        .filter(|task| task.completeable && !task.is_inactive() && !task.has_empty_schedule() && task.is_due())
+        .map(|task| (*task, occurrence_window(task, task.most_recent_due_date())))
+        .collect();
+    let Some(since) = windows.iter().map(|(_, (start, _))| *start).min() else {
+        return Ok(0);
+    };
+
+    let mut completions: HashMap<String, Vec<DateTime<Utc>>> = HashMap::new();
+    for (task_id, completed_at) in db::get_completions_since(pool, since).await? {
+        completions.entry(task_id).or_default().push(completed_at);
     }
+    let overdue = windows.iter().filter(|(task, window)| {
+        let done = completions
+            .get(&task.id)
+            .map_or(0, |times| times.iter().filter(|at| completion_satisfies(**at, *window)).count() as i64);
+        !occurrence_done(task, done)
+    });
+    Ok(overdue.count() as i64)
+}
+
+/// Whether the occurrence due at `due` has been completed (for counters, reached
+/// its target), by the same window the dashboard uses. Reminders and completion
+/// links go through this too, so they agree with what the dashboard shows.
+pub async fn occurrence_completed(pool: &DbPool, task: &DemoTask, due: DateTime<Utc>) -> anyhow::Result<bool> {
+    let (start, end) = occurrence_window(task, due);
+    Ok(occurrence_done(task, db::count_completions_between(pool, &task.id, start, end).await?))
+}
+
+/// Whether an occurrence is done, given how many completions fall inside its
+/// window: a counter needs its target, anything else one. The dashboard, the
+/// task page, the overdue count and reminders all decide it here.
+fn occurrence_done(task: &DemoTask, completions_in_window: i64) -> bool {
+    let needed = if task.is_counter() { task.target_count } else { 1 };
+    completions_in_window >= needed
+}
 
-    // Default: 60 days from now
-    after + Duration::days(60)
+/// The completion that covers the occurrence due at `due`, if any
+fn occurrence_completion<'a>(
+    task: &DemoTask,
+    completions: &'a [db::CompletionRecord],
+    due: DateTime<Utc>,
+) -> Option<&'a db::CompletionRecord> {
+    let window = occurrence_window(task, due);
+    completions.iter().find(|c| completion_satisfies(c.completed_at, window))
 }
 
-fn render_completions_list(task_id: &str, completions: &[db::CompletionRecord]) -> String {
+/// `read_only` leaves out the delete buttons (guest view)
+fn render_completions_list(task_id: &str, completions: &[db::CompletionRecord], read_only: bool) -> String {
     if completions.is_empty() {
         return maud! {
             div .completions-empty {
@@ -1713,23 +3136,40 @@ fn render_completions_list(task_id: &str, completions: &[db::CompletionRecord])
     }
 
     let tz = get_timezone();
+    let time_format = get_time_format();
     let items: Vec<String> = completions
         .iter()
         .map(|c| {
-            let tz_time = c.completed_at.with_timezone(&tz);
-            let formatted = tz_time.format("%A, %B %-d, %Y at %H:%M").to_string();
+            let formatted = format_completion_time(c.completed_at, &tz, time_format);
             let delete_url = format!("/tasks/{}/completions/{}", task_id, c.id);
             let by_str = match &c.person_initials {
                 Some(initials) => format!(" — {}", html_escape(initials)),
                 None => String::new(),
             };
+            let duration_str = match c.duration_minutes {
+                Some(minutes) => format!(" ({})", format_duration_minutes(minutes)),
+                None => String::new(),
+            };
+            let note_html = match &c.note {
+                Some(note) => format!(r#"<span class="completion-note">{}</span>"#, html_escape(note)),
+                None => String::new(),
+            };
+
+            let delete_button = if read_only {
+                String::new()
+            } else {
+                format!(
+                    r##"<button class="btn completion-delete" hx-delete="{}" hx-target="#task-show-page" hx-swap="outerHTML" hx-confirm="Delete this completion?">×</button>"##,
+                    delete_url
+                )
+            };
 
             format!(
                 r##"<li class="completion-item">
-                    <span class="completion-date">{}{}</span>
-                    <button class="btn completion-delete" hx-delete="{}" hx-target="#task-show-page" hx-swap="outerHTML" hx-confirm="Delete this completion?">×</button>
+                    <span class="completion-date">{}{}{}{}</span>
+                    {}
                 </li>"##,
-                formatted, by_str, delete_url
+                formatted, by_str, duration_str, note_html, delete_button
             )
         })
         .collect();
@@ -1743,32 +3183,85 @@ fn render_completions_list(task_id: &str, completions: &[db::CompletionRecord])
     .into_inner()
 }
 
-#[derive(Deserialize)]
-pub struct ListQuery {
-    #[serde(default = "default_sort")]
-    pub sort: String,
-    #[serde(default = "default_page")]
-    pub page: i64,
-    #[serde(default = "default_per_page")]
-    pub per_page: i64,
+/// Short label for an audit entry's action, e.g. "Edited"
+fn audit_action_label(action: &str) -> String {
+    match action {
+        "create" => "Created".to_string(),
+        "edit" => "Edited".to_string(),
+        "complete" => "Completed".to_string(),
+        "snooze" => "Snoozed".to_string(),
+        "delete" => "Deleted".to_string(),
+        "restore" => "Restored".to_string(),
+        "revert" => "Reverted".to_string(),
+        other => html_escape(other),
+    }
 }
 
-fn default_sort() -> String {
-    "name".to_string()
+/// The task's change history. Entries whose saved schedule differs from the
+/// current one can be reverted to.
+fn render_audit_log(task: &DemoTask, entries: &[db::AuditEntry]) -> String {
+    let tz = get_timezone();
+    let current = db::schedule_snapshot(task);
+    let items: String = entries
+        .iter()
+        .map(|e| {
+            let revert = match &e.schedule_snapshot {
+                Some(snapshot) if *snapshot != current => format!(
+                    r##"<button class="btn audit-revert-btn" hx-post="/tasks/{}/revert/{}" hx-target="#task-show-page" hx-swap="outerHTML" hx-confirm="Put the schedule back the way it was after this change?">Revert to this version</button>"##,
+                    task.id, e.id
+                ),
+                _ => String::new(),
+            };
+            format!(
+                r#"<li class="completion-item audit-item"><span class="audit-action audit-action-{}">{}</span><span class="completion-date">{} — {}</span>{}</li>"#,
+                html_escape(&e.action),
+                audit_action_label(&e.action),
+                e.created_at.with_timezone(&tz).format("%b %-d, %Y %H:%M"),
+                html_escape(&e.summary),
+                revert
+            )
+        })
+        .collect();
+    format!(r#"<ul class="completions-list audit-list">{}</ul>"#, items)
 }
 
-fn default_page() -> i64 {
-    1
+/// Format a completion timestamp in the given timezone, including the zone abbreviation
+/// (e.g. "Thursday, January 15, 2026 at 09:04 CST").
+fn format_completion_time(completed_at: DateTime<Utc>, tz: &chrono_tz::Tz, time_format: TimeFormat) -> String {
+    let tz_time = completed_at.with_timezone(tz);
+    let pattern = format!("%A, %B %-d, %Y at {} %Z", time_format.strftime());
+    tz_time.format(&pattern).to_string()
 }
 
-fn default_per_page() -> i64 {
-    10
-}
+// GET /stats - Time spent on chores, per task and overall
+pub async fn stats_page(State(pool): State<DbPool>, headers: HeaderMap) -> Html<String> {
+    use chrono::NaiveDate;
 
-// GET /tasks - Show the task index page
-async fn tasks_index(State(pool): State<DbPool>, Query(query): Query<ListQuery>, headers: HeaderMap) -> Html<String> {
     let is_touch = settings::is_touch_mode(&headers);
-    let list_html = render_task_list(&pool, &query.sort, query.page, query.per_page, is_touch).await;
+    let tz = get_timezone();
+    let today = clock::now().with_timezone(&tz).date_naive();
+    let month_start = NaiveDate::from_ymd_opt(today.year(), today.month(), 1)
+        .and_then(|d| tz.from_local_datetime(&d.and_time(NaiveTime::MIN)).earliest())
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now);
+
+    let totals = db::get_duration_totals(&pool, month_start).await.unwrap_or_default();
+    let overall_minutes: i64 = totals.iter().map(|t| t.total_minutes).sum();
+    let month_minutes: i64 = totals.iter().map(|t| t.recent_minutes).sum();
+
+    let rows_html: String = totals
+        .iter()
+        .map(|t| {
+            format!(
+                r#"<tr><td><a href="/tasks/{}">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>"#,
+                html_escape(&t.task_id),
+                html_escape(t.task_name.as_deref().unwrap_or("(deleted task)")),
+                format_duration_minutes(t.recent_minutes),
+                format_duration_minutes(t.total_minutes),
+                t.timed_completions
+            )
+        })
+        .collect();
 
     let html = maud! {
         !DOCTYPE
@@ -1776,44 +3269,127 @@ async fn tasks_index(State(pool): State<DbPool>, Query(query): Query<ListQuery>,
             head {
                 meta charset="utf-8";
                 meta name="viewport" content="width=device-width, initial-scale=1";
-                title { "Tasks - Chores" }
+                title { "Stats - Chores" }
                 link rel="stylesheet" href="/static/system.css";
                 link rel="stylesheet" href="/static/app.css";
-                script src="/static/htmx.min.js" {}
                 script src="/static/auto-sleep.js" {}
             }
             body {
-                div .tasks-page {
-                    div .tasks-page-header {
-                        @if is_touch {
-                            button .btn onclick="window.location.href='/'" { "← Home" }
-                        } @else {
-                            a href="/" { "← Home" }
+                div .stats-page {
+                    div .page-header {
+                        h1 { "Stats" }
+                        div .page-header-buttons {
+                            @if is_touch {
+                                button .btn onclick="window.location.href='/'" { "Home" }
+                            } @else {
+                                a .btn href="/" { "Home" }
+                            }
                         }
                     }
 
-                    h1 { "Tasks" }
-
-                    // Sorting and pagination controls
-                    div .list-controls {
-                        div .list-controls-left {
-                            label for="sort-select" { "Sort by: " }
-                            (Raw::dangerously_create(&render_sort_select(&query.sort)))
-                            label for="per-page-select" { "Per page: " }
-                            (Raw::dangerously_create(&render_per_page_select(query.per_page)))
+                    @if totals.is_empty() {
+                        div .daily-empty {
+                            p { "No time recorded yet. Enter minutes when completing a task to track time spent." }
+                        }
+                    } @else {
+                        div .stats-summary {
+                            p { strong { (format_duration_minutes(month_minutes)) } " this month" }
+                            p { strong { (format_duration_minutes(overall_minutes)) } " all time" }
+                        }
+                        table .stats-table {
+                            thead {
+                                tr {
+                                    th { "Task" }
+                                    th { "This month" }
+                                    th { "All time" }
+                                    th { "Timed completions" }
+                                }
+                            }
+                            tbody {
+                                (Raw::dangerously_create(&rows_html))
+                            }
                         }
-                        (Raw::dangerously_create(
-                            r##"<button class="btn" hx-get="/tasks/new" hx-target="#modal-container" hx-swap="innerHTML">New Task</button>"##
-                        ))
                     }
+                }
+            }
+        }
+    };
 
-                    // Task list container
-                    div #task-list {
-                        (Raw::dangerously_create(&list_html))
+    Html(html.render().into_inner())
+}
+
+// GET /trash - Soft-deleted tasks, with restore and permanent delete
+pub async fn trash_page(State(pool): State<DbPool>, headers: HeaderMap) -> Html<String> {
+    let is_touch = settings::is_touch_mode(&headers);
+    let tz = get_timezone();
+    let tasks = db::get_deleted_tasks(&pool).await.unwrap_or_default();
+
+    let rows_html: String = tasks
+        .iter()
+        .map(|t| {
+            let deleted = t
+                .deleted_at
+                .map(|dt| dt.with_timezone(&tz).format("%b %-d, %Y").to_string())
+                .unwrap_or_default();
+            format!(
+                r#"<tr><td><a href="/tasks/{id}">{name}</a></td><td>{deleted}</td><td class="trash-actions"><form method="post" action="/trash/{id}/restore"><button class="btn" type="submit">Restore</button></form><form method="post" action="/trash/{id}/delete" onsubmit="return confirm('Permanently delete this task and its history?')"><button class="btn" type="submit">Delete Forever</button></form></td></tr>"#,
+                id = html_escape(&t.id),
+                name = html_escape(&t.name),
+                deleted = deleted
+            )
+        })
+        .collect();
+
+    let html = maud! {
+        !DOCTYPE
+        html {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "Trash - Chores" }
+                link rel="stylesheet" href="/static/system.css";
+                link rel="stylesheet" href="/static/app.css";
+                script src="/static/auto-sleep.js" {}
+            }
+            body {
+                div .trash-page {
+                    div .page-header {
+                        h1 { "Trash" }
+                        div .page-header-buttons {
+                            @if is_touch {
+                                button .btn onclick="window.location.href='/tasks'" { "Tasks" }
+                            } @else {
+                                a .btn href="/tasks" { "Tasks" }
+                            }
+                        }
                     }
 
-                    // Modal container (initially empty)
-                    div #modal-container {}
+                    @if tasks.is_empty() {
+                        div .daily-empty {
+                            p { "Trash is empty." }
+                        }
+                    } @else {
+                        div .trash-bulk-actions {
+                            form method="post" action="/trash/restore-all" {
+                                button .btn type="submit" { "Restore All" }
+                            }
+                            form method="post" action="/trash/empty" onsubmit="return confirm('Permanently delete every task in the trash, with their history?')" {
+                                button .btn type="submit" { "Empty Trash" }
+                            }
+                        }
+                        table .trash-table {
+                            thead {
+                                tr {
+                                    th { "Task" }
+                                    th { "Deleted" }
+                                    th {}
+                                }
+                            }
+                            tbody {
+                                (Raw::dangerously_create(&rows_html))
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -1822,67 +3398,559 @@ async fn tasks_index(State(pool): State<DbPool>, Query(query): Query<ListQuery>,
     Html(html.render().into_inner())
 }
 
-// GET /tasks/list - Return just the task list (for HTMX)
-async fn tasks_list(State(pool): State<DbPool>, Query(query): Query<ListQuery>, headers: HeaderMap) -> Html<String> {
-    let is_touch = settings::is_touch_mode(&headers);
-    Html(render_task_list(&pool, &query.sort, query.page, query.per_page, is_touch).await)
+// POST /trash/:id/restore - Restore one task from the trash
+pub async fn trash_restore(State(pool): State<DbPool>, Path(id): Path<i64>) -> Redirect {
+    match db::set_task_deleted_at(&pool, id, None).await {
+        Ok(_) => {
+            info!(task_id = %id, "Task restored from trash");
+            record_audit(&pool, &id.to_string(), "restore", "restored").await;
+        }
+        Err(e) => error!(task_id = %id, error = %e, "Error restoring task"),
+    }
+    Redirect::to("/trash")
 }
 
-// GET /tasks/:id/edit - Get edit view for a single task (standalone, from saved state)
-async fn task_edit(State(pool): State<DbPool>, Path(id): Path<String>) -> Html<String> {
-    if is_demo_id(&id) {
-        let tasks = get_demo_tasks();
-        let tasks_guard = tasks.lock().unwrap();
-        if let Some(task) = tasks_guard.get(&id) {
-            return Html(render_task_editor(task));
-        }
-    } else {
-        if let Ok(task_id) = id.parse::<i64>() {
-            if let Ok(Some(task)) = db::get_task(&pool, task_id).await {
-                return Html(render_task_editor(&task));
+// POST /trash/:id/delete - Permanently delete one task from the trash
+pub async fn trash_delete(State(pool): State<DbPool>, Path(id): Path<i64>) -> Redirect {
+    // Only tasks already in the trash can be removed for good
+    if let Ok(Some(task)) = db::get_task(&pool, id).await
+        && task.is_deleted()
+    {
+        match db::hard_delete_task(&pool, id).await {
+            Ok(_) => {
+                info!(task_id = %id, name = %task.name, "Task permanently deleted");
+                events::notify_changed();
             }
+            Err(e) => error!(task_id = %id, error = %e, "Error permanently deleting task"),
         }
     }
+    Redirect::to("/trash")
+}
 
-    Html(format!(
-        "<div class=\"window\"><div class=\"window-pane\">Task '{}' not found</div></div>",
-        id
-    ))
+// POST /trash/restore-all - Restore every task in the trash
+pub async fn trash_restore_all(State(pool): State<DbPool>) -> Redirect {
+    match db::restore_all_deleted_tasks(&pool).await {
+        Ok(ids) => {
+            info!(count = ids.len(), "Restored all tasks from trash");
+            for id in ids {
+                record_audit(&pool, &id.to_string(), "restore", "restored").await;
+            }
+        }
+        Err(e) => error!(error = %e, "Error restoring tasks from trash"),
+    }
+    Redirect::to("/trash")
 }
 
-// GET /tasks/:id/edit-modal - Get edit view as a modal
-async fn task_edit_modal(State(pool): State<DbPool>, Path(id): Path<String>) -> Html<String> {
-    if is_demo_id(&id) {
-        let tasks = get_demo_tasks();
-        let tasks_guard = tasks.lock().unwrap();
-        if let Some(task) = tasks_guard.get(&id) {
-            return Html(render_task_modal(task));
+// POST /trash/empty - Permanently delete every task in the trash
+pub async fn trash_empty(State(pool): State<DbPool>) -> Redirect {
+    let tasks = db::get_deleted_tasks(&pool).await.unwrap_or_default();
+    for task in &tasks {
+        let Ok(id) = task.id.parse::<i64>() else { continue };
+        if let Err(e) = db::hard_delete_task(&pool, id).await {
+            error!(task_id = %id, error = %e, "Error permanently deleting task");
         }
+    }
+    info!(count = tasks.len(), "Emptied trash");
+    events::notify_changed();
+    Redirect::to("/trash")
+}
+
+/// Number of completions per page on the history timeline
+const HISTORY_PER_PAGE: i64 = 50;
+
+// Query params for history pagination
+#[derive(Deserialize)]
+pub struct HistoryQuery {
+    #[serde(default = "default_page")]
+    page: i64,
+}
+
+/// Heading for a day in the history timeline: "Today", "Yesterday", or the full date.
+fn history_day_label(date: chrono::NaiveDate, today: chrono::NaiveDate) -> String {
+    if date == today {
+        "Today".to_string()
+    } else if date == today - Duration::days(1) {
+        "Yesterday".to_string()
     } else {
-        if let Ok(task_id) = id.parse::<i64>() {
-            if let Ok(Some(task)) = db::get_task(&pool, task_id).await {
-                return Html(render_task_modal(&task));
+        date.format("%A, %B %-d, %Y").to_string()
+    }
+}
+
+// GET /history - Reverse-chronological timeline of completions across all tasks
+pub async fn history_page(State(pool): State<DbPool>, Query(query): Query<HistoryQuery>, headers: HeaderMap) -> Html<String> {
+    let is_touch = settings::is_touch_mode(&headers);
+    let total_count = db::get_completion_count(&pool).await.unwrap_or(0);
+    let total_pages = ((total_count + HISTORY_PER_PAGE - 1) / HISTORY_PER_PAGE).max(1);
+    let page = query.page.clamp(1, total_pages);
+    let entries = db::get_recent_completions(&pool, (page - 1) * HISTORY_PER_PAGE, HISTORY_PER_PAGE)
+        .await
+        .unwrap_or_default();
+
+    let tz = get_timezone();
+    let today = clock::now().with_timezone(&tz).date_naive();
+
+    // Group entries under a heading per local day
+    let mut timeline_html = String::new();
+    let mut current_day: Option<chrono::NaiveDate> = None;
+    for entry in &entries {
+        let local = entry.completed_at.with_timezone(&tz);
+        let day = local.date_naive();
+        if current_day != Some(day) {
+            if current_day.is_some() {
+                timeline_html.push_str("</ul>");
             }
+            timeline_html.push_str(&format!(
+                r#"<h2 class="history-day">{}</h2><ul class="completions-list history-list">"#,
+                history_day_label(day, today)
+            ));
+            current_day = Some(day);
         }
+
+        let name = entry.task_name.as_deref().unwrap_or("(deleted task)");
+        let by_str = match &entry.person_initials {
+            Some(initials) => format!(" — {}", html_escape(initials)),
+            None => String::new(),
+        };
+        timeline_html.push_str(&format!(
+            r#"<li class="completion-item"><span class="daily-event-time">{}</span><span class="completion-date"><a class="daily-event-name" href="/tasks/{}">{}</a> completed{}</span></li>"#,
+            local.format(get_time_format().strftime()),
+            html_escape(&entry.task_id),
+            html_escape(name),
+            by_str
+        ));
+    }
+    if current_day.is_some() {
+        timeline_html.push_str("</ul>");
     }
 
-    Html(format!(
-        "<div class=\"modal-overlay\"><div class=\"window\"><div class=\"window-pane\">Task '{}' not found</div></div></div>",
-        id
-    ))
+    let newer_url = format!("/history?page={}", page - 1);
+    let older_url = format!("/history?page={}", page + 1);
+
+    let html = maud! {
+        !DOCTYPE
+        html {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "History - Chores" }
+                link rel="stylesheet" href="/static/system.css";
+                link rel="stylesheet" href="/static/app.css";
+                script src="/static/auto-sleep.js" {}
+            }
+            body {
+                div .history-page {
+                    div .page-header {
+                        h1 { "History" }
+                        div .page-header-buttons {
+                            @if is_touch {
+                                button .btn onclick="window.location.href='/'" { "Home" }
+                            } @else {
+                                a .btn href="/" { "Home" }
+                            }
+                        }
+                    }
+
+                    @if entries.is_empty() {
+                        div .daily-empty {
+                            p { "No completions recorded yet." }
+                        }
+                    } @else {
+                        (Raw::dangerously_create(&timeline_html))
+                    }
+
+                    @if total_pages > 1 {
+                        div .history-pagination {
+                            @if page > 1 {
+                                @if is_touch {
+                                    button .btn onclick=(format!("window.location.href='{}'", newer_url)) { "← Newer" }
+                                } @else {
+                                    a href=(newer_url) { "← Newer" }
+                                }
+                            }
+                            span .pagination-info { "Page " (page) " of " (total_pages) }
+                            @if page < total_pages {
+                                @if is_touch {
+                                    button .btn onclick=(format!("window.location.href='{}'", older_url)) { "Older →" }
+                                } @else {
+                                    a href=(older_url) { "Older →" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    Html(html.render().into_inner())
 }
 
-// Form data for the full task
-#[derive(Deserialize, Debug, Default)]
-pub struct TaskForm {
-    pub name: String,
-    pub details: String,
+#[derive(Deserialize)]
+pub struct ListQuery {
+    #[serde(default = "default_sort")]
+    pub sort: String,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_per_page")]
+    pub per_page: i64,
+}
+
+fn default_sort() -> String {
+    "name".to_string()
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_per_page() -> i64 {
+    10
+}
+
+// POST /tasks/:id/save-template - Save the task's schedule and settings as a named template
+async fn save_as_template(State(pool): State<DbPool>, Path(id): Path<String>, headers: HeaderMap) -> Html<String> {
+    let task = if is_demo_id(&id) {
+        let tasks = get_demo_tasks();
+        let tasks_guard = tasks.lock().unwrap();
+        tasks_guard.get(&id).cloned()
+    } else if let Ok(task_id) = id.parse::<i64>() {
+        db::get_task(&pool, task_id).await.ok().flatten()
+    } else {
+        None
+    };
+
+    let Some(task) = task else {
+        return Html("Task not found".to_string());
+    };
+
+    // hx-prompt sends the entered name in the HX-Prompt header (URL-encoded by some browsers)
+    let name = headers
+        .get("HX-Prompt")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| urlencoding::decode(v).map(|d| d.into_owned()).unwrap_or_else(|_| v.to_string()))
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| task.name.clone());
+
+    match db::save_template(&pool, &name, &task).await {
+        Ok(template_id) => {
+            info!(task_id = %id, template_id = template_id, name = %name, "Template saved");
+            Html(format!("Saved as template \"{}\"", html_escape(&name)))
+        }
+        Err(e) => {
+            error!(task_id = %id, error = %e, "Error saving template");
+            Html("Could not save template".to_string())
+        }
+    }
+}
+
+// GET /tasks/templates - List saved templates
+async fn templates_index(State(pool): State<DbPool>, headers: HeaderMap) -> Html<String> {
+    let is_touch = settings::is_touch_mode(&headers);
+    let templates = db::get_all_templates(&pool).await.unwrap_or_default();
+
+    let items_html: String = templates
+        .iter()
+        .map(|t| {
+            format!(
+                r##"<li class="completion-item"><span class="completion-date">{}</span><form method="post" action="/tasks/templates/{}/delete" style="display:inline"><button class="btn completion-delete" type="submit">×</button></form></li>"##,
+                html_escape(&t.name),
+                t.id
+            )
+        })
+        .collect();
+
+    let html = maud! {
+        !DOCTYPE
+        html {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "Templates - Chores" }
+                link rel="stylesheet" href="/static/system.css";
+                link rel="stylesheet" href="/static/app.css";
+                script src="/static/auto-sleep.js" {}
+            }
+            body {
+                div .tasks-page {
+                    div .tasks-page-header {
+                        @if is_touch {
+                            button .btn onclick="window.location.href='/tasks'" { "← Tasks" }
+                        } @else {
+                            a href="/tasks" { "← Tasks" }
+                        }
+                    }
+
+                    h1 { "Templates" }
+
+                    @if templates.is_empty() {
+                        p .form-help { "No templates yet. Use \"Save as Template\" on a task's page to create one." }
+                    } @else {
+                        ul .completions-list {
+                            (Raw::dangerously_create(&items_html))
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    Html(html.render().into_inner())
+}
+
+// POST /tasks/templates/:template_id/delete - Delete a template
+async fn delete_template(State(pool): State<DbPool>, Path(template_id): Path<i64>) -> Redirect {
+    match db::delete_template(&pool, template_id).await {
+        Ok(_) => info!(template_id = template_id, "Template deleted"),
+        Err(e) => error!(template_id = template_id, error = %e, "Error deleting template"),
+    }
+    Redirect::to("/tasks/templates")
+}
+
+// GET /tasks - Show the task index page
+async fn tasks_index(State(pool): State<DbPool>, Query(query): Query<ListQuery>, headers: HeaderMap) -> Html<String> {
+    let is_touch = settings::is_touch_mode(&headers);
+    let list_html = render_task_list(&pool, &query.sort, query.page, query.per_page, is_touch).await;
+    let total_count = db::get_task_count(&pool).await.unwrap_or(0);
+
+    let html = maud! {
+        !DOCTYPE
+        html {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "Tasks - Chores" }
+                link rel="stylesheet" href="/static/system.css";
+                link rel="stylesheet" href="/static/app.css";
+                script src="/static/htmx.min.js" {}
+                script src="/static/auto-sleep.js" {}
+            }
+            body {
+                div .tasks-page {
+                    div .tasks-page-header {
+                        @if is_touch {
+                            button .btn onclick="window.location.href='/dashboard'" { "← Dashboard" }
+                            " "
+                            button .btn onclick="window.location.href='/tasks/templates'" { "Templates" }
+                            " "
+                            button .btn onclick="window.location.href='/import'" { "Import" }
+                            " "
+                            button .btn onclick="window.location.href='/trash'" { "Trash" }
+                        } @else {
+                            a href="/dashboard" { "← Dashboard" }
+                            " | "
+                            a href="/tasks/templates" { "Templates" }
+                            " | "
+                            a href="/import" { "Import" }
+                            " | "
+                            a href="/trash" { "Trash" }
+                        }
+                    }
+
+                    h1 { "Tasks" }
+
+                    // Sorting and pagination controls
+                    div .list-controls {
+                        div .list-controls-left {
+                            label for="sort-select" { "Sort by: " }
+                            (Raw::dangerously_create(&render_sort_select(&query.sort)))
+                            label for="per-page-select" { "Per page: " }
+                            (Raw::dangerously_create(&render_per_page_select(query.per_page)))
+                        }
+                        (Raw::dangerously_create(
+                            r##"<a class="btn" href="/tasks/new" hx-get="/tasks/new" hx-target="#modal-container" hx-swap="innerHTML">New Task</a>"##
+                        ))
+                    }
+
+                    // Bulk actions for the tasks ticked in the list below
+                    (Raw::dangerously_create(&render_bulk_alerting_form(total_count)))
+
+                    // Task list container
+                    div #task-list {
+                        (Raw::dangerously_create(&list_html))
+                    }
+
+                    // Modal container (initially empty)
+                    div #modal-container {}
+                }
+                (Raw::dangerously_create(&pages::render_bottom_nav(is_touch, pages::NavItem::Tasks)))
+            }
+        }
+    };
+
+    Html(html.render().into_inner())
+}
+
+/// Task IDs ticked on the tasks index; the checkboxes all post as `task_ids`
+fn selected_task_ids(fields: &[(String, String)]) -> Vec<i64> {
+    fields
+        .iter()
+        .filter(|(key, _)| key == "task_ids")
+        .filter_map(|(_, value)| value.parse().ok())
+        .collect()
+}
+
+/// Whether a form checkbox or button with this name was sent
+fn has_field(fields: &[(String, String)], name: &str) -> bool {
+    fields.iter().any(|(key, _)| key == name)
+}
+
+/// Asks before a "select all" bulk action goes ahead. The button resubmits
+/// the bulk form with `confirm_all` set.
+fn render_bulk_confirm(count: usize) -> String {
+    format!(
+        r#"<span class="bulk-status">This changes all {count} tasks, not just this page.</span> <button class="btn" type="submit" name="confirm_all" value="1">Apply to all {count}</button>"#
+    )
+}
+
+/// The "all tasks" confirmation as a page of its own, for browsers without htmx
+fn render_bulk_confirm_page(count: usize, alerting_time: i64, is_touch: bool) -> String {
+    let body = maud! {
+        div .window {
+            div .title-bar {
+                h1 .title { "Change every task?" }
+            }
+            div .window-pane {
+                form .bulk-actions method="post" action="/tasks/alerting/batch" {
+                    input type="hidden" name="all_matching" value="1";
+                    input type="hidden" name="alerting_time" value=(alerting_time);
+                    (Raw::dangerously_create(&render_bulk_confirm(count)))
+                    " "
+                    a .btn href="/tasks" { "Cancel" }
+                }
+            }
+        }
+    }
+    .render()
+    .into_inner();
+    pages::render_page("Change alert times", &body, is_touch, pages::NavItem::Tasks)
+}
+
+// POST /tasks/alerting/batch - Set one alert time on every selected task, all or nothing.
+// With "all_matching" it applies to every task on the index instead, once confirmed.
+async fn batch_alerting_time(
+    State(pool): State<DbPool>,
+    headers: HeaderMap,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, AppError> {
+    let all_matching = has_field(&fields, "all_matching");
+    // Re-run the index's query rather than trusting the ticked boxes on one page
+    let task_ids = if all_matching {
+        db::get_all_task_ids(&pool).await?
+    } else {
+        selected_task_ids(&fields)
+    };
+    let alerting_time = fields
+        .iter()
+        .find(|(key, _)| key == "alerting_time")
+        .and_then(|(_, value)| value.parse::<i64>().ok())
+        .filter(|minutes| *minutes >= 0);
+
+    let message = match alerting_time {
+        None => "Pick an alert time first.".to_string(),
+        Some(_) if task_ids.is_empty() => "Select at least one task first.".to_string(),
+        Some(minutes) if all_matching && !has_field(&fields, "confirm_all") => {
+            if !is_htmx_request(&headers) {
+                let is_touch = settings::is_touch_mode(&headers);
+                return Ok(Html(render_bulk_confirm_page(task_ids.len(), minutes, is_touch)).into_response());
+            }
+            return Ok(Html(render_bulk_confirm(task_ids.len())).into_response());
+        }
+        Some(minutes) => {
+            // Only tasks whose alert time actually changes are touched, so the audit log stays meaningful
+            match db::set_alerting_time_batch(&pool, &task_ids, minutes).await {
+                Ok(changed) => {
+                    info!(count = changed.len(), alerting_time = minutes, "Alert time updated in bulk");
+                    for (task_id, previous) in &changed {
+                        record_audit(&pool, &task_id.to_string(), "edit", &describe_alerting_change(*previous, minutes)).await;
+                    }
+                    match changed.len() {
+                        1 => "Updated the alert time on 1 task.".to_string(),
+                        count => format!("Updated the alert time on {} tasks.", count),
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "Error updating alert times in bulk");
+                    "Could not update alert times. Nothing was changed.".to_string()
+                }
+            }
+        }
+    };
+
+    if !is_htmx_request(&headers) {
+        return Ok(Redirect::to("/tasks").into_response());
+    }
+    Ok(Html(format!(r#"<span class="bulk-status">{}</span>"#, html_escape(&message))).into_response())
+}
+
+// GET /tasks/list - Return just the task list (for HTMX)
+async fn tasks_list(State(pool): State<DbPool>, Query(query): Query<ListQuery>, headers: HeaderMap) -> Html<String> {
+    let is_touch = settings::is_touch_mode(&headers);
+    Html(render_task_list(&pool, &query.sort, query.page, query.per_page, is_touch).await)
+}
+
+// GET /tasks/:id/edit - Get edit view for a single task (standalone, from saved state)
+async fn task_edit(State(pool): State<DbPool>, Path(id): Path<String>) -> Html<String> {
+    if is_demo_id(&id) {
+        let tasks = get_demo_tasks();
+        let tasks_guard = tasks.lock().unwrap();
+        if let Some(task) = tasks_guard.get(&id) {
+            return Html(render_task_editor(task));
+        }
+    } else {
+        if let Ok(task_id) = id.parse::<i64>() {
+            if let Ok(Some(task)) = db::get_task(&pool, task_id).await {
+                return Html(render_task_editor(&task));
+            }
+        }
+    }
+
+    Html(format!(
+        "<div class=\"window\"><div class=\"window-pane\">Task '{}' not found</div></div>",
+        id
+    ))
+}
+
+// GET /tasks/:id/edit-modal - Get edit view as a modal
+async fn task_edit_modal(State(pool): State<DbPool>, Path(id): Path<String>) -> Html<String> {
+    if is_demo_id(&id) {
+        let tasks = get_demo_tasks();
+        let tasks_guard = tasks.lock().unwrap();
+        if let Some(task) = tasks_guard.get(&id) {
+            return Html(render_task_modal(task));
+        }
+    } else {
+        if let Ok(task_id) = id.parse::<i64>() {
+            if let Ok(Some(task)) = db::get_task(&pool, task_id).await {
+                return Html(render_task_modal(&task));
+            }
+        }
+    }
+
+    Html(format!(
+        "<div class=\"modal-overlay\"><div class=\"window\"><div class=\"window-pane\">Task '{}' not found</div></div></div>",
+        id
+    ))
+}
+
+// Form data for the full task
+#[derive(Deserialize, Debug, Default)]
+pub struct TaskForm {
+    pub name: String,
+    pub details: String,
+    #[serde(default)]
+    pub instructions: String,
     pub schedule_type: String,
+    /// The schedule type the editor showed before a type switch
+    #[serde(default)]
+    pub prev_schedule_type: Option<String>,
     #[serde(default)]
     pub n_days_count: Option<i32>,
     #[serde(default)]
     pub n_days_time: Option<String>,
     #[serde(default)]
+    pub n_days_skip_weekends: Option<String>,
+    #[serde(default)]
     pub n_weeks_count: Option<i32>,
     #[serde(default)]
     pub n_weeks_offset: Option<i32>,
@@ -1970,6 +4038,34 @@ pub struct TaskForm {
     pub alerting_time: Option<i64>,
     #[serde(default)]
     pub completeable: Option<String>,
+    #[serde(default)]
+    pub complete_label: Option<String>,
+    #[serde(default)]
+    pub grace_minutes: Option<i64>,
+    #[serde(default)]
+    pub notify_channels: Option<String>,
+    #[serde(default)]
+    pub target_count: Option<i64>,
+    #[serde(default)]
+    pub show_ahead_days: Option<i64>,
+    #[serde(default)]
+    pub all_day: Option<String>,
+    /// Last local date the task is active (YYYY-MM-DD); blank clears a scheduled end
+    #[serde(default)]
+    pub active_until: Option<String>,
+    /// Set after a duplicate-name warning to confirm creating the task anyway
+    #[serde(default)]
+    pub create_anyway: Option<String>,
+}
+
+/// The end of the given local day (YYYY-MM-DD) in UTC, used as a scheduled `deleted_at`
+fn parse_active_until(date_str: &str) -> Option<DateTime<Utc>> {
+    let date = chrono::NaiveDate::parse_from_str(date_str.trim(), "%Y-%m-%d").ok()?;
+    let end_of_day = date.and_hms_opt(23, 59, 59)?;
+    get_timezone()
+        .from_local_datetime(&end_of_day)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
 }
 
 impl TaskForm {
@@ -1991,6 +4087,11 @@ impl TaskForm {
                 .as_ref()
                 .and_then(|t| NaiveTime::parse_from_str(t, "%H:%M").ok())
                 .unwrap_or(base_task.n_days.time),
+            skip_weekends: self
+                .n_days_skip_weekends
+                .as_deref()
+                .map(SkipWeekends::parse)
+                .unwrap_or(base_task.n_days.skip_weekends),
         };
 
         let n_weeks_time = self
@@ -2005,7 +4106,7 @@ impl TaskForm {
         } else {
             0
         };
-        let n_weeks = NWeeks {
+        let mut n_weeks = NWeeks {
             weeks: n_weeks_weeks,
             offset: n_weeks_offset_clamped,
             sub_schedule: DaysOfWeek {
@@ -2060,7 +4161,7 @@ impl TaskForm {
             .as_ref()
             .and_then(|t| NaiveTime::parse_from_str(t, "%H:%M").ok())
             .unwrap_or(base_task.weeks_of_month.sub_schedule.time);
-        let weeks_of_month = WeeksOfMonth {
+        let mut weeks_of_month = WeeksOfMonth {
             weeks: wom_weeks,
             sub_schedule: DaysOfWeek {
                 sunday: self.wom_dow_sun.is_some(),
@@ -2074,6 +4175,18 @@ impl TaskForm {
             },
         };
 
+        // Switching between the two weekday-based kinds keeps the chosen days
+        // and time, since only the old kind's fields were in the form
+        match (self.prev_schedule_type.as_deref().and_then(ScheduleKind::parse), &schedule_kind) {
+            (Some(ScheduleKind::NWeeks), ScheduleKind::WeeksOfMonth) => {
+                weeks_of_month.sub_schedule = n_weeks.sub_schedule.clone();
+            }
+            (Some(ScheduleKind::WeeksOfMonth), ScheduleKind::NWeeks) => {
+                n_weeks.sub_schedule = weeks_of_month.sub_schedule.clone();
+            }
+            _ => {}
+        }
+
         // Parse certain_months
         let mut cm_months = Vec::new();
         if self.cm_month_jan.is_some() { cm_months.push(1); }
@@ -2111,33 +4224,34 @@ impl TaskForm {
 
         // Parse Once datetime - if "now" checkbox is set, use current time
         let once = if self.once_now.is_some() {
-            Once { datetime: Utc::now() }
+            Once { datetime: clock::now() }
         } else {
-            // Parse date and time from form fields
-            let once_date = self.once_date.as_ref()
-                .filter(|s| !s.is_empty())
-                .and_then(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
-            let once_time = self.once_time.as_ref()
-                .filter(|s| !s.is_empty())
-                .and_then(|t| NaiveTime::parse_from_str(t, "%H:%M").ok())
-                .unwrap_or_else(|| NaiveTime::from_hms_opt(9, 0, 0).unwrap());
-            
-            if let Some(date) = once_date {
-                let datetime = date.and_time(once_time);
-                let tz = get_timezone();
-                tz.from_local_datetime(&datetime)
-                    .single()
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .map(|dt| Once { datetime: dt })
-                    .unwrap_or(base_task.once.clone())
-            } else {
-                base_task.once.clone()
-            }
+            parse_once_datetime(self.once_date.as_deref(), self.once_time.as_deref())
+                .map(|datetime| Once { datetime })
+                .unwrap_or(base_task.once.clone())
+        };
+        // An all-day one-time task is due from the start of its day
+        let all_day = self.all_day.is_some();
+        let once = if all_day { Once { datetime: start_of_local_day(once.datetime) } } else { once };
+
+        // A blank "Active until" clears a scheduled end, but never restores a task already in the trash
+        let deleted_at = match self.active_until.as_deref().map(str::trim) {
+            None => base_task.deleted_at,
+            Some("") if base_task.is_deleted() => base_task.deleted_at,
+            Some("") => None,
+            Some(date) => parse_active_until(date).or(base_task.deleted_at),
         };
 
-        // Preserve created_at and deleted_at from base task (managed via delete/restore buttons)
-        DemoTask {
-            id: id.to_string(),
+        // Grace only applies to one-time and all-day tasks (see `grace_applies`)
+        let grace_minutes = if schedule_kind == ScheduleKind::Once || all_day {
+            self.grace_minutes.unwrap_or(base_task.grace_minutes).max(0)
+        } else {
+            0
+        };
+
+        // Preserve created_at from base task
+        DemoTask {
+            id: id.to_string(),
             name: self.name.clone(),
             details: self.details.clone(),
             schedule_kind,
@@ -2149,8 +4263,21 @@ impl TaskForm {
             once,
             alerting_time: self.alerting_time.unwrap_or(base_task.alerting_time),
             completeable: self.completeable.is_some(),
+            complete_label: self
+                .complete_label
+                .as_deref()
+                .map(str::trim)
+                .unwrap_or_default()
+                .to_string(),
+            grace_minutes,
+            notify_channels: self.notify_channels.as_deref().map(str::trim).unwrap_or_default().to_string(),
+            target_count: self.target_count.unwrap_or(base_task.target_count).clamp(1, MAX_TARGET_COUNT),
+            instructions: self.instructions.trim().to_string(),
+            show_ahead_days: self.show_ahead_days.unwrap_or(base_task.show_ahead_days).clamp(0, MAX_SHOW_AHEAD_DAYS),
+            all_day,
+            snoozed_until: base_task.snoozed_until,
             created_at: base_task.created_at,
-            deleted_at: base_task.deleted_at,
+            deleted_at,
         }
     }
 
@@ -2158,6 +4285,13 @@ impl TaskForm {
     pub fn validate(&self) -> FormErrors {
         let mut errors = FormErrors::default();
 
+        let name_len = self.name.trim().chars().count();
+        if name_len == 0 {
+            errors.name = Some("Please enter a name".to_string());
+        } else if name_len > MAX_NAME_LEN {
+            errors.name = Some(format!("Name must be {} characters or fewer", MAX_NAME_LEN));
+        }
+
         // Validate monthwise_days if schedule type is monthwise
         if self.schedule_type == "monthwise" {
             if let Some(ref days_str) = self.monthwise_days {
@@ -2180,16 +4314,173 @@ impl TaskForm {
             }
         }
 
+        if let Some(ref label) = self.complete_label {
+            if label.trim().chars().count() > MAX_COMPLETE_LABEL_LEN {
+                errors.complete_label = Some(format!(
+                    "Button label must be {} characters or fewer",
+                    MAX_COMPLETE_LABEL_LEN
+                ));
+            }
+        }
+
+        if let Some(date_str) = self.active_until.as_deref().map(str::trim)
+            && !date_str.is_empty()
+        {
+            match chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
+                Ok(date) if date < clock::now().with_timezone(&get_timezone()).date_naive() => {
+                    errors.active_until = Some("Active until date can't be in the past".to_string());
+                }
+                Ok(_) => {}
+                Err(_) => errors.active_until = Some("Please enter a valid date".to_string()),
+            }
+        }
+
         errors
     }
 }
 
+/// `task` with its schedule replaced by `schedule`, checked by the same rules
+/// as the editor: the schedule is filled into a `TaskForm` as if it had been
+/// typed in. Lists the form can't leave empty or out of range are errors here
+/// rather than falling back to the old values.
+pub fn with_schedule(task: &DemoTask, schedule: &Schedule) -> Result<DemoTask, String> {
+    let hhmm = |time: &NaiveTime| Some(time.format("%H:%M").to_string());
+    let on = |checked: bool| checked.then(|| "on".to_string());
+    let check = |values: &[i32], range: std::ops::RangeInclusive<i32>, what: &str| {
+        if values.is_empty() || values.iter().any(|v| !range.contains(v)) {
+            Err(format!("{} must be a non-empty list of {} to {}", what, range.start(), range.end()))
+        } else {
+            Ok(())
+        }
+    };
+
+    let mut form = TaskForm {
+        name: task.name.clone(),
+        schedule_type: schedule.kind().as_str().to_string(),
+        all_day: on(task.all_day),
+        ..Default::default()
+    };
+    let weekdays = |days: &DaysOfWeek| {
+        [days.sunday, days.monday, days.tuesday, days.wednesday, days.thursday, days.friday, days.saturday].map(on)
+    };
+    match schedule {
+        Schedule::NDays(n_days) => {
+            check(&[n_days.days], 1..=i32::MAX, "days")?;
+            form.n_days_count = Some(n_days.days);
+            form.n_days_time = hhmm(&n_days.time);
+            form.n_days_skip_weekends = Some(n_days.skip_weekends.as_str().to_string());
+        }
+        Schedule::NWeeks(n_weeks) => {
+            check(&[n_weeks.weeks], 1..=i32::MAX, "weeks")?;
+            [form.dow_sun, form.dow_mon, form.dow_tue, form.dow_wed, form.dow_thu, form.dow_fri, form.dow_sat] =
+                weekdays(&n_weeks.sub_schedule);
+            form.n_weeks_count = Some(n_weeks.weeks);
+            form.n_weeks_offset = Some(n_weeks.offset);
+            form.n_weeks_time = hhmm(&n_weeks.sub_schedule.time);
+        }
+        Schedule::Monthwise(monthwise) => {
+            form.monthwise_days = Some(format_day_range(&monthwise.days));
+            form.monthwise_time = hhmm(&monthwise.time);
+        }
+        Schedule::WeeksOfMonth(weeks_of_month) => {
+            check(&weeks_of_month.weeks, 1..=5, "weeks")?;
+            [form.wom_dow_sun, form.wom_dow_mon, form.wom_dow_tue, form.wom_dow_wed, form.wom_dow_thu, form.wom_dow_fri, form.wom_dow_sat] =
+                weekdays(&weeks_of_month.sub_schedule);
+            [form.wom_week_1, form.wom_week_2, form.wom_week_3, form.wom_week_4, form.wom_week_5] =
+                std::array::from_fn(|i| on(weeks_of_month.weeks.contains(&(i as i32 + 1))));
+            form.wom_time = hhmm(&weeks_of_month.sub_schedule.time);
+        }
+        Schedule::CertainMonths(certain_months) => {
+            check(&certain_months.months, 1..=12, "months")?;
+            [
+                form.cm_month_jan, form.cm_month_feb, form.cm_month_mar, form.cm_month_apr,
+                form.cm_month_may, form.cm_month_jun, form.cm_month_jul, form.cm_month_aug,
+                form.cm_month_sep, form.cm_month_oct, form.cm_month_nov, form.cm_month_dec,
+            ] = std::array::from_fn(|i| on(certain_months.months.contains(&(i as i32 + 1))));
+            form.cm_days = Some(format_day_range(&certain_months.days));
+            form.cm_time = hhmm(&certain_months.time);
+        }
+        Schedule::Once(once) => {
+            let local = once.datetime.with_timezone(&get_timezone());
+            form.once_date = Some(local.format("%Y-%m-%d").to_string());
+            form.once_time = hhmm(&local.time());
+        }
+    }
+
+    let errors = form.validate();
+    if let Some(message) = errors.monthwise_days.or(errors.certain_months_days) {
+        return Err(message);
+    }
+
+    // Only the schedule comes from the form; everything else stays as it was
+    let parsed = form.to_demo_task(&task.id, task);
+    Ok(DemoTask {
+        schedule_kind: parsed.schedule_kind,
+        n_days: parsed.n_days,
+        n_weeks: parsed.n_weeks,
+        monthwise: parsed.monthwise,
+        weeks_of_month: parsed.weeks_of_month,
+        certain_months: parsed.certain_months,
+        once: parsed.once,
+        ..task.clone()
+    })
+}
+
+/// A Once date ("YYYY-MM-DD") and optional time ("HH:MM", 09:00 when blank)
+/// as entered in the configured timezone. None when the date is missing or
+/// invalid, or the local time doesn't exist (e.g. skipped by a DST change).
+fn parse_once_datetime(date: Option<&str>, time: Option<&str>) -> Option<DateTime<Utc>> {
+    let date = date
+        .filter(|s| !s.is_empty())
+        .and_then(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())?;
+    let time = time
+        .filter(|s| !s.is_empty())
+        .and_then(|t| NaiveTime::parse_from_str(t, "%H:%M").ok())
+        .unwrap_or_else(|| NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+    get_timezone()
+        .from_local_datetime(&date.and_time(time))
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// "Yesterday", "Today", "Tomorrow" or "Overmorrow" for a local due time as
+/// seen from local `now`, or None further out. Days begin at `day_start_hour`
+/// rather than midnight, so with 4 a task due at 02:00 is still "Today" the
+/// evening before. An all-day task is due at 00:00 of its own date, so it keeps
+/// that date rather than sliding back into the day before.
+fn relative_day_label(due: NaiveDateTime, all_day: bool, now: NaiveDateTime, day_start_hour: u32) -> Option<&'static str> {
+    let shift = Duration::hours(day_start_hour as i64);
+    let due_date = if all_day { due.date() } else { (due - shift).date() };
+    match (due_date - (now - shift).date()).num_days() {
+        -1 => Some("Yesterday"),
+        0 => Some("Today"),
+        1 => Some("Tomorrow"),
+        2 => Some("Overmorrow"),
+        _ => None,
+    }
+}
+
+/// Which timezone due times and completions are displayed in, for pages that show them
+fn timezone_note() -> String {
+    format!("Times shown in {}", get_timezone().name())
+}
+
+/// Midnight at the start of `at`'s local date, in the configured timezone
+fn start_of_local_day(at: DateTime<Utc>) -> DateTime<Utc> {
+    let tz = get_timezone();
+    tz.from_local_datetime(&at.with_timezone(&tz).date_naive().and_time(NaiveTime::MIN))
+        .earliest()
+        .map_or(at, |dt| dt.with_timezone(&Utc))
+}
+
 // POST /tasks/:id - Save the task
 async fn save_task(
     State(pool): State<DbPool>,
     Path(id): Path<String>,
     Form(form): Form<TaskForm>,
-) -> Html<String> {
+) -> Result<Html<String>, AppError> {
+    let not_found = || AppError::NotFound(format!("Task '{}' not found", id));
+
     // Validate the form
     let errors = form.validate();
     if errors.has_errors() {
@@ -2199,14 +4490,15 @@ async fn save_task(
             let tasks_guard = tasks.lock().unwrap();
             if let Some(base_task) = tasks_guard.get(&id) {
                 let temp_task = form.to_demo_task(&id, base_task);
-                return Html(render_task_modal_with_errors(&temp_task, &form, &errors));
-            }
-        } else if let Ok(task_id) = id.parse::<i64>() {
-            if let Ok(Some(base_task)) = db::get_task(&pool, task_id).await {
-                let temp_task = form.to_demo_task(&id, &base_task);
-                return Html(render_task_modal_with_errors(&temp_task, &form, &errors));
+                return Ok(Html(render_task_modal_with_errors(&temp_task, &form, &errors)));
             }
+        } else if let Ok(task_id) = id.parse::<i64>()
+            && let Some(base_task) = db::get_task(&pool, task_id).await?
+        {
+            let temp_task = form.to_demo_task(&id, &base_task);
+            return Ok(Html(render_task_modal_with_errors(&temp_task, &form, &errors)));
         }
+        return Err(not_found());
     }
 
     // On successful save, return a script that reloads the page (closes modal)
@@ -2216,32 +4508,23 @@ async fn save_task(
         let tasks = get_demo_tasks();
         let mut tasks_guard = tasks.lock().unwrap();
 
-        if let Some(existing_task) = tasks_guard.get(&id) {
-            let updated_task = form.to_demo_task(&id, existing_task);
-            tasks_guard.insert(id.clone(), updated_task);
-            return Html(success_response);
-        }
-    } else {
-        if let Ok(task_id) = id.parse::<i64>() {
-            if let Ok(Some(existing_task)) = db::get_task(&pool, task_id).await {
-                let updated_task = form.to_demo_task(&id, &existing_task);
-                match db::save_task(&pool, &updated_task).await {
-                    Ok(_) => {
-                        info!(task_id = %id, name = %updated_task.name, "Task updated");
-                        return Html(success_response);
-                    }
-                    Err(e) => {
-                        error!(task_id = %id, error = %e, "Error saving task");
-                    }
-                }
-            }
-        }
+        let existing_task = tasks_guard.get(&id).ok_or_else(not_found)?;
+        let updated_task = form.to_demo_task(&id, existing_task);
+        tasks_guard.insert(id.clone(), updated_task);
+        return Ok(Html(success_response));
     }
 
-    Html(format!(
-        "<div class=\"modal-overlay\"><div class=\"window\"><div class=\"window-pane\">Task '{}' not found</div></div></div>",
-        id
-    ))
+    let task_id = id.parse::<i64>().map_err(|_| not_found())?;
+    let existing_task = db::get_task(&pool, task_id).await?.ok_or_else(not_found)?;
+    let updated_task = form.to_demo_task(&id, &existing_task);
+    db::save_task(&pool, &updated_task).await?;
+
+    info!(task_id = %id, name = %updated_task.name, "Task updated");
+    let changes = describe_task_changes(&existing_task, &updated_task);
+    if !changes.is_empty() {
+        record_audit_with_snapshot(&pool, &id, "edit", &changes.join("; "), &updated_task).await;
+    }
+    Ok(Html(success_response))
 }
 
 // POST /tasks/:id/schedule-type - Re-render form with new schedule type (doesn't save)
@@ -2274,30 +4557,91 @@ async fn change_schedule_type(
 }
 
 // GET /tasks/new - Show modal for creating a new task
-async fn new_task_modal() -> Html<String> {
-    let new_task = create_default_task();
-    Html(render_new_task_modal(&new_task))
+#[derive(Deserialize)]
+pub struct NewTaskQuery {
+    #[serde(default)]
+    template: Option<String>,
+}
+
+async fn new_task_modal(
+    State(pool): State<DbPool>,
+    Query(query): Query<NewTaskQuery>,
+    headers: HeaderMap,
+) -> Html<String> {
+    let templates = db::get_all_templates(&pool).await.unwrap_or_default();
+
+    // Pre-fill from a template if one was picked; templates carry no dates, so keep the defaults
+    let template_task = match query.template.as_deref().and_then(|t| t.parse::<i64>().ok()) {
+        Some(template_id) => db::get_template_task(&pool, template_id).await.ok().flatten(),
+        None => None,
+    };
+    let new_task = match template_task {
+        Some(task) => DemoTask { once: default_once(), ..task },
+        None => create_default_task(),
+    };
+
+    let modal_html = render_new_task_modal(&new_task, &templates);
+    if is_htmx_request(&headers) {
+        Html(modal_html)
+    } else {
+        Html(render_new_task_page(&modal_html, settings::is_touch_mode(&headers)))
+    }
 }
 
 // POST /tasks/new - Create a new task
-async fn create_task(State(pool): State<DbPool>, Form(form): Form<TaskForm>) -> Html<String> {
+// Plain form posts (no JS, or the full-page form) get a full page back on
+// errors and a redirect to /tasks on success.
+async fn create_task(State(pool): State<DbPool>, headers: HeaderMap, Form(form): Form<TaskForm>) -> Response {
     let base_task = create_default_task();
+    let is_htmx = is_htmx_request(&headers);
 
     // Validate the form
     let errors = form.validate();
     if errors.has_errors() {
         let temp_task = form.to_demo_task("", &base_task);
-        return Html(render_new_task_modal_with_errors(&temp_task, &form, &errors));
+        let modal_html = render_new_task_modal_with_errors(&temp_task, &form, &errors);
+        if is_htmx {
+            return Html(modal_html).into_response();
+        }
+        return Html(render_new_task_page(&modal_html, settings::is_touch_mode(&headers))).into_response();
     }
 
     let new_task = form.to_demo_task("", &base_task);
 
+    // Warn (once) about a likely duplicate before creating it
+    if form.create_anyway.is_none() {
+        let existing = db::get_all_tasks(&pool).await.unwrap_or_default();
+        if let Some(duplicate) = find_similar_task(&existing, &new_task.name) {
+            let warning = FormErrors {
+                duplicate_warning: Some(format!("A task named \"{}\" already exists.", duplicate.name)),
+                ..Default::default()
+            };
+            let modal_html = render_new_task_modal_with_errors(&new_task, &form, &warning);
+            if is_htmx {
+                return Html(modal_html).into_response();
+            }
+            return Html(render_new_task_page(&modal_html, settings::is_touch_mode(&headers))).into_response();
+        }
+    }
+
     // Save to database
     match db::save_task(&pool, &new_task).await {
         Ok(task_id) => {
             info!(task_id = %task_id, name = %new_task.name, "Task created");
+            record_audit_with_snapshot(&pool, &task_id.to_string(), "create", "created", &new_task).await;
+            if !is_htmx {
+                return Redirect::to("/tasks").into_response();
+            }
+            // Submitted with HTMX from the full-page form: there is no list to refresh
+            if headers
+                .get("HX-Current-URL")
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|url| url.split('?').next().unwrap_or("").ends_with("/tasks/new"))
+            {
+                return ([("HX-Redirect", "/tasks")], Html(String::new())).into_response();
+            }
             // Return empty modal container (closes the modal) and trigger list refresh
-            Html(r##"<div hx-get="/tasks/list" hx-trigger="load" hx-target="#task-list" hx-swap="innerHTML"></div>"##.to_string())
+            Html(r##"<div hx-get="/tasks/list" hx-trigger="load" hx-target="#task-list" hx-swap="innerHTML"></div>"##.to_string()).into_response()
         }
         Err(e) => {
             error!(name = %new_task.name, error = %e, "Error creating task");
@@ -2305,23 +4649,113 @@ async fn create_task(State(pool): State<DbPool>, Form(form): Form<TaskForm>) ->
                 "<div class=\"modal-overlay\"><div class=\"window\"><div class=\"window-pane\">Error creating task: {}</div></div></div>",
                 e
             ))
+            .into_response()
+        }
+    }
+}
+
+/// The empty-database welcome: load the example tasks, or create a first task.
+/// `return_to` is where loading the examples lands: "home" for the dashboard,
+/// anything else for the task list, where the create button opens the modal.
+fn render_onboarding(return_to: &str) -> String {
+    let create = if return_to == "home" {
+        r#"<a class="btn btn-default" href="/tasks/new">Create your first task</a>"#
+    } else {
+        r##"<a class="btn btn-default" href="/tasks/new" hx-get="/tasks/new" hx-target="#modal-container" hx-swap="innerHTML">Create your first task</a>"##
+    };
+    maud! {
+        div .onboarding {
+            p { "No tasks yet. Start from a few examples to see how schedules work, or make your own." }
+            div .onboarding-actions {
+                form method="post" action="/tasks/examples" {
+                    input type="hidden" name="return" value=(return_to);
+                    button .btn type="submit" { "Load example tasks" }
+                }
+                (Raw::dangerously_create(create))
+            }
         }
     }
+    .render()
+    .into_inner()
+}
+
+#[derive(Deserialize)]
+struct ExamplesForm {
+    #[serde(rename = "return", default)]
+    return_to: String,
+}
+
+// POST /tasks/examples - Copy the demo tasks into the database. Examples whose
+// name is already taken are skipped, so submitting twice adds nothing new.
+async fn load_example_tasks(State(pool): State<DbPool>, Form(form): Form<ExamplesForm>) -> Result<Redirect, AppError> {
+    let existing: HashSet<String> = db::get_all_tasks(&pool).await?.into_iter().map(|t| t.name).collect();
+    let mut examples: Vec<DemoTask> = get_demo_tasks().lock().unwrap().values().cloned().collect();
+    examples.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let now = clock::now();
+    let mut added = 0;
+    for mut example in examples.into_iter().filter(|t| !existing.contains(&t.name)) {
+        example.id = String::new();
+        example.created_at = Some(now);
+        let task_id = db::save_task(&pool, &example).await?;
+        record_audit_with_snapshot(&pool, &task_id.to_string(), "create", "created from the examples", &example).await;
+        added += 1;
+    }
+    info!(count = added, "Loaded example tasks");
+    if added > 0 {
+        events::notify_changed();
+    }
+
+    Ok(Redirect::to(if form.return_to == "home" { "/" } else { "/tasks" }))
+}
+
+/// Reduce a task name to lowercase words without punctuation or filler
+/// ("the", "a", "an"), so "Take out the Trash!" and "take out trash" compare equal.
+fn normalize_task_name(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty() && !matches!(*w, "the" | "a" | "an"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// First non-deleted task whose name matches `name` after normalization
+pub fn find_similar_task<'a>(tasks: &'a [DemoTask], name: &str) -> Option<&'a DemoTask> {
+    let wanted = normalize_task_name(name);
+    if wanted.is_empty() {
+        return None;
+    }
+    tasks
+        .iter()
+        .find(|t| !t.is_deleted() && normalize_task_name(&t.name) == wanted)
+}
+
+#[derive(Deserialize)]
+pub struct ParseDaysForm {
+    // The monthwise and certain-months editors name their day fields differently
+    monthwise_days: Option<String>,
+    cm_days: Option<String>,
+}
+
+// POST /tasks/parse-days - Preview how a day-range field will be read, as the user types
+async fn parse_days_preview(Form(form): Form<ParseDaysForm>) -> Html<String> {
+    let raw = form.monthwise_days.or(form.cm_days).unwrap_or_default();
+    Html(render_day_range_preview(&raw))
 }
 
 // POST /tasks/new/schedule-type - Re-render new task form with new schedule type
 async fn new_task_schedule_type(Form(form): Form<TaskForm>) -> Html<String> {
     let base_task = create_default_task();
     let temp_task = form.to_demo_task("", &base_task);
-    Html(render_new_task_modal(&temp_task))
+    Html(render_new_task_modal(&temp_task, &[]))
 }
 
-fn create_default_task() -> DemoTask {
+pub fn create_default_task() -> DemoTask {
     DemoTask {
         id: String::new(),
         name: String::new(),
         details: String::new(),
-        schedule_kind: ScheduleKind::Once,
+        schedule_kind: get_default_schedule_kind(),
         n_days: default_n_days(),
         n_weeks: default_n_weeks(),
         monthwise: default_monthwise(),
@@ -2330,6 +4764,14 @@ fn create_default_task() -> DemoTask {
         once: default_once(),
         alerting_time: 1440, // 24 hours in minutes
         completeable: true,
+        complete_label: String::new(),
+        grace_minutes: 0,
+        notify_channels: String::new(),
+        target_count: 1,
+        instructions: String::new(),
+        show_ahead_days: 0,
+        all_day: false,
+        snoozed_until: None,
         created_at: None,
         deleted_at: None,
     }
@@ -2349,57 +4791,97 @@ pub struct DemoTask {
     pub once: Once,
     pub alerting_time: i64,
     pub completeable: bool,
+    /// Text for the completion button; empty means the default "Complete"
+    pub complete_label: String,
+    /// Minutes after the due time before the task counts as overdue
+    pub grace_minutes: i64,
+    /// Comma-separated reminder channels; empty means the global default
+    pub notify_channels: String,
+    /// Completions needed per occurrence; above 1 the task is a counter ("3/8")
+    pub target_count: i64,
+    /// How-to notes shown on the task page and when completing, but not on cards
+    pub instructions: String,
+    /// Days before due to list the task under Planning; 0 never does. Unlike
+    /// alerting_time this only affects visibility, not the alert state.
+    pub show_ahead_days: i64,
+    /// Due any time on its days rather than at a set time: the whole local
+    /// day is the due window and displays leave the time out
+    pub all_day: bool,
+    /// Set by "Not today": the current occurrence is hidden until this time passes
+    pub snoozed_until: Option<DateTime<Utc>>,
     pub created_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl DemoTask {
-    /// Calculate the next due date for this task
-    /// Uses is_due_on_date for consistency with calendar display
-    pub fn next_due_date(&self) -> DateTime<Utc> {
-        let now = Utc::now();
-        
-        // Special case for Once: always return the once datetime (there's only one)
-        if matches!(self.schedule_kind, ScheduleKind::Once) {
-            return self.once.datetime;
-        }
-        
-        let tz = get_timezone();
-        let tz_now = now.with_timezone(&tz);
-        let today = tz_now.date_naive();
+    /// Whether the task needs several completions per occurrence
+    pub fn is_counter(&self) -> bool {
+        self.target_count > 1
+    }
 
-        // Search up to 1000 days ahead for the next due date
-        for days_ahead in 0..=1000 {
-            let check_date = today + Duration::days(days_ahead);
-            
-            if is_due_on_date(self, check_date) {
-                let due_time = get_due_time(self, check_date);
-                let at_time = tz.from_local_datetime(&check_date.and_time(due_time))
-                    .unwrap()
-                    .with_timezone(&Utc);
-                
-                // Only return if this time is still in the future
-                if at_time > now {
-                    return at_time;
-                }
-            }
+    /// Text for this task's completion button
+    pub fn completion_button_label(&self) -> &str {
+        if self.complete_label.is_empty() {
+            "Complete"
+        } else {
+            &self.complete_label
         }
+    }
 
-        // Fallback: distant future (sentinel value)
-        now + Duration::days(10000)
+    /// The next due time after now; for a Once task, its only occurrence
+    pub fn next_due_date(&self) -> DateTime<Utc> {
+        engine::next_due(self, get_timezone(), clock::now())
     }
-    
+
     /// Check if the next due date is the "distant future" sentinel
     fn is_distant_future(&self) -> bool {
-        let next_due = self.next_due_date();
-        let now = Utc::now();
-        // If more than 1000 days away, it's the distant future sentinel
-        next_due > now + Duration::days(1000)
+        self.next_due_date() > clock::now() + Duration::days(engine::NEXT_DUE_SCAN_DAYS)
     }
-    
+
+    /// The task's schedule as one typed value: its kind and that kind's fields
+    pub fn schedule(&self) -> Schedule {
+        match self.schedule_kind {
+            ScheduleKind::NDays => Schedule::NDays(self.n_days.clone()),
+            ScheduleKind::NWeeks => Schedule::NWeeks(self.n_weeks.clone()),
+            ScheduleKind::Monthwise => Schedule::Monthwise(self.monthwise.clone()),
+            ScheduleKind::WeeksOfMonth => Schedule::WeeksOfMonth(self.weeks_of_month.clone()),
+            ScheduleKind::CertainMonths => Schedule::CertainMonths(self.certain_months.clone()),
+            ScheduleKind::Once => Schedule::Once(self.once.clone()),
+        }
+    }
+
+    /// A recurring schedule with nothing selected (no weekdays, weeks, months or
+    /// days), which older data and imports can contain. Such a task never comes due.
+    pub fn has_empty_schedule(&self) -> bool {
+        match self.schedule_kind {
+            ScheduleKind::NDays | ScheduleKind::Once => false,
+            ScheduleKind::NWeeks => self.n_weeks.sub_schedule.is_empty(),
+            ScheduleKind::Monthwise => self.monthwise.days.is_empty(),
+            ScheduleKind::WeeksOfMonth => self.weeks_of_month.weeks.is_empty() || self.weeks_of_month.sub_schedule.is_empty(),
+            ScheduleKind::CertainMonths => self.certain_months.months.is_empty() || self.certain_months.days.is_empty(),
+        }
+    }
+
     /// Check if this is a Once task that has no future occurrences
     pub fn is_once_completed(&self) -> bool {
-        matches!(self.schedule_kind, ScheduleKind::Once) && self.once.datetime <= Utc::now()
+        matches!(self.schedule_kind, ScheduleKind::Once) && self.once.datetime <= clock::now()
+    }
+
+    /// A past Once task older than the ARCHIVE_AFTER_DAYS cutoff (never, when unset)
+    pub fn is_archived(&self, archive_after_days: Option<i64>, now: DateTime<Utc>) -> bool {
+        match archive_after_days {
+            Some(days) => self.is_once_completed() && self.once.datetime < now - Duration::days(days),
+            None => false,
+        }
+    }
+
+    /// The next due time as RFC 3339 for integrations (X-Next-Due header and
+    /// next-due meta tag), or None when there is no upcoming occurrence
+    pub fn next_due_rfc3339(&self) -> Option<String> {
+        if self.is_deleted() || self.is_once_completed() || self.is_distant_future() {
+            return None;
+        }
+        Some(self.next_due_date().to_rfc3339())
     }
 
     /// Format the next due date as a human-readable string
@@ -2414,59 +4896,117 @@ impl DemoTask {
             return "Distant Future".to_string();
         }
         
-        let next_due = self.next_due_date();
+        let next_due = self.all_day_due_today().unwrap_or_else(|| self.next_due_date());
         let tz = get_timezone();
         let tz_time = next_due.with_timezone(&tz);
-        let now_tz = Utc::now().with_timezone(&tz);
-
-        // Get dates without time for comparison
-        let due_date = tz_time.date_naive();
-        let today = now_tz.date_naive();
-        let yesterday = today - Duration::days(1);
-        let tomorrow = today + Duration::days(1);
-        let overmorrow = today + Duration::days(2);
-
-        let time_str = tz_time.format("%H:%M").to_string();
-
-        if due_date == yesterday {
-            format!("Yesterday at {}", time_str)
-        } else if due_date == today {
-            format!("Today at {}", time_str)
-        } else if due_date == tomorrow {
-            format!("Tomorrow at {}", time_str)
-        } else if due_date == overmorrow {
-            format!("Overmorrow at {}", time_str)
+        let now_tz = clock::now().with_timezone(&tz);
+
+        let day = match relative_day_label(tz_time.naive_local(), self.all_day, now_tz.naive_local(), get_day_start_hour()) {
+            Some(label) => label.to_string(),
+            // "{day name}, {month} {day}"
+            None => tz_time.format("%A, %B %-d").to_string(),
+        };
+
+        // All-day tasks have no time worth showing
+        if self.all_day {
+            day
         } else {
-            // "{day name}, {month} {day}" at {time}
-            tz_time.format("%A, %B %-d at %H:%M").to_string()
+            format!("{} at {}", day, tz_time.format("%H:%M"))
+        }
+    }
+
+    /// For an all-day task with an occurrence today, the start of today. The
+    /// task stays due for the rest of the day rather than moving on to its
+    /// next occurrence once midnight has passed.
+    pub fn all_day_due_today(&self) -> Option<DateTime<Utc>> {
+        if !self.all_day {
+            return None;
         }
+        let now = clock::now();
+        let tz = get_timezone();
+        let due = self.most_recent_due_date();
+        (due <= now && due.with_timezone(&tz).date_naive() == now.with_timezone(&tz).date_naive()).then_some(due)
+    }
+
+    /// Whether `grace_minutes` means anything for this task. A recurring
+    /// task's next due time is always ahead, so grace only delays one-time and
+    /// all-day tasks.
+    pub fn grace_applies(&self) -> bool {
+        self.schedule_kind == ScheduleKind::Once || self.all_day
     }
 
-    /// Check if the task is due (past its due date)
+    /// Check if the task is due (past its due date plus any grace period)
     pub fn is_due(&self) -> bool {
-        // Inactive tasks are never due
-        if self.is_inactive() {
+        // Inactive and snoozed tasks are never due
+        if self.is_inactive() || self.is_snoozed() {
             return false;
         }
-        self.next_due_date() <= Utc::now()
+        let grace = Duration::minutes(self.grace_minutes);
+        if let Some(due) = self.all_day_due_today()
+            && due + grace <= clock::now()
+        {
+            return true;
+        }
+        self.next_due_date() + grace <= clock::now()
     }
 
-    /// Check if the task is alerting (due within the alerting_time window but not yet due)
+    /// Check if the task is alerting (due within the alerting_time window, or inside
+    /// its grace period, but not yet due)
     pub fn is_alerting(&self) -> bool {
-        // Inactive tasks are never alerting
-        if self.is_inactive() {
+        // Inactive and snoozed tasks are never alerting
+        if self.is_inactive() || self.is_snoozed() {
             return false;
         }
+        let now = clock::now();
+        // An all-day occurrence today is only alerting while inside its grace period
+        if let Some(due) = self.all_day_due_today() {
+            return due + Duration::minutes(self.grace_minutes) > now;
+        }
         let next_due = self.next_due_date();
-        let now = Utc::now();
         let alert_threshold = now + Duration::minutes(self.alerting_time);
 
-        next_due > now && next_due <= alert_threshold
+        next_due + Duration::minutes(self.grace_minutes) > now && next_due <= alert_threshold
+    }
+
+    /// Whether the task belongs under Planning: due within its show-ahead
+    /// window but not yet close enough to be alerting
+    pub fn is_planning(&self) -> bool {
+        if self.show_ahead_days <= 0 || self.is_inactive() || self.is_snoozed() || self.is_alerting() {
+            return false;
+        }
+        let next_due = self.next_due_date();
+        let now = clock::now();
+        next_due > now && next_due <= now + Duration::days(self.show_ahead_days)
+    }
+
+    /// Reminder channels for this task, falling back to the global default
+    pub fn effective_notify_channels(&self) -> Vec<NotifyChannel> {
+        if self.notify_channels.trim().is_empty() {
+            get_notify_channels()
+        } else {
+            parse_notify_channels(&self.notify_channels)
+        }
+    }
+
+    /// Check if the current occurrence was dismissed with "Not today"
+    pub fn is_snoozed(&self) -> bool {
+        self.snoozed_until.is_some_and(|until| clock::now() < until)
+    }
+
+    /// Whether the task is in the trash. A future `deleted_at` is a scheduled
+    /// retirement: the task stays active until then.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some_and(|deleted_at| deleted_at <= clock::now())
+    }
+
+    /// The scheduled retirement time, if it's still ahead
+    pub fn scheduled_end(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at.filter(|deleted_at| *deleted_at > clock::now())
     }
 
     /// Check if the task is inactive (before created_at or after deleted_at)
     pub fn is_inactive(&self) -> bool {
-        let now = Utc::now();
+        let now = clock::now();
         
         // If created_at is set and we're before it, task is inactive
         if let Some(created_at) = self.created_at {
@@ -2485,34 +5025,10 @@ impl DemoTask {
         false
     }
 
-    /// Calculate the most recent past due date for this task
-    /// Used to determine if a completion happened after the task became due
-    /// Uses is_due_on_date for consistency with calendar display
+    /// The most recent due time at or before now. Used to determine if a
+    /// completion happened after the task became due.
     pub fn most_recent_due_date(&self) -> DateTime<Utc> {
-        let now = Utc::now();
-        let tz = get_timezone();
-        let tz_now = now.with_timezone(&tz);
-        let today = tz_now.date_naive();
-
-        // Search up to 60 days back for the most recent due date
-        for days_back in 0..=60 {
-            let check_date = today - Duration::days(days_back);
-            
-            if is_due_on_date(self, check_date) {
-                let due_time = get_due_time(self, check_date);
-                let at_time = tz.from_local_datetime(&check_date.and_time(due_time))
-                    .unwrap()
-                    .with_timezone(&Utc);
-                
-                // Only return if this time is in the past (or now)
-                if at_time <= now {
-                    return at_time;
-                }
-            }
-        }
-
-        // Fallback: 60 days ago
-        now - Duration::days(60)
+        engine::most_recent_due(self, get_timezone(), clock::now())
     }
 }
 
@@ -2520,6 +5036,7 @@ pub fn default_n_days() -> NDays {
     NDays {
         days: 1,
         time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        skip_weekends: SkipWeekends::Off,
     }
 }
 
@@ -2573,7 +5090,7 @@ pub fn default_certain_months() -> CertainMonths {
 
 pub fn default_once() -> Once {
     Once {
-        datetime: Utc::now(),
+        datetime: clock::now(),
     }
 }
 
@@ -2610,41 +5127,52 @@ fn render_per_page_select(current_per_page: i64) -> String {
     )
 }
 
-async fn render_task_list(pool: &DbPool, sort: &str, page: i64, per_page: i64, is_touch: bool) -> String {
-    // Ensure valid pagination values
-    let per_page = per_page.max(1).min(100);
-    let page = page.max(1);
+/// Largest page size accepted by the task list and /api/tasks
+pub const MAX_PER_PAGE: i64 = 100;
+
+/// Clamp pagination input the same way for HTML and JSON: per_page to
+/// 1..=MAX_PER_PAGE and page to 1..=total_pages (page 1 when there are no tasks).
+/// Returns (page, per_page, total_pages).
+pub fn clamp_pagination(page: i64, per_page: i64, total_count: i64) -> (i64, i64, i64) {
+    let per_page = per_page.clamp(1, MAX_PER_PAGE);
+    let total_pages = (total_count.max(0) + per_page - 1) / per_page;
+    let page = page.min(total_pages).max(1);
+    (page, per_page, total_pages)
+}
+
+/// Load one (already clamped) page of tasks. Sorting by "due" needs every task
+/// since next due dates are calculated, so that sort paginates in memory.
+pub async fn fetch_task_page(pool: &DbPool, sort: &str, page: i64, per_page: i64) -> Vec<DemoTask> {
+    if sort == "due" {
+        let mut all_tasks = db::get_all_tasks(pool).await.unwrap_or_default();
+        all_tasks.sort_by(|a, b| a.next_due_date().cmp(&b.next_due_date()));
+        let start = ((page - 1) * per_page) as usize;
+        all_tasks.into_iter().skip(start).take(per_page as usize).collect()
+    } else {
+        let offset = (page - 1) * per_page;
+        db::get_tasks_paginated(pool, sort, offset, per_page)
+            .await
+            .unwrap_or_default()
+    }
+}
 
+async fn render_task_list(pool: &DbPool, sort: &str, page: i64, per_page: i64, is_touch: bool) -> String {
     // Get total count for pagination
     let total_count = db::get_task_count(pool).await.unwrap_or(0);
 
     if total_count == 0 {
         return maud! {
             div .empty-list {
-                p { "No tasks yet. Create your first task!" }
+                (Raw::dangerously_create(&render_onboarding("tasks")))
             }
         }
         .render()
         .into_inner();
     }
 
-    // Calculate total pages
-    let total_pages = (total_count + per_page - 1) / per_page;
-    let page = page.min(total_pages); // Clamp page to max
-
-    // Fetch tasks - for "due" sort we need all tasks to sort globally before paginating
-    let tasks: Vec<DemoTask> = if sort == "due" {
-        let mut all_tasks = db::get_all_tasks(pool).await.unwrap_or_default();
-        all_tasks.sort_by(|a, b| a.next_due_date().cmp(&b.next_due_date()));
-        let start = ((page - 1) * per_page) as usize;
-        let end = (start + per_page as usize).min(all_tasks.len());
-        all_tasks.into_iter().skip(start).take(end - start).collect()
-    } else {
-        let offset = (page - 1) * per_page;
-        db::get_tasks_paginated(pool, sort, offset, per_page)
-            .await
-            .unwrap_or_default()
-    };
+    // Ensure valid pagination values
+    let (page, per_page, total_pages) = clamp_pagination(page, per_page, total_count);
+    let tasks = fetch_task_page(pool, sort, page, per_page).await;
 
     let items: Vec<String> = tasks.iter().map(|t| render_task_list_item(t, is_touch)).collect();
     let pagination_html = render_pagination(page, total_pages, per_page, sort, total_count);
@@ -2769,6 +5297,23 @@ fn render_pagination(current_page: i64, total_pages: i64, per_page: i64, sort: &
     )
 }
 
+/// Bulk alert-time form on the tasks index. The list's checkboxes join it via
+/// their `form` attribute, so they keep working after HTMX swaps the list.
+/// "All N tasks" reaches past the current page; the server asks to confirm.
+fn render_bulk_alerting_form(total_count: i64) -> String {
+    format!(
+        r##"<form id="bulk-actions-form" class="bulk-actions" method="post" action="/tasks/alerting/batch" hx-post="/tasks/alerting/batch" hx-target="#bulk-status" hx-swap="innerHTML">
+            <label><input type="checkbox" class="bulk-select-page" onclick="document.querySelectorAll('.task-select').forEach(function(box) {{ box.checked = this.checked; }}, this)"> Select page</label>
+            <label><input type="checkbox" name="all_matching" value="1"> All {total_count} tasks</label>
+            <label for="alerting-time-bulk">Alert time for selected:</label>
+            {alerting}
+            <button class="btn" type="submit">Apply</button>
+            <span id="bulk-status"></span>
+        </form>"##,
+        alerting = render_alerting_time_input("bulk", 1440)
+    )
+}
+
 fn render_task_list_item(task: &DemoTask, is_touch: bool) -> String {
     let edit_url = format!("/tasks/{}/edit-modal", task.id);
     let show_url = format!("/tasks/{}", task.id);
@@ -2788,8 +5333,15 @@ fn render_task_list_item(task: &DemoTask, is_touch: bool) -> String {
         )
     };
 
+    let select_html = format!(
+        r#"<input type="checkbox" class="task-select" name="task_ids" value="{id}" form="bulk-actions-form" aria-label="Select {name}">"#,
+        id = html_escape(&task.id),
+        name = html_escape(&task.name)
+    );
+
     maud! {
         li .task-list-item {
+            (Raw::dangerously_create(&select_html))
             (Raw::dangerously_create(&format!(
                 r##"<button class="btn" hx-get="{}" hx-target="#modal-container" hx-swap="innerHTML">Edit</button>"##,
                 edit_url
@@ -2803,7 +5355,7 @@ fn render_task_list_item(task: &DemoTask, is_touch: bool) -> String {
 }
 
 /// Simple HTML escaping for task names
-fn html_escape(s: &str) -> String {
+pub fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -2811,7 +5363,7 @@ fn html_escape(s: &str) -> String {
 }
 
 fn render_task_modal(task: &DemoTask) -> String {
-    let editor_html = render_task_editor_inner(task, true, false, None, &FormErrors::default());
+    let editor_html = render_task_editor_inner(task, true, false, None, &FormErrors::default(), "");
 
     maud! {
         div .modal-overlay {
@@ -2823,7 +5375,7 @@ fn render_task_modal(task: &DemoTask) -> String {
 }
 
 fn render_task_modal_with_errors(task: &DemoTask, form: &TaskForm, errors: &FormErrors) -> String {
-    let editor_html = render_task_editor_inner(task, true, false, Some(form), errors);
+    let editor_html = render_task_editor_inner(task, true, false, Some(form), errors, "");
 
     maud! {
         div .modal-overlay {
@@ -2834,8 +5386,8 @@ fn render_task_modal_with_errors(task: &DemoTask, form: &TaskForm, errors: &Form
     .into_inner()
 }
 
-fn render_new_task_modal(task: &DemoTask) -> String {
-    let editor_html = render_task_editor_inner(task, true, true, None, &FormErrors::default());
+fn render_new_task_modal(task: &DemoTask, templates: &[db::DbTemplate]) -> String {
+    let editor_html = render_task_editor_inner(task, true, true, None, &FormErrors::default(), &render_template_picker(templates));
 
     maud! {
         div .modal-overlay {
@@ -2847,7 +5399,7 @@ fn render_new_task_modal(task: &DemoTask) -> String {
 }
 
 fn render_new_task_modal_with_errors(task: &DemoTask, form: &TaskForm, errors: &FormErrors) -> String {
-    let editor_html = render_task_editor_inner(task, true, true, Some(form), errors);
+    let editor_html = render_task_editor_inner(task, true, true, Some(form), errors, "");
 
     maud! {
         div .modal-overlay {
@@ -2858,11 +5410,72 @@ fn render_new_task_modal_with_errors(task: &DemoTask, form: &TaskForm, errors: &
     .into_inner()
 }
 
+/// Full page wrapping the new task form, for browsers without JS or direct links to /tasks/new.
+/// The form lives in #modal-container so its HTMX swaps behave the same as in the modal.
+fn render_new_task_page(modal_html: &str, is_touch: bool) -> String {
+    maud! {
+        !DOCTYPE
+        html {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "New Task - Chores" }
+                link rel="stylesheet" href="/static/system.css";
+                link rel="stylesheet" href="/static/app.css";
+                script src="/static/htmx.min.js" {}
+            }
+            body {
+                div .tasks-page {
+                    div .tasks-page-header {
+                        a href="/tasks" { "← Back to Tasks" }
+                    }
+                    div #modal-container {
+                        (Raw::dangerously_create(modal_html))
+                    }
+                }
+                (Raw::dangerously_create(&pages::render_bottom_nav(is_touch, pages::NavItem::NewTask)))
+            }
+        }
+    }
+    .render()
+    .into_inner()
+}
+
+/// "New from template" select shown at the top of the new task modal
+fn render_template_picker(templates: &[db::DbTemplate]) -> String {
+    if templates.is_empty() {
+        return String::new();
+    }
+
+    let options: String = templates
+        .iter()
+        .map(|t| format!(r#"<option value="{}">{}</option>"#, t.id, html_escape(&t.name)))
+        .collect();
+
+    format!(
+        r##"<div class="form-group template-picker">
+            <label for="template-select">New from template</label>
+            <select id="template-select" name="template" hx-get="/tasks/new" hx-target="#modal-container" hx-swap="innerHTML">
+                <option value="">Blank task</option>
+                {}
+            </select>
+        </div>"##,
+        options
+    )
+}
+
 pub fn render_task_editor(task: &DemoTask) -> String {
-    render_task_editor_inner(task, false, false, None, &FormErrors::default())
+    render_task_editor_inner(task, false, false, None, &FormErrors::default(), "")
 }
 
-fn render_task_editor_inner(task: &DemoTask, is_modal: bool, is_new: bool, form: Option<&TaskForm>, errors: &FormErrors) -> String {
+fn render_task_editor_inner(
+    task: &DemoTask,
+    is_modal: bool,
+    is_new: bool,
+    form: Option<&TaskForm>,
+    errors: &FormErrors,
+    preamble: &str,
+) -> String {
     let schedule_label = match task.schedule_kind {
         ScheduleKind::NDays => "Every N Days",
         ScheduleKind::NWeeks => "Weekly",
@@ -2878,9 +5491,32 @@ fn render_task_editor_inner(task: &DemoTask, is_modal: bool, is_new: bool, form:
     // Get raw form value for monthwise_days if there's an error (to preserve user input)
     let raw_monthwise_days = form.and_then(|f| f.monthwise_days.clone());
     let raw_cm_days = form.and_then(|f| f.cm_days.clone());
-
-    let schedule_editor_html = match task.schedule_kind {
-        ScheduleKind::NDays => render_n_days_editor(&id_suffix, &task.n_days),
+    let complete_label_value = form
+        .and_then(|f| f.complete_label.clone())
+        .unwrap_or_else(|| task.complete_label.clone());
+    let name_error_class = if errors.name.is_some() { "input-error" } else { "" };
+    let name_error_html = errors.name.as_ref().map(|msg| {
+        format!(r#"<div class="field-error-message" style="color: #c00; margin-bottom: 4px; font-size: 13px;">{}</div>"#, html_escape(msg))
+    }).unwrap_or_default();
+    let complete_label_error_class = if errors.complete_label.is_some() { "input-error" } else { "" };
+    // Only a scheduled (future) end is editable here; trashed tasks are managed via delete/restore
+    let active_until_value = form
+        .and_then(|f| f.active_until.clone())
+        .unwrap_or_else(|| {
+            task.scheduled_end()
+                .map(|end| end.with_timezone(&get_timezone()).format("%Y-%m-%d").to_string())
+                .unwrap_or_default()
+        });
+    let active_until_error_class = if errors.active_until.is_some() { "input-error" } else { "" };
+    let active_until_error_html = errors.active_until.as_ref().map(|msg| {
+        format!(r#"<div class="field-error-message" style="color: #c00; margin-bottom: 4px; font-size: 13px;">{}</div>"#, html_escape(msg))
+    }).unwrap_or_default();
+    let complete_label_error_html = errors.complete_label.as_ref().map(|msg| {
+        format!(r#"<div class="field-error-message" style="color: #c00; margin-bottom: 4px; font-size: 13px;">{}</div>"#, html_escape(msg))
+    }).unwrap_or_default();
+
+    let schedule_editor_html = match task.schedule_kind {
+        ScheduleKind::NDays => render_n_days_editor(&id_suffix, &task.n_days),
         ScheduleKind::NWeeks => render_n_weeks_editor(&id_suffix, &task.n_weeks),
         ScheduleKind::Monthwise => render_monthwise_editor(&id_suffix, &task.monthwise, raw_monthwise_days.as_deref(), &errors.monthwise_days),
         ScheduleKind::WeeksOfMonth => render_weeks_of_month_editor(&id_suffix, &task.weeks_of_month),
@@ -2897,6 +5533,7 @@ fn render_task_editor_inner(task: &DemoTask, is_modal: bool, is_new: bool, form:
 
     let name_id = format!("task-name-{}", id_suffix);
     let details_id = format!("task-details-{}", id_suffix);
+    let instructions_id = format!("task-instructions-{}", id_suffix);
     let schedule_type_id = format!("task-schedule-type-{}", id_suffix);
     let editor_id = format!("task-editor-{}", id_suffix);
 
@@ -2930,7 +5567,7 @@ fn render_task_editor_inner(task: &DemoTask, is_modal: bool, is_new: bool, form:
     // Save button - for modal, server returns reload trigger on success; for standalone, swap in place
     let save_button = if is_modal {
         format!(
-            r##"<button class="btn btn-default" type="button" hx-post="{}" hx-target="{}" hx-swap="innerHTML" hx-include="closest form">Save</button>"##,
+            r##"<button class="btn btn-default" type="submit" hx-post="{}" hx-target="{}" hx-swap="innerHTML" hx-include="closest form">Save</button>"##,
             hx_save_post, hx_target
         )
     } else {
@@ -2964,13 +5601,16 @@ fn render_task_editor_inner(task: &DemoTask, is_modal: bool, is_new: bool, form:
             div .separator {}
 
             div .window-pane {
-                form {
+                (Raw::dangerously_create(preamble))
+                form method="post" action=(hx_save_post) {
                     div .form-group {
                         label for=(name_id) { "Name" }
+                        (Raw::dangerously_create(&name_error_html))
                         input
                             type="text"
                             id=(name_id)
                             name="name"
+                            class=(name_error_class)
                             value=(task.name);
                     }
 
@@ -2982,8 +5622,21 @@ fn render_task_editor_inner(task: &DemoTask, is_modal: bool, is_new: bool, form:
                         { (task.details) }
                     }
 
+                    div .form-group {
+                        label for=(instructions_id) { "Instructions" }
+                        textarea
+                            id=(instructions_id)
+                            name="instructions"
+                            placeholder="How to do it, e.g. filter model, water off first"
+                        { (task.instructions) }
+                        small style="display: block; color: #666; margin-top: 4px;" {
+                            "Shown on the task page and when completing, not on cards"
+                        }
+                    }
+
                     div .form-group {
                         label for=(schedule_type_id) { "Schedule Type" }
+                        input type="hidden" name="prev_schedule_type" value=(task.schedule_kind.as_str());
                         (Raw::dangerously_create(&render_schedule_type_select(
                             &schedule_type_id,
                             &hx_schedule_type_post,
@@ -3002,11 +5655,53 @@ fn render_task_editor_inner(task: &DemoTask, is_modal: bool, is_new: bool, form:
                         (Raw::dangerously_create(&schedule_editor_html))
                     }
 
+                    div .form-group {
+                        div .field-row {
+                            @if task.all_day {
+                                input type="checkbox" id=(format!("all-day-{}", id_suffix)) name="all_day" checked;
+                            } @else {
+                                input type="checkbox" id=(format!("all-day-{}", id_suffix)) name="all_day";
+                            }
+                            label for=(format!("all-day-{}", id_suffix)) { "All day" }
+                        }
+                        small style="display: block; color: #666; margin-top: 4px; margin-left: 20px;" {
+                            "Due any time on the day; the time above is ignored"
+                        }
+                    }
+
                     div .form-group {
                         label for=(format!("alerting-time-{}", id_suffix)) { "Alert Before Due" }
                         (Raw::dangerously_create(&render_alerting_time_input(&id_suffix, task.alerting_time)))
                     }
 
+                    div .form-group {
+                        label for=(format!("show-ahead-days-{}", id_suffix)) { "Show Ahead (days)" }
+                        input
+                            type="number"
+                            id=(format!("show-ahead-days-{}", id_suffix))
+                            name="show_ahead_days"
+                            min="0"
+                            max=(MAX_SHOW_AHEAD_DAYS)
+                            value=(task.show_ahead_days);
+                        small style="display: block; color: #666; margin-top: 4px;" {
+                            "List the task under Planning on the dashboard this many days before it's due, without alerting. 0 turns this off."
+                        }
+                    }
+
+                    // Recurring occurrences move on at the next due time, so only one-time
+                    // and all-day tasks have a grace period to offer
+                    @if task.grace_applies() {
+                        div .form-group {
+                            label for=(format!("grace-minutes-{}", id_suffix)) { "Grace Period" }
+                            (Raw::dangerously_create(&render_grace_minutes_input(&id_suffix, task.grace_minutes)))
+                        }
+                    }
+
+                    div .form-group {
+                        label for=(format!("notify-channels-{}", id_suffix)) { "Reminders" }
+                        (Raw::dangerously_create(&render_notify_channels_input(&id_suffix, &task.notify_channels)))
+                    }
+
                     div .form-group {
                         div .field-row {
                             @if task.completeable {
@@ -3021,11 +5716,60 @@ fn render_task_editor_inner(task: &DemoTask, is_modal: bool, is_new: bool, form:
                         }
                     }
 
+                    div .form-group {
+                        label for=(format!("target-count-{}", id_suffix)) { "Times per Occurrence" }
+                        input
+                            type="number"
+                            id=(format!("target-count-{}", id_suffix))
+                            name="target_count"
+                            min="1"
+                            max=(MAX_TARGET_COUNT)
+                            value=(task.target_count);
+                        small style="display: block; color: #666; margin-top: 4px;" {
+                            "More than 1 makes this a counter, e.g. 8 for \"drink a glass of water\". Each completion counts once."
+                        }
+                    }
+
+                    div .form-group {
+                        label for=(format!("complete-label-{}", id_suffix)) { "Button Label" }
+                        (Raw::dangerously_create(&complete_label_error_html))
+                        input
+                            type="text"
+                            id=(format!("complete-label-{}", id_suffix))
+                            name="complete_label"
+                            class=(complete_label_error_class)
+                            maxlength=(MAX_COMPLETE_LABEL_LEN)
+                            placeholder="Complete"
+                            value=(complete_label_value);
+                        small style="display: block; color: #666; margin-top: 4px;" {
+                            "e.g. \"Acknowledge\" or \"Done for today\". Leave blank for \"Complete\"."
+                        }
+                    }
+
+                    div .form-group {
+                        label for=(format!("active-until-{}", id_suffix)) { "Active Until" }
+                        (Raw::dangerously_create(&active_until_error_html))
+                        input
+                            type="date"
+                            id=(format!("active-until-{}", id_suffix))
+                            name="active_until"
+                            class=(active_until_error_class)
+                            value=(active_until_value);
+                        small style="display: block; color: #666; margin-top: 4px;" {
+                            "The task retires after this day. Leave blank to keep it indefinitely."
+                        }
+                    }
+
                     div .form-group style="margin-top: 16px;" {
                         @if errors.has_errors() {
                             div .form-error-message style="margin-bottom: 12px; color: #c00; text-align: center;" {
                                 "Please fix the error(s) and resave"
                             }
+                        } @else if let Some(warning) = &errors.duplicate_warning {
+                            div .form-warning-message style="margin-bottom: 12px; color: #8a5a00; text-align: center;" {
+                                (warning) " Save again to create it anyway."
+                            }
+                            input type="hidden" name="create_anyway" value="1";
                         }
                         div style="text-align: right;" {
                             (Raw::dangerously_create(&cancel_button))
@@ -3123,6 +5867,104 @@ fn render_alerting_time_input(task_id: &str, alerting_time: i64) -> String {
     )
 }
 
+fn render_grace_minutes_input(task_id: &str, grace_minutes: i64) -> String {
+    let input_id = format!("grace-minutes-{}", task_id);
+
+    let presets = [
+        (0, "None"),
+        (15, "15 minutes"),
+        (30, "30 minutes"),
+        (60, "1 hour"),
+        (120, "2 hours"),
+        (360, "6 hours"),
+        (720, "12 hours"),
+        (1440, "1 day"),
+    ];
+
+    let mut options = String::new();
+    let mut found_preset = false;
+
+    for (minutes, label) in presets {
+        let selected = if minutes == grace_minutes {
+            found_preset = true;
+            " selected"
+        } else {
+            ""
+        };
+        options.push_str(&format!(r#"<option value="{}"{}>{}</option>"#, minutes, selected, label));
+    }
+
+    // If current value doesn't match a preset, add it as a custom option
+    if !found_preset {
+        options.push_str(&format!(
+            r#"<option value="{}" selected>{} (custom)</option>"#,
+            grace_minutes, format_alerting_time(grace_minutes)
+        ));
+    }
+
+    format!(
+        r##"<div class="inline-field alerting-time-field">
+            <select id="{}" name="grace_minutes" class="alerting-time-select">
+                {}
+            </select>
+            <span class="alerting-time-help">(task stays "Upcoming" this long after the due time)</span>
+        </div>"##,
+        input_id, options
+    )
+}
+
+/// Label for a task's notify_channels value; empty means the global default
+fn describe_notify_channels(channels: &str) -> String {
+    if channels.trim().is_empty() {
+        "default".to_string()
+    } else {
+        channels.to_string()
+    }
+}
+
+fn render_notify_channels_input(task_id: &str, notify_channels: &str) -> String {
+    let input_id = format!("notify-channels-{}", task_id);
+    let current = notify_channels.trim();
+
+    let default_label = if get_notify_channels().is_empty() {
+        "Default (none)"
+    } else {
+        "Default (webhook)"
+    };
+    let presets = [("", default_label), ("webhook", "Webhook"), ("none", "None")];
+
+    let mut options = String::new();
+    let mut found_preset = false;
+
+    for (value, label) in presets {
+        let selected = if value == current {
+            found_preset = true;
+            " selected"
+        } else {
+            ""
+        };
+        options.push_str(&format!(r#"<option value="{}"{}>{}</option>"#, value, selected, label));
+    }
+
+    // Keep hand-entered values (e.g. from seed files) selectable
+    if !found_preset {
+        options.push_str(&format!(
+            r#"<option value="{0}" selected>{0} (custom)</option>"#,
+            html_escape(current)
+        ));
+    }
+
+    format!(
+        r##"<div class="inline-field alerting-time-field">
+            <select id="{}" name="notify_channels" class="alerting-time-select">
+                {}
+            </select>
+            <span class="alerting-time-help">(where this task's reminders are sent)</span>
+        </div>"##,
+        input_id, options
+    )
+}
+
 fn format_alerting_time(minutes: i64) -> String {
     if minutes == 0 {
         "None".to_string()
@@ -3143,7 +5985,13 @@ fn format_alerting_time(minutes: i64) -> String {
 fn render_n_days_editor(task_id: &str, n_days: &NDays) -> String {
     let count_id = format!("n-days-count-{}", task_id);
     let time_id = format!("n-days-time-{}", task_id);
+    let weekends_id = format!("n-days-skip-weekends-{}", task_id);
     let time_value = n_days.time.format("%H:%M").to_string();
+    let weekend_options = [
+        (SkipWeekends::Off, "Include weekends"),
+        (SkipWeekends::CalendarDays, "Move weekend days to Monday"),
+        (SkipWeekends::WeekdaysOnly, "Count weekdays only"),
+    ];
 
     maud! {
         div .form-group {
@@ -3168,6 +6016,17 @@ fn render_n_days_editor(task_id: &str, n_days: &NDays) -> String {
                     value=(time_value);
             }
         }
+        div .form-group {
+            div .inline-field {
+                label for=(weekends_id) { "Weekends" }
+                select id=(weekends_id) name="n_days_skip_weekends" {
+                    @for (mode, label) in weekend_options {
+                        option value=(mode.as_str()) selected[mode == n_days.skip_weekends] { (label) }
+                    }
+                }
+            }
+            p .form-help { "Moving keeps the day count; counting weekdays only means \"every 2 days\" is every other weekday." }
+        }
     }
     .render()
     .into_inner()
@@ -3302,17 +6161,18 @@ fn render_monthwise_editor(task_id: &str, monthwise: &Monthwise, raw_days: Optio
         format!(r#"<div class="field-error-message" style="color: #c00; margin-bottom: 4px; font-size: 13px;">{}</div>"#, msg)
     }).unwrap_or_default();
 
+    // Typing in the field asks the server how it will be read and shows the answer underneath
+    let preview_id = format!("monthwise-days-preview-{}", task_id);
+    let days_input_html = format!(
+        r##"<input type="text" id="{}" name="monthwise_days" class="{}" placeholder="e.g. 1, 4-7, 15" value="{}" hx-post="/tasks/parse-days" hx-trigger="input changed delay:300ms" hx-target="#{}" hx-swap="innerHTML"><div class="day-range-preview" id="{}" aria-live="polite"></div>"##,
+        days_id, error_class.trim(), html_escape(&days_str), preview_id, preview_id
+    );
+
     maud! {
         div .form-group {
             label for=(days_id) { "On day(s) of month:" }
             (Raw::dangerously_create(&error_html))
-            input
-                type="text"
-                id=(days_id)
-                name="monthwise_days"
-                class=(error_class)
-                placeholder="e.g. 1, 4-7, 15"
-                value=(days_str);
+            (Raw::dangerously_create(&days_input_html))
             small style="display: block; color: #666; margin-top: 4px;" {
                 "Days or ranges (e.g. 1, 4-7, 15-17)"
             }
@@ -3467,6 +6327,13 @@ fn render_certain_months_editor(task_id: &str, certain_months: &CertainMonths, r
         format!(r#"<div class="field-error-message" style="color: #c00; margin-bottom: 4px; font-size: 13px;">{}</div>"#, msg)
     }).unwrap_or_default();
 
+    // Typing in the field asks the server how it will be read and shows the answer underneath
+    let preview_id = format!("cm-days-preview-{}", task_id);
+    let days_input_html = format!(
+        r##"<input type="text" id="{}" name="cm_days" class="{}" placeholder="e.g. 1, 4-7, 15" value="{}" hx-post="/tasks/parse-days" hx-trigger="input changed delay:300ms" hx-target="#{}" hx-swap="innerHTML"><div class="day-range-preview" id="{}" aria-live="polite"></div>"##,
+        days_id, error_class.trim(), html_escape(&days_str), preview_id, preview_id
+    );
+
     let month_names = [
         ("jan", "Jan", 1), ("feb", "Feb", 2), ("mar", "Mar", 3), ("apr", "Apr", 4),
         ("may", "May", 5), ("jun", "Jun", 6), ("jul", "Jul", 7), ("aug", "Aug", 8),
@@ -3504,13 +6371,7 @@ fn render_certain_months_editor(task_id: &str, certain_months: &CertainMonths, r
         div .form-group {
             label for=(days_id) { "On day(s) of month:" }
             (Raw::dangerously_create(&error_html))
-            input
-                type="text"
-                id=(days_id)
-                name="cm_days"
-                class=(error_class)
-                placeholder="e.g. 1, 4-7, 15"
-                value=(days_str);
+            (Raw::dangerously_create(&days_input_html))
             small style="display: block; color: #666; margin-top: 4px;" {
                 "Days or ranges (e.g. 1, 4-7, 15-17)"
             }
@@ -3588,6 +6449,7 @@ fn render_once_editor(task_id: &str, once: &Once) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::db::test_support::{insert_task, test_pool};
 
     // ========================================================================
     // parse_day_range tests
@@ -3760,303 +6622,1809 @@ mod tests {
     }
 
     #[test]
-    fn test_format_multiple_ranges() {
-        assert_eq!(format_day_range(&[1, 2, 3, 10, 11, 12]), "1-3, 10-12");
-        assert_eq!(format_day_range(&[1, 2, 5, 6, 7, 10]), "1-2, 5-7, 10");
+    fn test_format_multiple_ranges() {
+        assert_eq!(format_day_range(&[1, 2, 3, 10, 11, 12]), "1-3, 10-12");
+        assert_eq!(format_day_range(&[1, 2, 5, 6, 7, 10]), "1-2, 5-7, 10");
+    }
+
+    #[test]
+    fn test_format_mixed() {
+        assert_eq!(
+            format_day_range(&[1, 4, 5, 6, 7, 10]),
+            "1, 4-7, 10"
+        );
+        assert_eq!(
+            format_day_range(&[1, 2, 4, 5, 6, 7, 10, 15, 16, 17]),
+            "1-2, 4-7, 10, 15-17"
+        );
+    }
+
+    #[test]
+    fn test_format_unsorted_input() {
+        // Should handle unsorted input
+        assert_eq!(format_day_range(&[15, 1, 10]), "1, 10, 15");
+        assert_eq!(format_day_range(&[5, 3, 1, 2, 4]), "1-5");
+    }
+
+    #[test]
+    fn test_format_with_duplicates() {
+        // Should handle duplicates
+        assert_eq!(format_day_range(&[1, 1, 2, 2, 3]), "1-3");
+        assert_eq!(format_day_range(&[5, 5, 5]), "5");
+    }
+
+    #[test]
+    fn test_format_all_days() {
+        let all_days: Vec<i32> = (1..=31).collect();
+        assert_eq!(format_day_range(&all_days), "1-31");
+    }
+
+    #[test]
+    fn test_format_complex_pattern() {
+        // First, 15th, and last week of month
+        assert_eq!(
+            format_day_range(&[1, 2, 3, 4, 5, 6, 7, 15, 25, 26, 27, 28, 29, 30, 31]),
+            "1-7, 15, 25-31"
+        );
+    }
+
+    // ========================================================================
+    // Round-trip tests (parse -> format -> parse)
+    // ========================================================================
+
+    #[test]
+    fn test_roundtrip_simple() {
+        let inputs = vec![
+            "1",
+            "1, 15",
+            "1-5",
+            "1, 4-7, 10",
+            "1-2, 4-7, 10, 15-17",
+        ];
+
+        for input in inputs {
+            let parsed = parse_day_range(input).unwrap();
+            let formatted = format_day_range(&parsed);
+            let reparsed = parse_day_range(&formatted).unwrap();
+            assert_eq!(parsed, reparsed, "Round-trip failed for: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_normalizes() {
+        // Input with redundancy should normalize
+        let parsed = parse_day_range("5, 1-5, 3").unwrap();
+        let formatted = format_day_range(&parsed);
+        assert_eq!(formatted, "1-5");
+    }
+
+    // ========================================================================
+    // Open-ended day range tests
+    // ========================================================================
+
+    #[test]
+    fn test_open_ended_ranges_parse_and_round_trip() {
+        assert_eq!(parse_day_range("20-").unwrap(), vec![-20]);
+        assert_eq!(parse_day_range("-5").unwrap(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(parse_day_range("1, 25-").unwrap(), vec![1, -25]);
+        // Days the open range already covers, or that run into it, fold in
+        assert_eq!(parse_day_range("28, 15-19, 20-, 25-").unwrap(), vec![-15]);
+        assert_eq!(format_day_range(&parse_day_range("1, 25-").unwrap()), "1, 25-");
+        assert_eq!(format_day_range(&[-20]), "20-");
+        assert!(parse_day_range("-").is_err());
+        assert!(parse_day_range("32-").is_err());
+        assert!(parse_day_range("0-").is_err());
+    }
+
+    #[test]
+    fn test_open_ended_range_resolves_per_month() {
+        use chrono::NaiveDate;
+
+        let mut task = create_default_task();
+        task.schedule_kind = ScheduleKind::Monthwise;
+        task.monthwise.days = parse_day_range("28-").unwrap();
+        task.created_at = None;
+        let due = |task: &DemoTask, y, m, d| is_due_on_date(task, NaiveDate::from_ymd_opt(y, m, d).unwrap());
+
+        assert!(!due(&task, 2026, 2, 27));
+        assert!(due(&task, 2026, 2, 28));
+        assert!(due(&task, 2028, 2, 29));
+        assert!(due(&task, 2026, 3, 31));
+        assert!(!due(&task, 2026, 4, 1));
+        assert_eq!(describe_schedule(&task), "Monthly on day(s) 28-");
+
+        task.schedule_kind = ScheduleKind::CertainMonths;
+        task.certain_months.months = vec![2];
+        task.certain_months.days = parse_day_range("1, 28-").unwrap();
+        assert!(due(&task, 2026, 2, 1) && due(&task, 2026, 2, 28));
+        assert!(!due(&task, 2026, 3, 31));
+    }
+
+    // ========================================================================
+    // FormErrors tests
+    // ========================================================================
+
+    #[test]
+    fn test_form_errors_default() {
+        let errors = FormErrors::default();
+        assert!(!errors.has_errors());
+        assert!(errors.monthwise_days.is_none());
+        assert!(errors.general.is_none());
+    }
+
+    #[test]
+    fn test_form_errors_with_monthwise_error() {
+        let errors = FormErrors {
+            name: None,
+            monthwise_days: Some("Invalid day format".to_string()),
+            certain_months_days: None,
+            complete_label: None,
+            active_until: None,
+            general: None,
+            duplicate_warning: None,
+        };
+        assert!(errors.has_errors());
+    }
+
+    #[test]
+    fn test_form_errors_with_general_error() {
+        let errors = FormErrors {
+            name: None,
+            monthwise_days: None,
+            certain_months_days: None,
+            complete_label: None,
+            active_until: None,
+            general: Some("Something went wrong".to_string()),
+            duplicate_warning: None,
+        };
+        assert!(errors.has_errors());
+    }
+
+    #[test]
+    fn test_form_errors_with_multiple_errors() {
+        let errors = FormErrors {
+            name: None,
+            monthwise_days: Some("Invalid day".to_string()),
+            certain_months_days: None,
+            complete_label: None,
+            active_until: None,
+            general: Some("General error".to_string()),
+            duplicate_warning: None,
+        };
+        assert!(errors.has_errors());
+    }
+
+    #[test]
+    fn test_form_errors_with_certain_months_error() {
+        let errors = FormErrors {
+            name: None,
+            monthwise_days: None,
+            certain_months_days: Some("Invalid day format".to_string()),
+            complete_label: None,
+            active_until: None,
+            general: None,
+            duplicate_warning: None,
+        };
+        assert!(errors.has_errors());
+    }
+
+    // ========================================================================
+    // TaskForm validation tests
+    // ========================================================================
+
+    #[test]
+    fn test_task_form_validate_valid_monthwise() {
+        let form = TaskForm {
+            name: "Test Task".to_string(),
+            details: String::new(),
+            schedule_type: "monthwise".to_string(),
+            monthwise_days: Some("1, 15".to_string()),
+            monthwise_time: Some("10:00".to_string()),
+            ..Default::default()
+        };
+
+        let errors = form.validate();
+        assert!(!errors.has_errors());
+    }
+
+    #[test]
+    fn test_task_form_validate_invalid_monthwise_format() {
+        let form = TaskForm {
+            name: "Test Task".to_string(),
+            details: String::new(),
+            schedule_type: "monthwise".to_string(),
+            monthwise_days: Some("abc, xyz".to_string()),
+            monthwise_time: Some("10:00".to_string()),
+            ..Default::default()
+        };
+
+        let errors = form.validate();
+        assert!(errors.has_errors());
+        assert!(errors.monthwise_days.is_some());
+    }
+
+    #[test]
+    fn test_task_form_validate_monthwise_out_of_range() {
+        let form = TaskForm {
+            name: "Test Task".to_string(),
+            details: String::new(),
+            schedule_type: "monthwise".to_string(),
+            monthwise_days: Some("1, 32".to_string()),
+            monthwise_time: Some("10:00".to_string()),
+            ..Default::default()
+        };
+
+        let errors = form.validate();
+        assert!(errors.has_errors());
+        assert!(errors.monthwise_days.is_some());
+        assert!(errors.monthwise_days.as_ref().unwrap().contains("out of range"));
+    }
+
+    #[test]
+    fn test_task_form_validate_monthwise_empty() {
+        let form = TaskForm {
+            name: "Test Task".to_string(),
+            details: String::new(),
+            schedule_type: "monthwise".to_string(),
+            monthwise_days: Some("".to_string()),
+            monthwise_time: Some("10:00".to_string()),
+            ..Default::default()
+        };
+
+        let errors = form.validate();
+        assert!(errors.has_errors());
+        assert!(errors.monthwise_days.is_some());
+    }
+
+    #[test]
+    fn test_task_form_validate_monthwise_none() {
+        let form = TaskForm {
+            name: "Test Task".to_string(),
+            details: String::new(),
+            schedule_type: "monthwise".to_string(),
+            monthwise_days: None,
+            monthwise_time: Some("10:00".to_string()),
+            ..Default::default()
+        };
+
+        let errors = form.validate();
+        assert!(errors.has_errors());
+        assert!(errors.monthwise_days.is_some());
+    }
+
+    #[test]
+    fn test_task_form_validate_non_monthwise_schedule() {
+        // When schedule type is not monthwise, monthwise_days is not validated
+        let form = TaskForm {
+            name: "Test Task".to_string(),
+            details: String::new(),
+            schedule_type: "n_days".to_string(),
+            monthwise_days: Some("invalid".to_string()), // This should be ignored
+            n_days_count: Some(7),
+            ..Default::default()
+        };
+
+        let errors = form.validate();
+        assert!(!errors.has_errors());
+    }
+
+    #[test]
+    fn test_task_form_validate_complete_label_length() {
+        let mut form = TaskForm {
+            name: "Test Task".to_string(),
+            schedule_type: "n_days".to_string(),
+            complete_label: Some("  Done for today  ".to_string()),
+            ..Default::default()
+        };
+        assert!(!form.validate().has_errors());
+
+        form.complete_label = Some("x".repeat(MAX_COMPLETE_LABEL_LEN + 1));
+        let errors = form.validate();
+        assert!(errors.complete_label.is_some());
+        assert!(errors.has_errors());
+    }
+
+    #[test]
+    fn test_task_form_validate_name() {
+        let mut form = TaskForm {
+            name: "   ".to_string(),
+            schedule_type: "n_days".to_string(),
+            ..Default::default()
+        };
+        let errors = form.validate();
+        assert!(errors.name.is_some());
+        assert!(errors.has_errors());
+
+        form.name = "x".repeat(MAX_NAME_LEN + 1);
+        assert!(form.validate().name.is_some());
+
+        form.name = "  Dishes ".to_string();
+        assert!(!form.validate().has_errors());
+    }
+
+    #[test]
+    fn test_completion_button_label_default() {
+        let mut task = create_default_task();
+        assert_eq!(task.completion_button_label(), "Complete");
+        task.complete_label = "Acknowledge".to_string();
+        assert_eq!(task.completion_button_label(), "Acknowledge");
+    }
+
+    #[test]
+    fn test_task_form_validate_monthwise_with_ranges() {
+        let form = TaskForm {
+            name: "Test Task".to_string(),
+            details: String::new(),
+            schedule_type: "monthwise".to_string(),
+            monthwise_days: Some("1-5, 10, 15-20".to_string()),
+            monthwise_time: Some("10:00".to_string()),
+            ..Default::default()
+        };
+
+        let errors = form.validate();
+        assert!(!errors.has_errors());
+    }
+
+    #[test]
+    fn test_task_form_validate_monthwise_invalid_range() {
+        let form = TaskForm {
+            name: "Test Task".to_string(),
+            details: String::new(),
+            schedule_type: "monthwise".to_string(),
+            monthwise_days: Some("10-5".to_string()), // reversed range
+            monthwise_time: Some("10:00".to_string()),
+            ..Default::default()
+        };
+
+        let errors = form.validate();
+        assert!(errors.has_errors());
+        assert!(errors.monthwise_days.is_some());
+    }
+
+    // ========================================================================
+    // Edge cases and boundary tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_boundary_days() {
+        // Minimum valid day
+        assert_eq!(parse_day_range("1").unwrap(), vec![1]);
+        
+        // Maximum valid day
+        assert_eq!(parse_day_range("31").unwrap(), vec![31]);
+        
+        // Full range
+        assert_eq!(parse_day_range("1-31").unwrap().len(), 31);
+    }
+
+    #[test]
+    fn test_format_preserves_order() {
+        // Even if given in weird order, output should be ascending
+        assert_eq!(format_day_range(&[31, 1, 15, 10, 5]), "1, 5, 10, 15, 31");
+    }
+
+    #[test]
+    fn test_parse_long_list() {
+        // Stress test with many items
+        let input = (1..=31).map(|d| d.to_string()).collect::<Vec<_>>().join(", ");
+        let result = parse_day_range(&input).unwrap();
+        assert_eq!(result.len(), 31);
+    }
+
+    #[test]
+    fn test_format_efficiency() {
+        // The formatter should produce the most compact representation
+        // [1,2,3,4,5] should be "1-5" not "1, 2, 3, 4, 5"
+        let days: Vec<i32> = (1..=10).collect();
+        let formatted = format_day_range(&days);
+        assert_eq!(formatted, "1-10");
+        assert!(formatted.len() < 30); // Much shorter than listing all
+    }
+
+    #[test]
+    fn test_day_range_preview() {
+        let ok = render_day_range_preview("15, 4-7, 1, 5");
+        assert!(ok.contains("→ 1, 4-7, 15"));
+        assert!(!ok.contains("field-error-message"));
+
+        let err = render_day_range_preview("4-<b>");
+        assert!(err.contains("field-error-message"));
+        assert!(err.contains("Invalid number: '&lt;b&gt;'"));
+        assert!(!err.contains("<b>"));
+    }
+
+    // ========================================================================
+    // Completion duration tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_duration_minutes() {
+        assert_eq!(parse_duration_minutes(None), None);
+        assert_eq!(parse_duration_minutes(Some("")), None);
+        assert_eq!(parse_duration_minutes(Some(" 25 ")), Some(25));
+        assert_eq!(parse_duration_minutes(Some("0")), None);
+        assert_eq!(parse_duration_minutes(Some("abc")), None);
+        assert_eq!(parse_duration_minutes(Some("1441")), None);
+    }
+
+    #[test]
+    fn test_format_duration_minutes() {
+        assert_eq!(format_duration_minutes(0), "0m");
+        assert_eq!(format_duration_minutes(45), "45m");
+        assert_eq!(format_duration_minutes(120), "2h");
+        assert_eq!(format_duration_minutes(200), "3h 20m");
+    }
+
+    #[test]
+    fn test_parse_note() {
+        assert_eq!(parse_note(None), None);
+        assert_eq!(parse_note(Some("   ")), None);
+        assert_eq!(parse_note(Some(" Used the blue filter ")).as_deref(), Some("Used the blue filter"));
+        assert_eq!(parse_note(Some(&"x".repeat(MAX_NOTE_LEN + 10))).unwrap().chars().count(), MAX_NOTE_LEN);
+    }
+
+    #[test]
+    fn test_completion_note_shown_escaped() {
+        let mut completion = completion_at(Utc::now());
+        completion.note = Some("<b>low on salt</b>".to_string());
+        let html = render_completions_list("1", &[completion], false);
+        assert!(html.contains("completion-note"));
+        assert!(html.contains("&lt;b&gt;low on salt&lt;/b&gt;"));
+    }
+
+    // ========================================================================
+    // History timeline tests
+    // ========================================================================
+
+    #[test]
+    fn test_history_day_label() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        assert_eq!(history_day_label(today, today), "Today");
+        assert_eq!(history_day_label(today - Duration::days(1), today), "Yesterday");
+        assert_eq!(history_day_label(today - Duration::days(2), today), "Sunday, March 8, 2026");
+    }
+
+    // ========================================================================
+    // Completion timestamp formatting tests
+    // ========================================================================
+
+    #[test]
+    fn test_format_completion_time_non_utc() {
+        let tz: chrono_tz::Tz = "America/Chicago".parse().unwrap();
+        let completed_at = Utc.with_ymd_and_hms(2026, 1, 15, 15, 4, 5).unwrap();
+        assert_eq!(
+            format_completion_time(completed_at, &tz, TimeFormat::Minutes),
+            "Thursday, January 15, 2026 at 09:04 CST"
+        );
+        assert_eq!(
+            format_completion_time(completed_at, &tz, TimeFormat::Seconds),
+            "Thursday, January 15, 2026 at 09:04:05 CST"
+        );
+    }
+
+    #[test]
+    fn test_format_completion_time_daylight_saving() {
+        let tz: chrono_tz::Tz = "America/Chicago".parse().unwrap();
+        let completed_at = Utc.with_ymd_and_hms(2026, 7, 1, 2, 30, 0).unwrap();
+        // 02:30 UTC is still the previous evening in Chicago
+        assert_eq!(
+            format_completion_time(completed_at, &tz, TimeFormat::Minutes),
+            "Tuesday, June 30, 2026 at 21:30 CDT"
+        );
+    }
+
+    #[test]
+    fn test_format_completion_time_utc() {
+        let completed_at = Utc.with_ymd_and_hms(2026, 1, 15, 15, 4, 5).unwrap();
+        assert_eq!(
+            format_completion_time(completed_at, &chrono_tz::UTC, TimeFormat::Seconds),
+            "Thursday, January 15, 2026 at 15:04:05 UTC"
+        );
+    }
+
+    // ========================================================================
+    // Audit log tests
+    // ========================================================================
+
+    #[test]
+    fn test_describe_task_changes_none() {
+        let task = create_default_task();
+        assert!(describe_task_changes(&task, &task.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_describe_task_changes_schedule_and_name() {
+        let mut previous = create_default_task();
+        previous.name = "Trash".to_string();
+        previous.schedule_kind = ScheduleKind::NDays;
+        previous.n_days.days = 7;
+
+        let mut updated = previous.clone();
+        updated.name = "Recycling".to_string();
+        updated.schedule_kind = ScheduleKind::Monthwise;
+        updated.monthwise.days = vec![1, 15];
+
+        assert_eq!(
+            describe_task_changes(&previous, &updated),
+            vec![
+                "renamed from \"Trash\" to \"Recycling\"".to_string(),
+                "changed schedule from Every 7 day(s) to Monthly on day(s) 1, 15".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_describe_task_changes_time_only() {
+        let mut previous = create_default_task();
+        previous.schedule_kind = ScheduleKind::NDays;
+        let mut updated = previous.clone();
+        updated.n_days.time = NaiveTime::from_hms_opt(18, 30, 0).unwrap();
+
+        let changes = describe_task_changes(&previous, &updated);
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].starts_with("changed time from "));
+        assert!(changes[0].ends_with(" to 18:30"));
+    }
+
+    // ========================================================================
+    // Combined due list tests
+    // ========================================================================
+
+    fn once_task(name: &str, due: DateTime<Utc>) -> DemoTask {
+        let mut task = create_default_task();
+        task.name = name.to_string();
+        task.once.datetime = due;
+        task
+    }
+
+    #[test]
+    fn test_merge_due_and_alerting_is_chronological() {
+        let now = Utc::now();
+        let due = vec![
+            once_task("Old", now - Duration::hours(5)),
+            once_task("Recent", now - Duration::hours(1)),
+        ];
+        let alerting = vec![
+            once_task("Soon", now + Duration::hours(1)),
+            once_task("Earlier", now - Duration::hours(3)),
+        ];
+        let merged = merge_due_and_alerting(due, alerting);
+        let order: Vec<(&str, &str)> = merged.iter().map(|(t, s)| (t.name.as_str(), *s)).collect();
+        assert_eq!(
+            order,
+            vec![("Old", "due"), ("Earlier", "alerting"), ("Recent", "due"), ("Soon", "alerting")]
+        );
+    }
+
+    #[test]
+    fn test_tagged_task_card_shows_overdue_or_upcoming() {
+        let task = once_task("Dishes", Utc::now());
+        assert!(render_tagged_task_card(&task, "due", false, 0, false, View::Full).contains("Overdue"));
+        assert!(render_tagged_task_card(&task, "alerting", false, 0, false, View::Full).contains("Upcoming"));
+        assert!(!render_task_card(&task, "due", false, None, 0, false, View::Full).contains("task-card-tag"));
+    }
+
+    // ========================================================================
+    // Grace period tests
+    // ========================================================================
+
+    fn graced_task(minutes_ago: i64, grace_minutes: i64) -> DemoTask {
+        let mut task = once_task("Dishes", Utc::now() - Duration::minutes(minutes_ago));
+        task.grace_minutes = grace_minutes;
+        task
+    }
+
+    #[test]
+    fn test_grace_zero_is_due_immediately() {
+        let task = graced_task(1, 0);
+        assert!(task.is_due());
+        assert!(!task.is_alerting());
+    }
+
+    #[test]
+    fn test_grace_keeps_task_upcoming_inside_window() {
+        let task = graced_task(29, 30);
+        assert!(!task.is_due());
+        assert!(task.is_alerting());
+    }
+
+    #[test]
+    fn test_grace_task_is_due_after_window() {
+        let task = graced_task(31, 30);
+        assert!(task.is_due());
+        assert!(!task.is_alerting());
+    }
+
+    #[test]
+    fn test_grace_does_not_affect_future_tasks() {
+        let task = graced_task(-60, 30);
+        assert!(!task.is_due());
+        assert!(task.is_alerting());
+    }
+
+    #[test]
+    fn test_task_form_grace_minutes_clamped() {
+        let base = create_default_task();
+        let mut form = TaskForm {
+            name: "Test Task".to_string(),
+            schedule_type: "once".to_string(),
+            grace_minutes: Some(-5),
+            ..Default::default()
+        };
+        assert_eq!(form.to_demo_task("", &base).grace_minutes, 0);
+        form.grace_minutes = Some(45);
+        assert_eq!(form.to_demo_task("", &base).grace_minutes, 45);
+    }
+
+    #[test]
+    fn test_grace_is_only_kept_for_one_time_and_all_day_tasks() {
+        let base = create_default_task();
+        let mut form = TaskForm {
+            name: "Take Bins In".to_string(),
+            schedule_type: "n_days".to_string(),
+            grace_minutes: Some(120),
+            ..Default::default()
+        };
+        let task = form.to_demo_task("", &base);
+        assert!(!task.grace_applies());
+        assert_eq!(task.grace_minutes, 0);
+
+        form.all_day = Some("on".to_string());
+        let task = form.to_demo_task("", &base);
+        assert!(task.grace_applies());
+        assert_eq!(task.grace_minutes, 120);
+    }
+
+    #[test]
+    fn test_switching_weekday_schedule_kinds_keeps_days_and_time() {
+        let base = create_default_task();
+        let weekly = TaskForm {
+            name: "Bins".to_string(),
+            schedule_type: "weeks_of_month".to_string(),
+            prev_schedule_type: Some("n_weeks".to_string()),
+            dow_tue: Some("on".to_string()),
+            dow_fri: Some("on".to_string()),
+            n_weeks_time: Some("18:30".to_string()),
+            ..Default::default()
+        };
+        let task = weekly.to_demo_task("", &base);
+        let days = &task.weeks_of_month.sub_schedule;
+        assert!(days.tuesday && days.friday && !days.monday);
+        assert_eq!(days.time, NaiveTime::from_hms_opt(18, 30, 0).unwrap());
+
+        let back = TaskForm {
+            schedule_type: "n_weeks".to_string(),
+            prev_schedule_type: Some("weeks_of_month".to_string()),
+            wom_dow_sat: Some("on".to_string()),
+            ..weekly
+        };
+        assert!(back.to_demo_task("", &base).n_weeks.sub_schedule.saturday);
+
+        // Other switches start from the new kind's own fields
+        let once = TaskForm { schedule_type: "once".to_string(), prev_schedule_type: Some("n_weeks".to_string()), ..Default::default() };
+        assert_eq!(once.to_demo_task("", &base).once.datetime, base.once.datetime);
+    }
+
+    // ========================================================================
+    // Not today (snooze) tests
+    // ========================================================================
+
+    #[test]
+    fn test_snoozed_task_is_neither_due_nor_alerting() {
+        let mut task = once_task("Dishes", Utc::now() - Duration::minutes(5));
+        task.snoozed_until = Some(Utc::now() + Duration::hours(1));
+        assert!(task.is_snoozed());
+        assert!(!task.is_due());
+        assert!(!task.is_alerting());
+    }
+
+    #[tokio::test]
+    async fn test_snoozed_task_is_folded_away_on_the_dashboard() {
+        let pool = test_pool("snoozed_dashboard_test").await;
+        let mut task = once_task("Dentist", Utc::now() - Duration::minutes(5));
+        task.id = String::new();
+        let id = db::save_task(&pool, &task).await.unwrap();
+        db::set_task_snoozed_until(&pool, id, Some(Utc::now() + Duration::hours(1))).await.unwrap();
+
+        let home = render_homepage(&pool, &HeaderMap::new(), View::Full).await;
+        assert!(home.contains(r#"<details class="task-section task-section-snoozed">"#), "{}", home);
+        assert!(home.contains("Snoozed (1)"));
+        assert_eq!(home.matches("Dentist").count(), 1);
+        for section in ["Due Tasks", "Upcoming", "Other Tasks"] {
+            assert!(!home.contains(section), "snoozed task shown under {}", section);
+        }
+    }
+
+    #[test]
+    fn test_snooze_expires() {
+        let mut task = once_task("Dishes", Utc::now() - Duration::minutes(5));
+        task.snoozed_until = Some(Utc::now() - Duration::seconds(1));
+        assert!(!task.is_snoozed());
+        assert!(task.is_due());
+    }
+
+    // ========================================================================
+    // Notification channel tests
+    // ========================================================================
+
+    #[test]
+    fn test_effective_notify_channels() {
+        let mut task = create_default_task();
+        assert_eq!(task.effective_notify_channels(), get_notify_channels());
+        task.notify_channels = "none".to_string();
+        assert!(task.effective_notify_channels().is_empty());
+        task.notify_channels = "webhook".to_string();
+        assert_eq!(task.effective_notify_channels(), vec![NotifyChannel::Webhook]);
+    }
+
+    // ========================================================================
+    // Duplicate detection tests
+    // ========================================================================
+
+    #[test]
+    fn test_normalize_task_name() {
+        assert_eq!(normalize_task_name("Take out the Trash!"), "take out trash");
+        assert_eq!(normalize_task_name("  take   out trash "), "take out trash");
+        assert_eq!(normalize_task_name("Water plants (a)"), "water plants");
+    }
+
+    #[test]
+    fn test_find_similar_task() {
+        let mut deleted = once_task("Dishes", Utc::now());
+        deleted.deleted_at = Some(Utc::now());
+        let tasks = vec![once_task("Take out trash", Utc::now()), deleted];
+
+        assert!(find_similar_task(&tasks, "take out the TRASH").is_some());
+        assert!(find_similar_task(&tasks, "Take out recycling").is_none());
+        assert!(find_similar_task(&tasks, "dishes").is_none());
+        assert!(find_similar_task(&tasks, "!!!").is_none());
+    }
+
+    #[test]
+    fn test_duplicate_warning_is_not_an_error() {
+        let errors = FormErrors {
+            duplicate_warning: Some("A task named \"Dishes\" already exists.".to_string()),
+            ..Default::default()
+        };
+        assert!(!errors.has_errors());
+    }
+
+    // ========================================================================
+    // Pagination tests
+    // ========================================================================
+
+    #[test]
+    fn test_clamp_pagination() {
+        assert_eq!(clamp_pagination(1, 10, 25), (1, 10, 3));
+        assert_eq!(clamp_pagination(9, 10, 25), (3, 10, 3));
+        assert_eq!(clamp_pagination(0, 0, 25), (1, 1, 25));
+        assert_eq!(clamp_pagination(-4, 1000, 25), (1, MAX_PER_PAGE, 1));
+        assert_eq!(clamp_pagination(3, 10, 0), (1, 10, 0));
+    }
+
+    // ========================================================================
+    // Occurrence completion tests
+    // ========================================================================
+
+    fn completion_at(completed_at: DateTime<Utc>) -> db::CompletionRecord {
+        db::CompletionRecord { id: 1, completed_at, person_initials: None, duration_minutes: None, note: None }
+    }
+
+    #[test]
+    fn test_backfilled_completion_at_due_time_covers_occurrence() {
+        let mut task = create_default_task();
+        task.schedule_kind = ScheduleKind::NDays;
+        task.n_days = default_n_days();
+        let tz = get_timezone();
+        let today = Utc::now().with_timezone(&tz).date_naive();
+        let due = tz
+            .from_local_datetime(&today.and_time(task.n_days.time))
+            .unwrap()
+            .with_timezone(&Utc);
+        let (_, next_due) = occurrence_window(&task, due);
+
+        // Stamped exactly at the due time, as calendar backfills are
+        let on_time = [completion_at(due)];
+        assert!(occurrence_completion(&task, &on_time, due).is_some());
+
+        // Before the occurrence, or at the next one, doesn't count
+        let outside = [completion_at(due - Duration::seconds(1)), completion_at(next_due)];
+        assert!(occurrence_completion(&task, &outside, due).is_none());
+    }
+
+    #[test]
+    fn test_occurrence_window_is_half_open() {
+        let task = daily_task();
+        let due = at("2026-03-10T09:00:00Z");
+        let window = occurrence_window(&task, due);
+        assert_eq!(window, (due, at("2026-03-11T09:00:00Z")));
+
+        assert!(completion_satisfies(due, window));
+        assert!(completion_satisfies(at("2026-03-11T08:59:59Z"), window));
+        assert!(!completion_satisfies(at("2026-03-10T08:59:59Z"), window));
+        assert!(!completion_satisfies(at("2026-03-11T09:00:00Z"), window));
+    }
+
+    #[test]
+    fn test_occurrence_done_needs_the_counter_target() {
+        let mut task = daily_task();
+        assert!(!occurrence_done(&task, 0));
+        assert!(occurrence_done(&task, 1));
+
+        task.target_count = 3;
+        assert!(!occurrence_done(&task, 2));
+        assert!(occurrence_done(&task, 3));
+    }
+
+    #[test]
+    fn test_dashboard_and_calendar_agree_on_backfilled_completion() {
+        // A backfill stamped exactly at the due time counts on both surfaces
+        let mut task = daily_task();
+        task.id = "7".to_string();
+        let _clock = clock::freeze(at("2026-03-10T12:00:00Z"));
+        let due = task.most_recent_due_date();
+        let backfilled = completion_at(due);
+
+        let dashboard_window = occurrence_window(&task, due);
+        assert!(completion_satisfies(backfilled.completed_at, dashboard_window));
+        let days = calendar_month(&task, std::slice::from_ref(&backfilled), 2026, 3).unwrap();
+        assert!(days[9].completion.is_some());
+    }
+
+    // ========================================================================
+    // Next due (integration) tests
+    // ========================================================================
+
+    #[test]
+    fn test_next_due_rfc3339() {
+        let future = once_task("Later", Utc::now() + Duration::days(2));
+        let parsed = DateTime::parse_from_rfc3339(&future.next_due_rfc3339().unwrap()).unwrap();
+        assert_eq!(parsed.with_timezone(&Utc), future.once.datetime);
+
+        let past = once_task("Done", Utc::now() - Duration::days(2));
+        assert_eq!(past.next_due_rfc3339(), None);
+    }
+
+    // ========================================================================
+    // Counter task tests
+    // ========================================================================
+
+    #[test]
+    fn test_counter_card_shows_progress() {
+        let mut task = once_task("Drink Water", Utc::now() - Duration::hours(1));
+        task.id = "7".to_string();
+        task.target_count = 8;
+        assert!(render_task_card(&task, "due", false, None, 3, false, View::Full).contains("3/8"));
+        assert!(render_task_card(&task, "completed", false, None, 9, false, View::Full).contains("8/8"));
+
+        task.target_count = 1;
+        assert!(!render_task_card(&task, "due", false, None, 0, false, View::Full).contains("task-card-count"));
+    }
+
+    #[test]
+    fn test_target_count_clamped_from_form() {
+        let base = create_default_task();
+        let mut form = TaskForm {
+            name: "Drink Water".to_string(),
+            details: String::new(),
+            schedule_type: "n_days".to_string(),
+            target_count: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(form.to_demo_task("", &base).target_count, 1);
+        form.target_count = Some(MAX_TARGET_COUNT + 5);
+        assert_eq!(form.to_demo_task("", &base).target_count, MAX_TARGET_COUNT);
+    }
+
+    // ========================================================================
+    // Recent strip tests
+    // ========================================================================
+
+    fn timeline_entry(id: i64, name: Option<&str>, completed_at: DateTime<Utc>) -> db::TimelineEntry {
+        db::TimelineEntry {
+            id,
+            task_id: "4".to_string(),
+            task_name: name.map(str::to_string),
+            completed_at,
+            person_initials: Some("AB".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_format_relative_time() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        assert_eq!(format_relative_time(now - Duration::seconds(20), now), "just now");
+        assert_eq!(format_relative_time(now - Duration::minutes(5), now), "5m ago");
+        assert_eq!(format_relative_time(now - Duration::hours(3), now), "3h ago");
+        assert_eq!(format_relative_time(now - Duration::days(2), now), "2d ago");
+    }
+
+    #[test]
+    fn test_recent_strip_hidden_without_recent_completions() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        assert_eq!(render_recent_strip(&[], now), "");
+        let old = [timeline_entry(1, Some("Dishes"), now - Duration::days(3))];
+        assert_eq!(render_recent_strip(&old, now), "");
+    }
+
+    #[test]
+    fn test_recent_strip_links_and_undo() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        let entries = [
+            timeline_entry(9, Some("<Dishes>"), now - Duration::minutes(5)),
+            timeline_entry(8, None, now - Duration::hours(2)),
+        ];
+        let html = render_recent_strip(&entries, now);
+        assert!(html.contains(r#"href="/tasks/4">&lt;Dishes&gt;</a>"#));
+        assert!(html.contains("5m ago by AB"));
+        assert!(html.contains(r#"hx-post="/tasks/4/completions/9/undo""#));
+        assert!(html.contains("(deleted task)"));
+    }
+
+    // ========================================================================
+    // Upcoming occurrence tests
+    // ========================================================================
+
+    fn tue_thu_task() -> DemoTask {
+        let mut task = create_default_task();
+        task.schedule_kind = ScheduleKind::NWeeks;
+        task.n_weeks = NWeeks {
+            weeks: 1,
+            offset: 0,
+            sub_schedule: DaysOfWeek {
+                sunday: false,
+                monday: false,
+                tuesday: true,
+                wednesday: false,
+                thursday: true,
+                friday: false,
+                saturday: false,
+                time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            },
+        };
+        task
+    }
+
+    #[test]
+    fn test_upcoming_occurrences_lists_next_few() {
+        // Tuesday 2026-03-10, after that day's 09:00 occurrence
+        let after = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        let upcoming = engine::upcoming_after(&tue_thu_task(), after, 3, get_timezone(), clock::now());
+        assert_eq!(
+            upcoming,
+            vec![
+                Utc.with_ymd_and_hms(2026, 3, 12, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 3, 17, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 3, 19, 9, 0, 0).unwrap(),
+            ]
+        );
+        assert_eq!(format_agenda_time(upcoming[0], after), "Thu 09:00");
+        assert_eq!(format_agenda_time(upcoming[2], after), "Thu Mar 19 09:00");
+    }
+
+    #[test]
+    fn test_upcoming_occurrences_stop_at_deletion() {
+        let mut task = tue_thu_task();
+        task.deleted_at = Some(Utc.with_ymd_and_hms(2026, 3, 13, 0, 0, 0).unwrap());
+        let after = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        assert_eq!(engine::upcoming_after(&task, after, 3, get_timezone(), clock::now()).len(), 1);
+    }
+
+    // ========================================================================
+    // Schedule snapshot tests
+    // ========================================================================
+
+    #[test]
+    fn test_schedule_snapshot_round_trip() {
+        let mut original = create_default_task();
+        original.schedule_kind = ScheduleKind::Monthwise;
+        original.monthwise.days = vec![1, 15];
+        original.n_days.skip_weekends = SkipWeekends::WeekdaysOnly;
+        let snapshot = db::schedule_snapshot(&original);
+
+        let mut edited = original.clone();
+        edited.schedule_kind = ScheduleKind::NDays;
+        edited.monthwise.days = vec![3];
+        assert_ne!(db::schedule_snapshot(&edited), snapshot);
+
+        assert!(db::apply_schedule_snapshot(&mut edited, &snapshot));
+        assert!(edited.schedule_kind == ScheduleKind::Monthwise);
+        assert_eq!(edited.monthwise.days, vec![1, 15]);
+        assert_eq!(edited.n_days.skip_weekends, SkipWeekends::WeekdaysOnly);
+        assert_eq!(db::schedule_snapshot(&edited), snapshot);
+
+        assert!(!db::apply_schedule_snapshot(&mut edited, "not json"));
+    }
+
+    // ========================================================================
+    // Instructions tests
+    // ========================================================================
+
+    #[test]
+    fn test_instructions_only_in_picker_when_set() {
+        assert_eq!(render_picker_instructions(""), "");
+        let html = render_picker_instructions("Filter <ABC>, water off first");
+        assert!(html.contains("Filter &lt;ABC&gt;, water off first"));
+        assert!(!html.contains("<ABC>"));
+    }
+
+    #[test]
+    fn test_instructions_trimmed_and_audited() {
+        let base = create_default_task();
+        let form = TaskForm {
+            name: "Fridge Filter".to_string(),
+            instructions: "  Water off first \n".to_string(),
+            schedule_type: "n_days".to_string(),
+            ..Default::default()
+        };
+        let updated = form.to_demo_task("", &base);
+        assert_eq!(updated.instructions, "Water off first");
+        assert!(describe_task_changes(&base, &updated).contains(&"edited instructions".to_string()));
+    }
+
+    // ========================================================================
+    // Calendar undo tests
+    // ========================================================================
+
+    #[test]
+    fn test_calendar_completed_cell_offers_undo_for_its_completion() {
+        let mut task = daily_task();
+        task.id = "7".to_string();
+        let mut covering = completion_at(at("2026-03-10T09:30:00Z"));
+        covering.id = 42;
+        let _clock = clock::freeze(at("2026-03-10T12:00:00Z"));
+
+        let html = render_calendar(&task, &[covering], false, false);
+        assert_eq!(html.matches("calendar-undo-btn").count(), 1);
+        assert!(html.contains(r#"hx-delete="/tasks/7/completions/42""#));
+
+        let mut covering = completion_at(at("2026-03-10T09:30:00Z"));
+        covering.id = 42;
+        assert!(!render_calendar(&task, &[covering], true, false).contains("calendar-undo-btn"));
+    }
+
+    #[test]
+    fn test_calendar_marks_closed_windows_without_completion_as_missed() {
+        let mut task = daily_task();
+        let completions = vec![completion_at(at("2026-03-05T10:00:00Z"))];
+        let _clock = clock::freeze(at("2026-03-10T12:00:00Z"));
+
+        let days = calendar_month(&task, &completions, 2026, 3).unwrap();
+        let missed: Vec<u32> = days.iter().filter(|d| d.missed).map(|d| d.date.day()).collect();
+        // The 10th is past due but can still be completed until the 11th's occurrence
+        assert_eq!(missed, vec![1, 2, 3, 4, 6, 7, 8, 9]);
+        assert_eq!(render_calendar(&task, &completions, false, false).matches("calendar-cell-missed").count(), 8);
+
+        task.completeable = false;
+        assert!(calendar_month(&task, &completions, 2026, 3).unwrap().iter().all(|d| !d.missed));
+    }
+
+    #[test]
+    fn test_calendar_week_numbers_are_opt_in() {
+        let task = daily_task();
+        // March 2026 starts on a Sunday, so its rows are ISO weeks 10 to 14
+        let _clock = clock::freeze(at("2026-03-10T12:00:00Z"));
+
+        assert!(!render_calendar(&task, &[], false, false).contains("calendar-week-number"));
+
+        let html = render_calendar(&task, &[], false, true);
+        assert!(html.contains("calendar-grid-weeks"));
+        for week in 10..=14 {
+            assert!(html.contains(&format!(r#"<div class="calendar-week-number">{}</div>"#, week)));
+        }
+        assert!(!html.contains(r#"<div class="calendar-week-number">15</div>"#));
+    }
+
+    // ========================================================================
+    // Reschedule tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_once_datetime() {
+        assert_eq!(
+            parse_once_datetime(Some("2026-11-03"), Some("14:30")),
+            Some(at("2026-11-03T14:30:00Z"))
+        );
+        // A blank time falls back to 09:00, same as the full editor
+        assert_eq!(parse_once_datetime(Some("2026-11-03"), Some("")), Some(at("2026-11-03T09:00:00Z")));
+        assert_eq!(parse_once_datetime(Some("11/03/2026"), None), None);
+        assert_eq!(parse_once_datetime(None, Some("14:30")), None);
+    }
+
+    #[test]
+    fn test_reschedule_control_only_on_once_cards() {
+        let mut task = once_task("Dentist", Utc::now() + Duration::days(2));
+        task.id = "5".to_string();
+        let html = render_task_card(&task, "upcoming", false, None, 0, false, View::Full);
+        assert!(html.contains(r#"action="/tasks/5/reschedule""#));
+
+        let guest = View::Guest { token: "abc", can_complete: false };
+        let html = render_task_card(&task, "upcoming", false, None, 0, false, guest);
+        assert!(!html.contains("reschedule"));
+
+        task.schedule_kind = ScheduleKind::NDays;
+        let html = render_task_card(&task, "upcoming", false, None, 0, false, View::Full);
+        assert!(!html.contains("reschedule"));
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_moves_once_task() {
+        use tower::ServiceExt;
+
+        let pool = test_pool("reschedule_test").await;
+        sqlx::query("INSERT INTO schedules (id, kind, once_datetime) VALUES (1, 'once', '2026-11-03T09:00:00+00:00')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO schedules (id, kind) VALUES (2, 'n_days')").execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO tasks (id, name, schedule_id) VALUES (1, 'Dentist', 1), (2, 'Dishes', 2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let post = |uri: &'static str| {
+            let app = router().with_state(pool.clone());
+            async move {
+                let request = axum::http::Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("Content-Type", "application/x-www-form-urlencoded")
+                    .body(axum::body::Body::from("once_date=2026-11-10&once_time=15:45&from=page"))
+                    .unwrap();
+                app.oneshot(request).await.unwrap().status()
+            }
+        };
+
+        assert_eq!(post("/1/reschedule").await, axum::http::StatusCode::SEE_OTHER);
+        let task = db::get_task(&pool, 1).await.unwrap().unwrap();
+        assert_eq!(task.once.datetime, at("2026-11-10T15:45:00Z"));
+        let log = db::get_audit_log(&pool, "1", 10).await.unwrap();
+        assert!(log.iter().any(|e| e.summary.starts_with("rescheduled")));
+
+        assert_eq!(post("/2/reschedule").await, axum::http::StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_audit_log_labels_each_action() {
+        let task = create_default_task();
+        let entry = |id, action: &str| db::AuditEntry {
+            id,
+            created_at: at("2026-03-10T09:00:00Z"),
+            action: action.to_string(),
+            summary: "something happened".to_string(),
+            schedule_snapshot: None,
+        };
+        let html = render_audit_log(&task, &[entry(1, "complete"), entry(2, "revert"), entry(3, "<odd>")]);
+        assert!(html.contains(r#"<span class="audit-action audit-action-complete">Completed</span>"#), "{}", html);
+        assert!(html.contains(r#"<span class="audit-action audit-action-revert">Reverted</span>"#));
+        assert!(html.contains("&lt;odd&gt;"));
+        assert!(!html.contains("<odd>"));
+    }
+
+    // ========================================================================
+    // Encoding tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_accented_task_name_round_trips_as_utf8() {
+        use tower::ServiceExt;
+
+        let pool = test_pool("utf8_name_test").await;
+        insert_task(&pool, 1, "Crème brûlée für Zoë").await;
+
+        for uri in ["/1", "/1/edit", "/list"] {
+            let request = axum::http::Request::builder().uri(uri).body(axum::body::Body::empty()).unwrap();
+            let response = router().with_state(pool.clone()).oneshot(request).await.unwrap();
+            assert_eq!(
+                response.headers()[axum::http::header::CONTENT_TYPE],
+                "text/html; charset=utf-8",
+                "{}",
+                uri
+            );
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let html = String::from_utf8(body.to_vec()).unwrap();
+            assert!(html.contains("Crème brûlée für Zoë"), "{}", uri);
+        }
+    }
+
+    // ========================================================================
+    // Pinned clock tests
+    // ========================================================================
+
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339).unwrap().with_timezone(&Utc)
+    }
+
+    fn daily_task() -> DemoTask {
+        let mut task = create_default_task();
+        task.schedule_kind = ScheduleKind::NDays;
+        task.n_days = default_n_days();
+        task
+    }
+
+    #[test]
+    fn test_show_page_flags_an_occurrence_that_is_already_done() {
+        let _clock = clock::freeze(at("2026-03-10T12:00:00Z"));
+        let mut task = daily_task();
+        task.id = "4".to_string();
+
+        let open = render_task_show_page(&task, &[], &[], false, View::Full);
+        assert!(open.contains("Not done yet"));
+        assert!(open.contains(r#"action="/tasks/4/complete""#));
+        assert!(!open.contains("confirm('This occurrence"));
+
+        // Done at 10:00 today, after the 09:00 occurrence came due
+        let done = [completion_at(at("2026-03-10T10:00:00Z"))];
+        let html = render_task_show_page(&task, &done, &[], false, View::Full);
+        assert!(html.contains("Already completed for this occurrence"));
+        assert!(html.contains("confirm('This occurrence is already done."));
+
+        // Yesterday's completion belongs to yesterday's occurrence
+        let stale = [completion_at(at("2026-03-09T10:00:00Z"))];
+        assert!(render_task_show_page(&task, &stale, &[], false, View::Full).contains("Not done yet"));
+    }
+
+    #[test]
+    fn test_next_and_most_recent_due_with_pinned_clock() {
+        let task = daily_task();
+
+        let _clock = clock::freeze(at("2026-03-10T10:00:00Z"));
+        assert_eq!(task.next_due_date(), at("2026-03-11T09:00:00Z"));
+        assert_eq!(task.most_recent_due_date(), at("2026-03-10T09:00:00Z"));
+
+        let _clock = clock::freeze(at("2026-03-10T08:59:59Z"));
+        assert_eq!(task.next_due_date(), at("2026-03-10T09:00:00Z"));
+        assert_eq!(task.most_recent_due_date(), at("2026-03-09T09:00:00Z"));
     }
 
     #[test]
-    fn test_format_mixed() {
-        assert_eq!(
-            format_day_range(&[1, 4, 5, 6, 7, 10]),
-            "1, 4-7, 10"
-        );
-        assert_eq!(
-            format_day_range(&[1, 2, 4, 5, 6, 7, 10, 15, 16, 17]),
-            "1-2, 4-7, 10, 15-17"
-        );
+    fn test_n_days_cadence_counts_from_pinned_today() {
+        let mut task = daily_task();
+        task.n_days.days = 3;
+        let _clock = clock::freeze(at("2026-03-10T10:00:00Z"));
+        let due: Vec<u32> = (8..=16)
+            .filter(|day| is_due_on_date(&task, chrono::NaiveDate::from_ymd_opt(2026, 3, *day).unwrap()))
+            .collect();
+        assert_eq!(due, vec![10, 13, 16]);
+        assert_eq!(task.next_due_date(), at("2026-03-13T09:00:00Z"));
     }
 
     #[test]
-    fn test_format_unsorted_input() {
-        // Should handle unsorted input
-        assert_eq!(format_day_range(&[15, 1, 10]), "1, 10, 15");
-        assert_eq!(format_day_range(&[5, 3, 1, 2, 4]), "1-5");
+    fn test_scheduled_end_with_pinned_clock() {
+        let mut task = daily_task();
+        task.deleted_at = Some(at("2026-03-10T23:59:59Z"));
+
+        let _clock = clock::freeze(at("2026-03-10T12:00:00Z"));
+        assert!(!task.is_deleted());
+        assert!(task.scheduled_end().is_some());
+
+        let _clock = clock::freeze(at("2026-03-11T00:00:00Z"));
+        assert!(task.is_deleted());
+        assert!(task.scheduled_end().is_none());
     }
 
     #[test]
-    fn test_format_with_duplicates() {
-        // Should handle duplicates
-        assert_eq!(format_day_range(&[1, 1, 2, 2, 3]), "1-3");
-        assert_eq!(format_day_range(&[5, 5, 5]), "5");
+    fn test_all_day_task_is_due_for_the_whole_day() {
+        let timed = daily_task();
+        let mut task = daily_task();
+        task.all_day = true;
+
+        let _clock = clock::freeze(at("2026-03-10T15:00:00Z"));
+        assert!(task.is_due());
+        assert!(!task.is_alerting());
+        assert_eq!(task.all_day_due_today(), Some(at("2026-03-10T00:00:00Z")));
+        assert_eq!(task.time_as_readable_string(), "Today");
+        assert!(completion_satisfies(at("2026-03-10T07:00:00Z"), occurrence_window(&task, task.most_recent_due_date())));
+
+        assert!(!timed.is_due());
+        assert_eq!(timed.time_as_readable_string(), "Tomorrow at 09:00");
+
+        // Inside the grace period it's still only alerting
+        task.grace_minutes = 60;
+        drop(_clock);
+        let _clock = clock::freeze(at("2026-03-10T00:30:00Z"));
+        assert!(!task.is_due());
+        assert!(task.is_alerting());
     }
 
     #[test]
-    fn test_format_all_days() {
-        let all_days: Vec<i32> = (1..=31).collect();
-        assert_eq!(format_day_range(&all_days), "1-31");
+    fn test_relative_day_label_with_shifted_day_start() {
+        let local = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        let evening = local("2026-03-10 22:00");
+        let small_hours = local("2026-03-11 02:00");
+
+        // At midnight the 02:00 task is tomorrow; with a 4am day start it's still tonight
+        assert_eq!(relative_day_label(small_hours, false, evening, 0), Some("Tomorrow"));
+        assert_eq!(relative_day_label(small_hours, false, evening, 4), Some("Today"));
+        assert_eq!(relative_day_label(local("2026-03-11 04:00"), false, evening, 4), Some("Tomorrow"));
+
+        // Seen from 01:00, the 22:00 task the evening before is still today, not yesterday
+        assert_eq!(relative_day_label(evening, false, local("2026-03-11 01:00"), 0), Some("Yesterday"));
+        assert_eq!(relative_day_label(evening, false, local("2026-03-11 01:00"), 4), Some("Today"));
+        assert_eq!(relative_day_label(local("2026-03-13 03:59"), false, evening, 4), Some("Overmorrow"));
+        assert_eq!(relative_day_label(local("2026-03-13 04:00"), false, evening, 4), None);
+
+        // All-day tasks are due at 00:00 of their own date, which the shift mustn't move back a day
+        let midnight = local("2026-03-10 00:00");
+        assert_eq!(relative_day_label(midnight, true, evening, 4), Some("Today"));
+        assert_eq!(relative_day_label(local("2026-03-11 00:00"), true, evening, 4), Some("Tomorrow"));
+        // Until the day starts at 04:00, the small hours still belong to the day before
+        assert_eq!(relative_day_label(local("2026-03-11 00:00"), true, small_hours, 4), Some("Tomorrow"));
+        assert_eq!(relative_day_label(midnight, true, small_hours, 4), Some("Today"));
     }
 
     #[test]
-    fn test_format_complex_pattern() {
-        // First, 15th, and last week of month
-        assert_eq!(
-            format_day_range(&[1, 2, 3, 4, 5, 6, 7, 15, 25, 26, 27, 28, 29, 30, 31]),
-            "1-7, 15, 25-31"
-        );
+    fn test_all_day_form_moves_once_to_start_of_day() {
+        let base = create_default_task();
+        let form = TaskForm {
+            name: "Dentist".to_string(),
+            schedule_type: "once".to_string(),
+            once_date: Some("2026-11-03".to_string()),
+            once_time: Some("14:30".to_string()),
+            all_day: Some("on".to_string()),
+            ..Default::default()
+        };
+        let task = form.to_demo_task("", &base);
+        assert!(task.all_day);
+        assert_eq!(task.once.datetime, at("2026-11-03T00:00:00Z"));
+        assert_eq!(describe_schedule(&task), "Once on Nov 03, 2026");
+
+        let mut timed = task.clone();
+        timed.all_day = false;
+        assert!(describe_task_changes(&timed, &task).contains(&"made all-day".to_string()));
+        assert_eq!(event_time_label(&task, NaiveTime::MIN), "All day");
+        assert_eq!(event_time_label(&timed, NaiveTime::MIN), "00:00");
     }
 
     // ========================================================================
-    // Round-trip tests (parse -> format -> parse)
+    // Guest view tests
     // ========================================================================
 
     #[test]
-    fn test_roundtrip_simple() {
-        let inputs = vec![
-            "1",
-            "1, 15",
-            "1-5",
-            "1, 4-7, 10",
-            "1-2, 4-7, 10, 15-17",
-        ];
+    fn test_guest_cards_link_to_guest_pages() {
+        let mut task = create_default_task();
+        task.id = "4".to_string();
+        task.name = "Feed cat".to_string();
+
+        let read_only = View::Guest { token: "abc", can_complete: false };
+        let html = render_task_card(&task, "due", false, None, 0, false, read_only);
+        assert!(html.contains(r#"href="/guest/abc/tasks/4""#));
+        assert!(!html.contains("complete-picker"));
+        assert!(!html.contains("<form"));
+
+        let can_complete = View::Guest { token: "abc", can_complete: true };
+        let html = render_task_card(&task, "due", false, None, 0, false, can_complete);
+        assert!(html.contains(r#"action="/guest/abc/tasks/4/complete""#));
+        assert!(!html.contains("not-today"));
+    }
 
-        for input in inputs {
-            let parsed = parse_day_range(input).unwrap();
-            let formatted = format_day_range(&parsed);
-            let reparsed = parse_day_range(&formatted).unwrap();
-            assert_eq!(parsed, reparsed, "Round-trip failed for: {}", input);
+    #[test]
+    fn test_guest_task_page_has_no_mutation_controls() {
+        let mut task = create_default_task();
+        task.id = "4".to_string();
+        task.name = "Feed cat".to_string();
+        let completions = vec![completion_at(Utc::now() - Duration::days(1))];
+
+        let full = render_task_show_page(&task, &completions, &[], false, View::Full);
+        assert!(full.contains("edit-modal"));
+        assert!(full.contains("completion-delete"));
+
+        let guest = render_task_show_page(&task, &completions, &[], false, View::Guest { token: "abc", can_complete: true });
+        for control in ["edit-modal", "save-template", "delete-modal", "restore-modal", "completion-delete", "complete-on"] {
+            assert!(!guest.contains(control), "guest page contains {}", control);
         }
+        assert!(guest.contains(r#"href="/guest/abc""#));
+        assert!(guest.contains(&format!("Times shown in {}", get_timezone().name())));
     }
 
     #[test]
-    fn test_roundtrip_normalizes() {
-        // Input with redundancy should normalize
-        let parsed = parse_day_range("5, 1-5, 3").unwrap();
-        let formatted = format_day_range(&parsed);
-        assert_eq!(formatted, "1-5");
+    fn test_shared_views_are_read_only() {
+        let mut task = create_default_task();
+        task.id = "4".to_string();
+        task.name = "Feed cat".to_string();
+        let shared = View::Shared { token: "xyz" };
+
+        let card = render_task_card(&task, "due", false, None, 0, false, shared);
+        assert!(card.contains(r#"href="/share/xyz/tasks/4""#));
+        assert!(!card.contains("<form"));
+
+        let page = render_task_show_page(&task, &[], &[], false, shared);
+        for control in ["edit-modal", "delete-modal", "complete-on", "/complete"] {
+            assert!(!page.contains(control), "shared page contains {}", control);
+        }
+        assert!(page.contains(r#"href="/share/xyz""#));
     }
 
     // ========================================================================
-    // FormErrors tests
+    // Show ahead tests
     // ========================================================================
 
     #[test]
-    fn test_form_errors_default() {
-        let errors = FormErrors::default();
-        assert!(!errors.has_errors());
-        assert!(errors.monthwise_days.is_none());
-        assert!(errors.general.is_none());
+    fn test_planning_window_is_separate_from_alerting() {
+        let mut task = once_task("Passport renewal", Utc::now() + Duration::days(5));
+        task.alerting_time = 60;
+        assert!(!task.is_planning());
+
+        task.show_ahead_days = 7;
+        assert!(task.is_planning());
+        assert!(!task.is_alerting());
+
+        task.show_ahead_days = 3;
+        assert!(!task.is_planning());
+
+        // Once it's alerting, it moves to Upcoming instead
+        task.show_ahead_days = 7;
+        task.alerting_time = 10 * 1440;
+        assert!(task.is_alerting());
+        assert!(!task.is_planning());
     }
 
     #[test]
-    fn test_form_errors_with_monthwise_error() {
-        let errors = FormErrors {
-            monthwise_days: Some("Invalid day format".to_string()),
-            certain_months_days: None,
-            general: None,
+    fn test_show_ahead_days_from_form() {
+        let base = create_default_task();
+        let mut form = TaskForm {
+            name: "Taxes".to_string(),
+            schedule_type: "once".to_string(),
+            show_ahead_days: Some(-3),
+            ..Default::default()
         };
-        assert!(errors.has_errors());
+        assert_eq!(form.to_demo_task("", &base).show_ahead_days, 0);
+        form.show_ahead_days = Some(14);
+        let updated = form.to_demo_task("", &base);
+        assert_eq!(updated.show_ahead_days, 14);
+        assert!(describe_task_changes(&base, &updated).contains(&"changed show ahead from 0 to 14 days".to_string()));
     }
 
+    // ========================================================================
+    // Bulk alert time tests
+    // ========================================================================
+
     #[test]
-    fn test_form_errors_with_general_error() {
-        let errors = FormErrors {
-            monthwise_days: None,
-            certain_months_days: None,
-            general: Some("Something went wrong".to_string()),
-        };
-        assert!(errors.has_errors());
+    fn test_selected_task_ids_ignores_other_fields() {
+        let fields = vec![
+            ("task_ids".to_string(), "3".to_string()),
+            ("alerting_time".to_string(), "60".to_string()),
+            ("task_ids".to_string(), "demo-1".to_string()),
+            ("task_ids".to_string(), "12".to_string()),
+        ];
+        assert_eq!(selected_task_ids(&fields), vec![3, 12]);
     }
 
     #[test]
-    fn test_form_errors_with_multiple_errors() {
-        let errors = FormErrors {
-            monthwise_days: Some("Invalid day".to_string()),
-            certain_months_days: None,
-            general: Some("General error".to_string()),
+    fn test_task_list_items_join_bulk_form() {
+        let mut task = create_default_task();
+        task.id = "5".to_string();
+        let html = render_task_list_item(&task, false);
+        assert!(html.contains(r#"name="task_ids" value="5" form="bulk-actions-form""#));
+        let form = render_bulk_alerting_form(42);
+        assert!(form.contains(r#"id="bulk-actions-form""#));
+        assert!(form.contains("All 42 tasks"));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_all_matching_asks_then_updates_every_task() {
+        use tower::ServiceExt;
+
+        let pool = test_pool("bulk_all_matching_test").await;
+        for name in ["Dishes", "Trash", "Plants"] {
+            let mut task = create_default_task();
+            task.name = name.to_string();
+            db::save_task(&pool, &task).await.unwrap();
+        }
+
+        let app = router().with_state(pool.clone());
+        let post = |body: &'static str| {
+            axum::http::Request::builder()
+                .method("POST")
+                .uri("/alerting/batch")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .header("HX-Request", "true")
+                .body(axum::body::Body::from(body))
+                .unwrap()
         };
-        assert!(errors.has_errors());
+        let read = |response: Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        };
+
+        // Only the first page's box is ticked, but "all" reaches every task after confirming
+        let response = app.clone().oneshot(post("task_ids=1&all_matching=1&alerting_time=60")).await.unwrap();
+        assert!(read(response).await.contains("Apply to all 3"));
+        assert!(db::get_all_tasks(&pool).await.unwrap().iter().all(|t| t.alerting_time == 1440));
+
+        let response = app.clone().oneshot(post("task_ids=1&all_matching=1&alerting_time=60&confirm_all=1")).await.unwrap();
+        assert!(read(response).await.contains("on 3 tasks"));
+        assert!(db::get_all_tasks(&pool).await.unwrap().iter().all(|t| t.alerting_time == 60));
+
+        // Without htmx the confirmation is a page that posts the same choice back
+        let mut plain = post("all_matching=1&alerting_time=5");
+        plain.headers_mut().remove("HX-Request");
+        let response = app.clone().oneshot(plain).await.unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let page = read(response).await;
+        assert!(page.contains(r#"<form class="bulk-actions" method="post" action="/tasks/alerting/batch">"#));
+        assert!(page.contains(r#"name="alerting_time" value="5""#));
+        assert!(page.contains("Apply to all 3"));
+
+        // A database failure is an error, not "Select at least one task first."
+        pool.close().await;
+        let response = app.oneshot(post("all_matching=1&alerting_time=60")).await.unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::INTERNAL_SERVER_ERROR);
     }
 
-    #[test]
-    fn test_form_errors_with_certain_months_error() {
-        let errors = FormErrors {
-            monthwise_days: None,
-            certain_months_days: Some("Invalid day format".to_string()),
-            general: None,
+    #[tokio::test]
+    async fn test_empty_database_offers_examples_once() {
+        use tower::ServiceExt;
+
+        let pool = test_pool("onboarding_test").await;
+
+        let home = render_homepage(&pool, &HeaderMap::new(), View::Full).await;
+        assert!(home.contains(r#"action="/tasks/examples""#));
+        assert!(home.contains(r#"<p class="timezone-note">Times shown in "#));
+        assert!(home.contains("Create your first task"));
+        let guest = render_homepage(&pool, &HeaderMap::new(), View::Shared { token: "xyz" }).await;
+        assert!(!guest.contains("/tasks/examples"));
+        assert!(render_task_list(&pool, "name", 1, 20, false).await.contains(r##"hx-target="#modal-container""##));
+
+        let app = router().with_state(pool.clone());
+        let load = || {
+            axum::http::Request::builder()
+                .method("POST")
+                .uri("/examples")
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body(axum::body::Body::from("return=home"))
+                .unwrap()
         };
-        assert!(errors.has_errors());
+        let response = app.clone().oneshot(load()).await.unwrap();
+        assert_eq!(response.headers()["location"], "/");
+        let example_count = get_demo_tasks().lock().unwrap().len();
+        assert_eq!(db::get_task_count(&pool).await.unwrap(), example_count as i64);
+
+        // Loading again adds nothing, and the welcome is gone
+        app.oneshot(load()).await.unwrap();
+        assert_eq!(db::get_task_count(&pool).await.unwrap(), example_count as i64);
+        let home = render_homepage(&pool, &HeaderMap::new(), View::Full).await;
+        assert!(!home.contains("/tasks/examples"));
+    }
+
+    #[tokio::test]
+    async fn test_print_checklist_has_boxes_and_no_controls() {
+        let pool = test_pool("print_checklist_test").await;
+        let mut task = daily_task();
+        task.name = "Feed cat".to_string();
+        db::save_task(&pool, &task).await.unwrap();
+
+        let week = render_print_checklist(&pool, false).await;
+        assert_eq!(week.matches("Feed cat").count(), 7);
+        assert!(week.contains("☐"));
+        assert!(week.contains("/static/print.css"));
+        for control in ["<form", "hx-", "/complete"] {
+            assert!(!week.contains(control), "checklist contains {}", control);
+        }
+
+        let today = render_print_checklist(&pool, true).await;
+        assert_eq!(today.matches("Feed cat").count(), 1);
     }
 
     // ========================================================================
-    // TaskForm validation tests
+    // Completion insight tests
     // ========================================================================
 
     #[test]
-    fn test_task_form_validate_valid_monthwise() {
-        let form = TaskForm {
-            name: "Test Task".to_string(),
-            details: String::new(),
-            schedule_type: "monthwise".to_string(),
-            monthwise_days: Some("1, 15".to_string()),
-            monthwise_time: Some("10:00".to_string()),
-            ..Default::default()
+    fn test_completion_rate_counts_full_days_only() {
+        let tz = get_timezone();
+        let today = Utc::now().with_timezone(&tz).date_naive();
+        let mut task = create_default_task();
+        task.schedule_kind = ScheduleKind::Monthwise;
+        task.monthwise.days = (1..=31).collect();
+
+        let at_noon = |date: chrono::NaiveDate| {
+            completion_at(tz.from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap()).unwrap().with_timezone(&Utc))
         };
+        // Every other day done, plus today which shouldn't count
+        let completions: Vec<_> = (0..INSIGHT_WINDOW_DAYS)
+            .step_by(2)
+            .map(|days| at_noon(today - Duration::days(days + 1)))
+            .chain(std::iter::once(at_noon(today)))
+            .collect();
+        assert_eq!(completion_rate(&task, &completions, today), Some(50));
 
-        let errors = form.validate();
-        assert!(!errors.has_errors());
+        task.target_count = 2;
+        assert_eq!(completion_rate(&task, &completions, today), Some(25));
     }
 
     #[test]
-    fn test_task_form_validate_invalid_monthwise_format() {
-        let form = TaskForm {
-            name: "Test Task".to_string(),
-            details: String::new(),
-            schedule_type: "monthwise".to_string(),
-            monthwise_days: Some("abc, xyz".to_string()),
-            monthwise_time: Some("10:00".to_string()),
-            ..Default::default()
-        };
-
-        let errors = form.validate();
-        assert!(errors.has_errors());
-        assert!(errors.monthwise_days.is_some());
+    fn test_completion_rate_skips_unsuitable_tasks() {
+        let today = Utc::now().with_timezone(&get_timezone()).date_naive();
+        let mut task = create_default_task();
+        task.schedule_kind = ScheduleKind::NDays;
+        assert_eq!(completion_rate(&task, &[], today), Some(0));
+        task.completeable = false;
+        assert_eq!(completion_rate(&task, &[], today), None);
+
+        let once = once_task("Dentist", Utc::now() - Duration::days(3));
+        assert_eq!(completion_rate(&once, &[], today), None);
+
+        // Created yesterday: too few occurrences to judge
+        let mut fresh = create_default_task();
+        fresh.schedule_kind = ScheduleKind::NDays;
+        fresh.created_at = Some(Utc::now() - Duration::days(1));
+        assert_eq!(completion_rate(&fresh, &[], today), None);
     }
 
     #[test]
-    fn test_task_form_validate_monthwise_out_of_range() {
-        let form = TaskForm {
-            name: "Test Task".to_string(),
-            details: String::new(),
-            schedule_type: "monthwise".to_string(),
-            monthwise_days: Some("1, 32".to_string()),
-            monthwise_time: Some("10:00".to_string()),
-            ..Default::default()
-        };
-
-        let errors = form.validate();
-        assert!(errors.has_errors());
-        assert!(errors.monthwise_days.is_some());
-        assert!(errors.monthwise_days.as_ref().unwrap().contains("out of range"));
+    fn test_completion_insight_nudges_only_when_low() {
+        assert!(!render_completion_insight(85).contains("less frequent"));
+        let html = render_completion_insight(30);
+        assert!(html.contains("~30%"));
+        assert!(html.contains("less frequent"));
     }
 
+    // ========================================================================
+    // Empty schedule tests
+    // ========================================================================
+
     #[test]
-    fn test_task_form_validate_monthwise_empty() {
-        let form = TaskForm {
-            name: "Test Task".to_string(),
-            details: String::new(),
-            schedule_type: "monthwise".to_string(),
-            monthwise_days: Some("".to_string()),
-            monthwise_time: Some("10:00".to_string()),
-            ..Default::default()
+    fn test_empty_schedules_are_flagged() {
+        let mut weekly = create_default_task();
+        weekly.schedule_kind = ScheduleKind::NWeeks;
+        assert!(!weekly.has_empty_schedule());
+        weekly.n_weeks.sub_schedule = DaysOfWeek {
+            sunday: false,
+            monday: false,
+            tuesday: false,
+            wednesday: false,
+            thursday: false,
+            friday: false,
+            saturday: false,
+            time: weekly.n_weeks.sub_schedule.time,
         };
+        assert!(weekly.has_empty_schedule());
+        assert_eq!(describe_schedule(&weekly), EMPTY_SCHEDULE_LABEL);
+
+        let mut months = create_default_task();
+        months.schedule_kind = ScheduleKind::CertainMonths;
+        months.certain_months.months = vec![3];
+        months.certain_months.days = vec![15];
+        assert!(!months.has_empty_schedule());
+        months.certain_months.months.clear();
+        assert!(months.has_empty_schedule());
+        assert_eq!(describe_schedule(&months), EMPTY_SCHEDULE_LABEL);
+
+        let mut monthly = create_default_task();
+        monthly.schedule_kind = ScheduleKind::Monthwise;
+        monthly.monthwise.days.clear();
+        assert!(monthly.has_empty_schedule());
+
+        let mut nth = create_default_task();
+        nth.schedule_kind = ScheduleKind::WeeksOfMonth;
+        nth.weeks_of_month.weeks.clear();
+        assert!(nth.has_empty_schedule());
+    }
 
-        let errors = form.validate();
-        assert!(errors.has_errors());
-        assert!(errors.monthwise_days.is_some());
+    #[tokio::test]
+    async fn test_homepage_lists_empty_schedules_under_needs_attention() {
+        let pool = test_pool("empty_schedule_test").await;
+        sqlx::query("INSERT INTO schedules (id, kind, nweeks_weeks, nweeks_time) VALUES (1, 'n_weeks', 1, '09:00')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO schedules (id, kind, certain_months_months, certain_months_days) VALUES (2, 'certain_months', '', '1')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO schedules (id, kind) VALUES (3, 'n_days')").execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO tasks (id, name, schedule_id) VALUES (1, 'Water ferns', 1), (2, 'Clean gutters', 2), (3, 'Dishes', 3)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let html = render_homepage(&pool, &HeaderMap::new(), View::Full).await;
+        let section = &html[html.find("Needs Attention").unwrap()..];
+        let section = &section[..section.find("</section>").unwrap()];
+        assert!(section.contains("Water ferns"));
+        assert!(section.contains("Clean gutters"));
+        assert!(!section.contains("Dishes"));
     }
 
+    // ========================================================================
+    // Scheduled retirement tests
+    // ========================================================================
+
     #[test]
-    fn test_task_form_validate_monthwise_none() {
-        let form = TaskForm {
-            name: "Test Task".to_string(),
-            details: String::new(),
-            schedule_type: "monthwise".to_string(),
-            monthwise_days: None,
-            monthwise_time: Some("10:00".to_string()),
-            ..Default::default()
-        };
+    fn test_future_deleted_at_is_a_scheduled_end() {
+        let mut task = create_default_task();
+        task.deleted_at = Some(Utc::now() + Duration::days(5));
+        assert!(!task.is_deleted());
+        assert!(!task.is_inactive());
+        assert!(task.scheduled_end().is_some());
 
-        let errors = form.validate();
-        assert!(errors.has_errors());
-        assert!(errors.monthwise_days.is_some());
+        let tz = get_timezone();
+        let today = Utc::now().with_timezone(&tz).date_naive();
+        assert!(!is_due_on_date(&task, today + Duration::days(6)));
+
+        task.deleted_at = Some(Utc::now() - Duration::days(1));
+        assert!(task.is_deleted());
+        assert!(task.scheduled_end().is_none());
     }
 
     #[test]
-    fn test_task_form_validate_non_monthwise_schedule() {
-        // When schedule type is not monthwise, monthwise_days is not validated
+    fn test_active_until_sets_and_clears_end() {
+        let base = create_default_task();
+        let tz = get_timezone();
+        let date = Utc::now().with_timezone(&tz).date_naive() + Duration::days(10);
         let form = TaskForm {
-            name: "Test Task".to_string(),
-            details: String::new(),
+            name: "Seasonal".to_string(),
             schedule_type: "n_days".to_string(),
-            monthwise_days: Some("invalid".to_string()), // This should be ignored
-            n_days_count: Some(7),
+            active_until: Some(date.format("%Y-%m-%d").to_string()),
             ..Default::default()
         };
-
-        let errors = form.validate();
-        assert!(!errors.has_errors());
+        assert!(!form.validate().has_errors());
+        let updated = form.to_demo_task("", &base);
+        let end = updated.scheduled_end().unwrap().with_timezone(&tz);
+        assert_eq!(end.date_naive(), date);
+        assert!(describe_task_changes(&base, &updated).iter().any(|c| c.starts_with("set active until")));
+
+        let cleared = TaskForm { active_until: Some(String::new()), ..form }.to_demo_task("", &updated);
+        assert!(cleared.deleted_at.is_none());
     }
 
     #[test]
-    fn test_task_form_validate_monthwise_with_ranges() {
+    fn test_active_until_rejects_past_and_keeps_trashed() {
         let form = TaskForm {
-            name: "Test Task".to_string(),
-            details: String::new(),
-            schedule_type: "monthwise".to_string(),
-            monthwise_days: Some("1-5, 10, 15-20".to_string()),
-            monthwise_time: Some("10:00".to_string()),
+            schedule_type: "n_days".to_string(),
+            active_until: Some("2000-01-01".to_string()),
             ..Default::default()
         };
+        assert!(form.validate().active_until.is_some());
 
-        let errors = form.validate();
-        assert!(!errors.has_errors());
+        let mut trashed = create_default_task();
+        trashed.deleted_at = Some(Utc::now() - Duration::days(1));
+        let blank = TaskForm { active_until: Some(String::new()), ..form };
+        assert_eq!(blank.to_demo_task("", &trashed).deleted_at, trashed.deleted_at);
     }
 
     #[test]
-    fn test_task_form_validate_monthwise_invalid_range() {
-        let form = TaskForm {
-            name: "Test Task".to_string(),
-            details: String::new(),
-            schedule_type: "monthwise".to_string(),
-            monthwise_days: Some("10-5".to_string()), // reversed range
-            monthwise_time: Some("10:00".to_string()),
-            ..Default::default()
-        };
-
-        let errors = form.validate();
-        assert!(errors.has_errors());
-        assert!(errors.monthwise_days.is_some());
+    fn test_card_shows_scheduled_end() {
+        let mut task = create_default_task();
+        let html = render_task_card(&task, "upcoming", false, None, 0, false, View::Full);
+        assert!(!html.contains("task-card-ends"));
+
+        task.deleted_at = Some(Utc::now() + Duration::days(3));
+        let html = render_task_card(&task, "upcoming", false, None, 0, false, View::Full);
+        assert!(html.contains("task-card-ends"));
+        assert!(html.contains("Ends "));
     }
 
     // ========================================================================
-    // Edge cases and boundary tests
+    // Archive tests
     // ========================================================================
 
     #[test]
-    fn test_parse_boundary_days() {
-        // Minimum valid day
-        assert_eq!(parse_day_range("1").unwrap(), vec![1]);
-        
-        // Maximum valid day
-        assert_eq!(parse_day_range("31").unwrap(), vec![31]);
-        
-        // Full range
-        assert_eq!(parse_day_range("1-31").unwrap().len(), 31);
-    }
-
-    #[test]
-    fn test_format_preserves_order() {
-        // Even if given in weird order, output should be ascending
-        assert_eq!(format_day_range(&[31, 1, 15, 10, 5]), "1, 5, 10, 15, 31");
+    fn test_once_tasks_archive_after_configured_days() {
+        let now = Utc::now();
+        let old = once_task("Concert", now - Duration::days(10));
+        let recent = once_task("Dentist", now - Duration::days(2));
+        let upcoming = once_task("Party", now + Duration::days(2));
+
+        assert!(!old.is_archived(None, now));
+        assert!(old.is_archived(Some(7), now));
+        assert!(!recent.is_archived(Some(7), now));
+        assert!(!upcoming.is_archived(Some(0), now));
+
+        let mut recurring = create_default_task();
+        recurring.schedule_kind = ScheduleKind::NDays;
+        assert!(!recurring.is_archived(Some(0), now));
     }
 
-    #[test]
-    fn test_parse_long_list() {
-        // Stress test with many items
-        let input = (1..=31).map(|d| d.to_string()).collect::<Vec<_>>().join(", ");
-        let result = parse_day_range(&input).unwrap();
-        assert_eq!(result.len(), 31);
-    }
+    // ========================================================================
+    // Completion trigger tests
+    // ========================================================================
 
     #[test]
-    fn test_format_efficiency() {
-        // The formatter should produce the most compact representation
-        // [1,2,3,4,5] should be "1-5" not "1, 2, 3, 4, 5"
-        let days: Vec<i32> = (1..=10).collect();
-        let formatted = format_day_range(&days);
-        assert_eq!(formatted, "1-10");
-        assert!(formatted.len() < 30); // Much shorter than listing all
+    fn test_completion_trigger_names_task() {
+        let value: serde_json::Value = serde_json::from_str(&completion_trigger("42")).unwrap();
+        assert_eq!(value["taskCompleted"]["taskId"], "42");
     }
 
     // ========================================================================
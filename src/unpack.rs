@@ -6,9 +6,14 @@
 //!
 //! Extracts all photo BLOBs from photo_blobs table and writes them to the photos folder.
 
+mod clock;
 mod config;
 mod db;
+mod engine;
+mod error;
+mod events;
 mod migrate;
+mod pages;
 mod schedule;
 mod settings;
 mod tasks;
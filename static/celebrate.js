@@ -0,0 +1,27 @@
+// Brief confirmation after a task is completed. The server sends
+// `HX-Trigger: {"taskCompleted": ...}` from POST /tasks/:id/complete.
+(function() {
+    const script = document.currentScript;
+    const withSound = script && script.dataset.sound === 'true';
+    let chime = null;
+
+    function flourish() {
+        const body = document.body;
+        // Restart the animation if two completions land close together
+        body.classList.remove('celebrate');
+        void body.offsetWidth;
+        body.classList.add('celebrate');
+        setTimeout(function() {
+            body.classList.remove('celebrate');
+        }, 900);
+
+        if (withSound) {
+            chime = chime || new Audio('/static/celebrate.wav');
+            chime.currentTime = 0;
+            // Browsers may refuse playback before the first interaction; that's fine
+            chime.play().catch(function() {});
+        }
+    }
+
+    document.addEventListener('taskCompleted', flourish);
+})();
@@ -0,0 +1,29 @@
+// Re-fetch the dashboard whenever the server reports a change (see GET /events).
+(function() {
+    if (!window.EventSource || !window.htmx) {
+        return;
+    }
+
+    let pending = null;
+
+    function refresh() {
+        // Don't pull the person picker out from under someone who is completing a task
+        if (document.querySelector('.person-picker')) {
+            clearTimeout(pending);
+            pending = setTimeout(refresh, 10000);
+            return;
+        }
+        htmx.ajax('GET', window.location.pathname, {
+            target: '#homepage',
+            select: '#homepage',
+            swap: 'outerHTML'
+        });
+    }
+
+    // EventSource reconnects on its own if the server restarts
+    const source = new EventSource('/events');
+    source.addEventListener('refresh', refresh);
+    window.addEventListener('beforeunload', function() {
+        source.close();
+    });
+})();